@@ -0,0 +1,39 @@
+use crate::sale_registry::{SaleInfo, TemplateVersion};
+
+multiversx_sc::imports!();
+
+#[multiversx_sc::module]
+pub trait EventsModule {
+    fn emit_deploy_sale_event(&self, sale_info: &SaleInfo<Self::Api>) {
+        self.deploy_sale_event(
+            &sale_info.launched_token_id,
+            &sale_info.address,
+            sale_info.clone(),
+        );
+    }
+
+    fn emit_upgrade_sale_event(
+        &self,
+        sale_address: &ManagedAddress,
+        template_address: &ManagedAddress,
+        template_version: TemplateVersion,
+    ) {
+        self.upgrade_sale_event(sale_address, template_address, template_version);
+    }
+
+    #[event("deploySaleEvent")]
+    fn deploy_sale_event(
+        &self,
+        #[indexed] launched_token_id: &TokenIdentifier,
+        #[indexed] sale_address: &ManagedAddress,
+        sale_info: SaleInfo<Self::Api>,
+    );
+
+    #[event("upgradeSaleEvent")]
+    fn upgrade_sale_event(
+        &self,
+        #[indexed] sale_address: &ManagedAddress,
+        #[indexed] template_address: &ManagedAddress,
+        template_version: TemplateVersion,
+    );
+}
@@ -0,0 +1,166 @@
+#![no_std]
+
+use price_discovery::{
+    price_discovery_proxy::{self, PhaseDefinition, SaleMode, TimeBase},
+    Timestamp,
+};
+use sale_registry::{SaleInfo, TemplateVersion};
+
+multiversx_sc::imports!();
+
+pub mod events;
+pub mod owner_actions;
+pub mod sale_registry;
+
+#[multiversx_sc::contract]
+pub trait PriceDiscoveryFactory:
+    sale_registry::SaleRegistryModule + events::EventsModule + owner_actions::OwnerActionsModule
+{
+    /// `template_address` is an already deployed price discovery contract, whose code is used for the new sales
+    #[init]
+    fn init(&self, template_address: ManagedAddress) {
+        self.set_template_address(template_address);
+    }
+
+    #[upgrade]
+    fn upgrade(&self) {}
+
+    /// The sales deployed before are not upgraded automatically, see `upgradeNextSale`
+    #[only_owner]
+    #[endpoint(setTemplateAddress)]
+    fn set_template_address(&self, template_address: ManagedAddress) {
+        require!(
+            self.blockchain().is_smart_contract(&template_address),
+            "Invalid template address"
+        );
+
+        self.template_address().set(template_address);
+        self.template_version().update(|version| *version += 1);
+    }
+
+    /// Deploys a new sale from the template. The factory becomes the owner of the sale,
+    /// and `sale_owner` may call the sale's owner endpoints through the factory.
+    /// For explanations regarding the other parameters, please refer to the price discovery `init`,
+    /// which also validates them.
+    #[only_owner]
+    #[allow_multiple_var_args]
+    #[endpoint(deploySale)]
+    fn deploy_sale(
+        &self,
+        sale_owner: ManagedAddress,
+        launched_token_id: TokenIdentifier,
+        accepted_token_id: EgldOrEsdtTokenIdentifier,
+        launched_token_decimals: u32,
+        start_time: Timestamp,
+        user_deposit_withdraw_time: Timestamp,
+        owner_deposit_withdraw_time: Timestamp,
+        owner_redeem_time: Timestamp,
+        user_min_deposit: BigUint,
+        admin: ManagedAddress,
+        opt_sale_mode: OptionalValue<SaleMode<Self::Api>>,
+        opt_phase_pipeline: OptionalValue<ManagedVec<PhaseDefinition>>,
+        opt_time_base: OptionalValue<TimeBase>,
+    ) -> ManagedAddress {
+        require!(!sale_owner.is_zero(), "Invalid sale owner");
+        require!(!admin.is_zero(), "Invalid admin");
+        require!(
+            self.sale_by_launched_token(&launched_token_id).is_empty(),
+            "A sale was already deployed for this launched token"
+        );
+
+        let time_base = match &opt_time_base {
+            OptionalValue::Some(time_base) => *time_base,
+            OptionalValue::None => TimeBase::Timestamp,
+        };
+        let template_address = self.template_address().get();
+        let sale_address = self
+            .tx()
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .init(
+                &launched_token_id,
+                &accepted_token_id,
+                launched_token_decimals,
+                start_time,
+                user_deposit_withdraw_time,
+                owner_deposit_withdraw_time,
+                owner_redeem_time,
+                user_min_deposit,
+                admin,
+                opt_sale_mode,
+                opt_phase_pipeline,
+                opt_time_base,
+            )
+            .from_source(template_address)
+            .code_metadata(CodeMetadata::UPGRADEABLE | CodeMetadata::READABLE)
+            .returns(ReturnsNewManagedAddress)
+            .sync_call();
+
+        let sale_info = SaleInfo {
+            address: sale_address.clone(),
+            launched_token_id: launched_token_id.clone(),
+            accepted_token_id,
+            sale_owner,
+            start_time,
+            time_base,
+            end_time: self.get_sale_end_time(&sale_address),
+            template_version: self.template_version().get(),
+        };
+        let _ = self.sale_addresses().insert(sale_address.clone());
+        self.sale_info(&sale_address).set(&sale_info);
+        self.sale_by_launched_token(&launched_token_id)
+            .set(&sale_address);
+
+        self.emit_deploy_sale_event(&sale_info);
+
+        sale_address
+    }
+
+    /// Upgrades the first sale deployed from an older template.
+    /// An upgrade is an asynchronous call that ends the transaction,
+    /// so this endpoint is called once for every sale returned by `getOutdatedSales`.
+    /// The template version of the sale is only updated once the upgrade succeeded.
+    #[only_owner]
+    #[endpoint(upgradeNextSale)]
+    fn upgrade_next_sale(&self) {
+        let template_version = self.template_version().get();
+        let opt_sale_address = self.sale_addresses().iter().find(|sale_address| {
+            self.sale_info(sale_address).get().template_version < template_version
+        });
+        let sale_address = match opt_sale_address {
+            Some(sale_address) => sale_address,
+            None => sc_panic!("All sales are up to date"),
+        };
+
+        // the upgrade call has no callback of its own, so the closure is saved like for any other async call
+        let template_address = self.template_address().get();
+        self.callbacks()
+            .upgrade_sale_callback(&sale_address, &template_address, template_version)
+            .save_to_storage::<Self::Api>();
+
+        self.tx()
+            .to(sale_address)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .upgrade()
+            .from_source(template_address)
+            .code_metadata(CodeMetadata::UPGRADEABLE | CodeMetadata::READABLE)
+            .upgrade_async_call_and_exit();
+    }
+
+    #[callback]
+    fn upgrade_sale_callback(
+        &self,
+        sale_address: &ManagedAddress,
+        template_address: &ManagedAddress,
+        template_version: TemplateVersion,
+        #[call_result] result: ManagedAsyncCallResult<IgnoreValue>,
+    ) {
+        if result.is_err() {
+            return;
+        }
+
+        self.sale_info(sale_address)
+            .update(|sale_info| sale_info.template_version = template_version);
+
+        self.emit_upgrade_sale_event(sale_address, template_address, template_version);
+    }
+}
@@ -0,0 +1,100 @@
+use price_discovery::price_discovery_proxy;
+
+multiversx_sc::imports!();
+
+/// The factory is the owner of the deployed sales, so the owner endpoints of a sale
+/// are called through the factory by the sale owner. The tokens sent back by the sale
+/// are forwarded to the sale owner.
+#[multiversx_sc::module]
+pub trait OwnerActionsModule: crate::sale_registry::SaleRegistryModule {
+    #[payable("*")]
+    #[endpoint(ownerDeposit)]
+    fn owner_deposit(&self, sale_address: ManagedAddress) {
+        self.require_sale_owner(&sale_address);
+
+        let payment = self.call_value().single_esdt();
+        self.tx()
+            .to(&sale_address)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .owner_deposit()
+            .payment(payment)
+            .sync_call();
+    }
+
+    #[endpoint(ownerWithdraw)]
+    fn owner_withdraw(&self, sale_address: ManagedAddress, amount: BigUint) {
+        let caller = self.require_sale_owner(&sale_address);
+
+        let back_transfers = self
+            .tx()
+            .to(&sale_address)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .owner_withdraw(amount)
+            .returns(ReturnsBackTransfers)
+            .sync_call();
+        self.forward_back_transfers(&caller, &back_transfers);
+    }
+
+    #[endpoint(ownerRedeem)]
+    fn owner_redeem(&self, sale_address: ManagedAddress) {
+        let caller = self.require_sale_owner(&sale_address);
+
+        let back_transfers = self
+            .tx()
+            .to(&sale_address)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .owner_redeem_endpoint()
+            .returns(ReturnsBackTransfers)
+            .sync_call();
+        self.forward_back_transfers(&caller, &back_transfers);
+    }
+
+    #[endpoint(withdrawLaunchpadTokens)]
+    fn withdraw_launchpad_tokens(&self, sale_address: ManagedAddress) {
+        let caller = self.require_sale_owner(&sale_address);
+
+        let back_transfers = self
+            .tx()
+            .to(&sale_address)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .withdraw_launchpad_tokens()
+            .returns(ReturnsBackTransfers)
+            .sync_call();
+        self.forward_back_transfers(&caller, &back_transfers);
+    }
+
+    /// The new beneficiary accepts by calling `acceptBeneficiary` on the sale directly
+    #[endpoint(proposeBeneficiary)]
+    fn propose_beneficiary(&self, sale_address: ManagedAddress, new_beneficiary: ManagedAddress) {
+        self.require_sale_owner(&sale_address);
+
+        self.tx()
+            .to(&sale_address)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .propose_beneficiary(new_beneficiary)
+            .sync_call();
+    }
+
+    fn require_sale_owner(&self, sale_address: &ManagedAddress) -> ManagedAddress {
+        let sale_info = self.require_known_sale(sale_address);
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == sale_info.sale_owner,
+            "Only the sale owner may call this endpoint"
+        );
+
+        caller
+    }
+
+    fn forward_back_transfers(
+        &self,
+        to: &ManagedAddress,
+        back_transfers: &BackTransfers<Self::Api>,
+    ) {
+        self.send()
+            .direct_non_zero_egld(to, &back_transfers.total_egld_amount);
+        if !back_transfers.esdt_payments.is_empty() {
+            self.send().direct_multi(to, &back_transfers.esdt_payments);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use price_discovery::{
+    price_discovery_proxy::{self, TimeBase},
+    Timestamp,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type TemplateVersion = u64;
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct SaleInfo<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub launched_token_id: TokenIdentifier<M>,
+    pub accepted_token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub sale_owner: ManagedAddress<M>,
+    pub start_time: Timestamp,
+    pub time_base: TimeBase,
+    /// The start of the `UserRedeem` phase, see `refreshSaleEndTime`
+    pub end_time: Timestamp,
+    pub template_version: TemplateVersion,
+}
+
+#[multiversx_sc::module]
+pub trait SaleRegistryModule {
+    #[view(getSaleByLaunchedToken)]
+    fn get_sale_by_launched_token(
+        &self,
+        launched_token_id: TokenIdentifier,
+    ) -> OptionalValue<ManagedAddress> {
+        let mapper = self.sale_by_launched_token(&launched_token_id);
+        if mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(mapper.get())
+        }
+    }
+
+    /// `offset` and `limit` apply to the deployed sales, in deploy order
+    #[view(getAllSales)]
+    fn get_all_sales(&self, offset: usize, limit: usize) -> MultiValueEncoded<SaleInfo<Self::Api>> {
+        self.get_sales_page(offset, limit).into()
+    }
+
+    /// The sales that did not reach the `UserRedeem` phase yet.
+    /// `offset` and `limit` apply to all the deployed sales, in deploy order,
+    /// so a page may hold less than `limit` active sales
+    #[view(getActiveSales)]
+    fn get_active_sales(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<SaleInfo<Self::Api>> {
+        self.get_sales_by_status(false, offset, limit)
+    }
+
+    /// The sales in the `UserRedeem` phase, where all the other phases have ended.
+    /// `offset` and `limit` apply to all the deployed sales, in deploy order,
+    /// so a page may hold less than `limit` finished sales
+    #[view(getFinishedSales)]
+    fn get_finished_sales(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<SaleInfo<Self::Api>> {
+        self.get_sales_by_status(true, offset, limit)
+    }
+
+    /// The sales deployed from an older template, that may be upgraded with `upgradeNextSale`
+    #[view(getOutdatedSales)]
+    fn get_outdated_sales(&self) -> MultiValueEncoded<ManagedAddress> {
+        let template_version = self.template_version().get();
+        let mut sales = MultiValueEncoded::new();
+        for sale_address in self.sale_addresses().iter() {
+            if self.sale_info(&sale_address).get().template_version < template_version {
+                sales.push(sale_address);
+            }
+        }
+
+        sales
+    }
+
+    /// Reads the end time again from the sale, after its phases were changed by the sale admin,
+    /// or extended by the soft close. May be called by anyone.
+    #[endpoint(refreshSaleEndTime)]
+    fn refresh_sale_end_time(&self, sale_address: ManagedAddress) {
+        let _ = self.require_known_sale(&sale_address);
+
+        let end_time = self.get_sale_end_time(&sale_address);
+        self.sale_info(&sale_address)
+            .update(|sale_info| sale_info.end_time = end_time);
+    }
+
+    fn get_sales_by_status(
+        &self,
+        finished: bool,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<SaleInfo<Self::Api>> {
+        let mut sales = MultiValueEncoded::new();
+        for sale_info in self.get_sales_page(offset, limit).into_iter() {
+            if self.is_sale_finished(&sale_info) == finished {
+                sales.push(sale_info);
+            }
+        }
+
+        sales
+    }
+
+    fn get_sales_page(&self, offset: usize, limit: usize) -> ManagedVec<SaleInfo<Self::Api>> {
+        let sale_addresses = self.sale_addresses();
+        let first_index = offset.saturating_add(1);
+        let last_index = core::cmp::min(offset.saturating_add(limit), sale_addresses.len());
+
+        let mut sales = ManagedVec::new();
+        for index in first_index..=last_index {
+            let sale_address = sale_addresses.get_by_index(index);
+            sales.push(self.sale_info(&sale_address).get());
+        }
+
+        sales
+    }
+
+    fn is_sale_finished(&self, sale_info: &SaleInfo<Self::Api>) -> bool {
+        let current_time = match sale_info.time_base {
+            TimeBase::Timestamp => self.blockchain().get_block_timestamp(),
+            TimeBase::BlockNonce => self.blockchain().get_block_nonce(),
+        };
+
+        current_time >= sale_info.end_time
+    }
+
+    /// The end time is the start of the `UserRedeem` phase, the last entry of the sale's schedule
+    fn get_sale_end_time(&self, sale_address: &ManagedAddress) -> Timestamp {
+        let schedule = self
+            .tx()
+            .to(sale_address)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_phase_schedule()
+            .returns(ReturnsResult)
+            .sync_call();
+
+        match schedule.into_iter().last() {
+            Some(user_redeem_interval) => user_redeem_interval.start,
+            None => sc_panic!("Invalid sale schedule"),
+        }
+    }
+
+    fn require_known_sale(&self, sale_address: &ManagedAddress) -> SaleInfo<Self::Api> {
+        require!(self.sale_addresses().contains(sale_address), "Unknown sale");
+
+        self.sale_info(sale_address).get()
+    }
+
+    #[view(getTemplateAddress)]
+    #[storage_mapper("templateAddress")]
+    fn template_address(&self) -> SingleValueMapper<ManagedAddress>;
+
+    /// Increased every time the template address is set
+    #[view(getTemplateVersion)]
+    #[storage_mapper("templateVersion")]
+    fn template_version(&self) -> SingleValueMapper<TemplateVersion>;
+
+    #[storage_mapper("saleAddresses")]
+    fn sale_addresses(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getSaleInfo)]
+    #[storage_mapper("saleInfo")]
+    fn sale_info(&self, sale_address: &ManagedAddress) -> SingleValueMapper<SaleInfo<Self::Api>>;
+
+    #[storage_mapper("saleByLaunchedToken")]
+    fn sale_by_launched_token(
+        &self,
+        launched_token_id: &TokenIdentifier,
+    ) -> SingleValueMapper<ManagedAddress>;
+}
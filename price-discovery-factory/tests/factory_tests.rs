@@ -0,0 +1,573 @@
+#![allow(deprecated)]
+
+mod tests_common;
+use multiversx_sc::{codec::multi_types::OptionalValue, contract_base::ContractBase};
+use multiversx_sc_scenario::{managed_address, managed_biguint, managed_token_id, rust_biguint};
+use price_discovery::{
+    common_storage::CommonStorageModule,
+    phase,
+    price_discovery_proxy::TimeBase,
+    user_actions::{redeem::RedeemModule, user_deposit_withdraw::UserDepositWithdrawModule},
+};
+use price_discovery_factory::{
+    owner_actions::OwnerActionsModule, sale_registry::SaleRegistryModule,
+};
+use tests_common::*;
+
+const FIRST_LAUNCHED_TOKEN_ID: &[u8] = b"FIRST-123456";
+const SECOND_LAUNCHED_TOKEN_ID: &[u8] = b"SECOND-123456";
+const THIRD_LAUNCHED_TOKEN_ID: &[u8] = b"THIRD-123456";
+
+#[test]
+fn deploy_several_sales_test() {
+    let mut setup = FactorySetup::new(price_discovery_factory::contract_obj);
+    let first_sale = setup.deploy_sale(FIRST_LAUNCHED_TOKEN_ID, START_TIME);
+    let second_sale = setup.deploy_sale(SECOND_LAUNCHED_TOKEN_ID, START_TIME + 50);
+    let third_sale = setup.deploy_sale(THIRD_LAUNCHED_TOKEN_ID, START_TIME + 100);
+
+    setup
+        .b_mock
+        .execute_query(&setup.factory_wrapper, |sc| {
+            assert_eq!(sc.get_all_sales(0, 10).len(), 3);
+            let second_page = sc.get_all_sales(1, 1).to_vec();
+            assert_eq!(second_page.len(), 1);
+            assert_eq!(
+                second_page.get(0).address,
+                managed_address!(second_sale.address_ref())
+            );
+            assert_eq!(sc.get_all_sales(3, 10).len(), 0);
+            for (launched_token_id, sale) in [
+                (FIRST_LAUNCHED_TOKEN_ID, &first_sale),
+                (SECOND_LAUNCHED_TOKEN_ID, &second_sale),
+                (THIRD_LAUNCHED_TOKEN_ID, &third_sale),
+            ] {
+                assert_eq!(
+                    sc.get_sale_by_launched_token(managed_token_id!(launched_token_id))
+                        .into_option(),
+                    Some(managed_address!(sale.address_ref()))
+                );
+
+                let sale_info = sc.sale_info(&managed_address!(sale.address_ref())).get();
+                assert_eq!(
+                    sale_info.launched_token_id,
+                    managed_token_id!(launched_token_id)
+                );
+                assert_eq!(
+                    sale_info.sale_owner,
+                    managed_address!(&setup.sale_owner_address)
+                );
+                assert!(sale_info.time_base == TimeBase::Timestamp);
+                assert_eq!(sale_info.template_version, 1);
+            }
+            assert!(sc
+                .get_sale_by_launched_token(managed_token_id!(b"OTHER-123456"))
+                .is_none());
+        })
+        .assert_ok();
+
+    // the sales are initialized, and owned by the factory
+    setup
+        .b_mock
+        .execute_query(&second_sale, |sc| {
+            assert_eq!(
+                sc.launched_token_id().get(),
+                managed_token_id!(SECOND_LAUNCHED_TOKEN_ID)
+            );
+            assert_eq!(sc.start_time().get(), START_TIME + 50);
+            assert_eq!(
+                sc.blockchain().get_owner_address(),
+                managed_address!(setup.factory_wrapper.address_ref())
+            );
+        })
+        .assert_ok();
+
+    setup
+        .call_deploy_sale(
+            FIRST_LAUNCHED_TOKEN_ID,
+            ACCEPTED_TOKEN_ID,
+            START_TIME,
+            USER_DEPOSIT_TIME,
+        )
+        .assert_user_error("A sale was already deployed for this launched token");
+}
+
+#[test]
+fn deploy_sale_invalid_params_test() {
+    let mut setup = FactorySetup::new(price_discovery_factory::contract_obj);
+
+    // the parameters are validated by the init of the sale, which fails the whole deploy
+    for (launched_token_id, accepted_token_id, start_time, user_deposit_withdraw_time) in [
+        (
+            b"INVALID" as &[u8],
+            ACCEPTED_TOKEN_ID,
+            START_TIME,
+            USER_DEPOSIT_TIME,
+        ),
+        (
+            ACCEPTED_TOKEN_ID,
+            ACCEPTED_TOKEN_ID,
+            START_TIME,
+            USER_DEPOSIT_TIME,
+        ),
+        (
+            FIRST_LAUNCHED_TOKEN_ID,
+            ACCEPTED_TOKEN_ID,
+            START_TIME - 1,
+            USER_DEPOSIT_TIME,
+        ),
+        (FIRST_LAUNCHED_TOKEN_ID, ACCEPTED_TOKEN_ID, START_TIME, 0),
+    ] {
+        setup
+            .call_deploy_sale(
+                launched_token_id,
+                accepted_token_id,
+                start_time,
+                user_deposit_withdraw_time,
+            )
+            .assert_error(10, "error signalled by smartcontract");
+    }
+
+    setup
+        .b_mock
+        .execute_query(&setup.factory_wrapper, |sc| {
+            assert_eq!(sc.get_all_sales(0, 10).len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn active_and_finished_sales_test() {
+    let mut setup = FactorySetup::new(price_discovery_factory::contract_obj);
+    let first_sale = setup.deploy_sale(FIRST_LAUNCHED_TOKEN_ID, START_TIME);
+    let second_sale = setup.deploy_sale(SECOND_LAUNCHED_TOKEN_ID, START_TIME + 50);
+    let _ = setup.deploy_sale(THIRD_LAUNCHED_TOKEN_ID, START_TIME + 100);
+
+    setup
+        .b_mock
+        .execute_query(&setup.factory_wrapper, |sc| {
+            assert_eq!(sc.get_active_sales(0, 10).len(), 3);
+            assert_eq!(sc.get_finished_sales(0, 10).len(), 0);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(FactorySetup::<fn() -> _>::sale_end(START_TIME + 50));
+    setup
+        .b_mock
+        .execute_query(&setup.factory_wrapper, |sc| {
+            let active_sales = sc.get_active_sales(0, 10).to_vec();
+            assert_eq!(active_sales.len(), 1);
+            assert_eq!(
+                active_sales.get(0).launched_token_id,
+                managed_token_id!(THIRD_LAUNCHED_TOKEN_ID)
+            );
+
+            let finished_sales: Vec<_> = sc
+                .get_finished_sales(0, 10)
+                .into_iter()
+                .map(|sale_info| sale_info.address)
+                .collect();
+            assert_eq!(finished_sales.len(), 2);
+            assert!(finished_sales.contains(&managed_address!(first_sale.address_ref())));
+            assert!(finished_sales.contains(&managed_address!(second_sale.address_ref())));
+
+            // the pages are taken from all the sales, in deploy order
+            let first_page: Vec<_> = sc
+                .get_finished_sales(0, 1)
+                .into_iter()
+                .map(|sale_info| sale_info.address)
+                .collect();
+            assert_eq!(first_page, [managed_address!(first_sale.address_ref())]);
+            let second_page: Vec<_> = sc
+                .get_finished_sales(1, 5)
+                .into_iter()
+                .map(|sale_info| sale_info.address)
+                .collect();
+            assert_eq!(second_page, [managed_address!(second_sale.address_ref())]);
+            assert_eq!(sc.get_finished_sales(2, 5).len(), 0);
+            assert_eq!(sc.get_active_sales(0, 2).len(), 0);
+            assert_eq!(sc.get_active_sales(1, 5).len(), 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn refresh_sale_end_time_test() {
+    let mut setup = FactorySetup::new(price_discovery_factory::contract_obj);
+    let sale = setup.deploy_sale(FIRST_LAUNCHED_TOKEN_ID, START_TIME);
+    let sale_address = sale.address_ref().clone();
+
+    // the sale admin shortens the user phase on the sale directly
+    setup
+        .call_set_user_deposit_withdraw_time(&sale, USER_DEPOSIT_TIME - 50)
+        .assert_ok();
+    setup
+        .b_mock
+        .set_block_timestamp(FactorySetup::<fn() -> _>::sale_end(START_TIME) - 50);
+    setup
+        .b_mock
+        .execute_query(&setup.factory_wrapper, |sc| {
+            assert_eq!(sc.get_finished_sales(0, 10).len(), 0);
+        })
+        .assert_ok();
+
+    setup.call_refresh_sale_end_time(&sale_address).assert_ok();
+    setup
+        .call_refresh_sale_end_time(&setup.user_address.clone())
+        .assert_user_error("Unknown sale");
+    setup
+        .b_mock
+        .execute_query(&setup.factory_wrapper, |sc| {
+            let sale_info = sc.sale_info(&managed_address!(&sale_address)).get();
+            assert_eq!(
+                sale_info.end_time,
+                FactorySetup::<fn() -> _>::sale_end(START_TIME) - 50
+            );
+            assert_eq!(sc.get_finished_sales(0, 10).len(), 1);
+            assert_eq!(sc.get_active_sales(0, 10).len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn deploy_block_nonce_sale_test() {
+    let mut setup = FactorySetup::new(price_discovery_factory::contract_obj);
+    let sale = setup.b_mock.prepare_deploy_from_sc(
+        setup.factory_wrapper.address_ref(),
+        price_discovery::contract_obj,
+    );
+    setup
+        .call_deploy_sale_with_time_base(
+            FIRST_LAUNCHED_TOKEN_ID,
+            ACCEPTED_TOKEN_ID,
+            START_TIME,
+            USER_DEPOSIT_TIME,
+            Some(TimeBase::BlockNonce),
+        )
+        .assert_ok();
+
+    // the phases of the sale follow the block nonce, and so does the factory
+    setup
+        .b_mock
+        .execute_query(&sale, |sc| {
+            assert!(sc.time_base().get() == phase::TimeBase::BlockNonce);
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .set_block_timestamp(FactorySetup::<fn() -> _>::sale_end(START_TIME));
+    setup
+        .b_mock
+        .execute_query(&setup.factory_wrapper, |sc| {
+            let sale_info = sc.sale_info(&managed_address!(sale.address_ref())).get();
+            assert!(sale_info.time_base == TimeBase::BlockNonce);
+            assert_eq!(sc.get_finished_sales(0, 10).len(), 0);
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_nonce(FactorySetup::<fn() -> _>::sale_end(START_TIME));
+    setup
+        .b_mock
+        .execute_query(&setup.factory_wrapper, |sc| {
+            assert_eq!(sc.get_finished_sales(0, 10).len(), 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn owner_actions_through_factory_test() {
+    let mut setup = FactorySetup::new(price_discovery_factory::contract_obj);
+    let sale = setup.deploy_sale(FIRST_LAUNCHED_TOKEN_ID, START_TIME);
+    let sale_address = sale.address_ref().clone();
+    setup.call_whitelist_user(&sale).assert_ok();
+    setup.call_set_min_launched_tokens(&sale, 1_000).assert_ok();
+
+    let user_address = setup.user_address.clone();
+    setup.b_mock.set_block_timestamp(START_TIME);
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &user_address,
+            &sale,
+            ACCEPTED_TOKEN_ID,
+            0,
+            &rust_biguint!(1_000),
+            |sc| {
+                sc.user_deposit(OptionalValue::None);
+            },
+        )
+        .assert_ok();
+
+    let sale_owner_address = setup.sale_owner_address.clone();
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME);
+    setup
+        .b_mock
+        .execute_esdt_transfer(
+            &sale_owner_address,
+            &setup.factory_wrapper,
+            FIRST_LAUNCHED_TOKEN_ID,
+            0,
+            &rust_biguint!(5_000),
+            |sc| {
+                sc.owner_deposit(managed_address!(&sale_address));
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_tx(
+            &sale_owner_address,
+            &setup.factory_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.owner_withdraw(managed_address!(&sale_address), managed_biguint!(1_000));
+            },
+        )
+        .assert_ok();
+    setup.b_mock.check_esdt_balance(
+        &sale_owner_address,
+        FIRST_LAUNCHED_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - 4_000),
+    );
+
+    // only the sale owner may call the owner endpoints of the sale
+    setup
+        .b_mock
+        .execute_tx(
+            &user_address,
+            &setup.factory_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.propose_beneficiary(
+                    managed_address!(&sale_address),
+                    managed_address!(&user_address),
+                );
+            },
+        )
+        .assert_user_error("Only the sale owner may call this endpoint");
+    setup
+        .b_mock
+        .execute_tx(
+            &sale_owner_address,
+            &setup.factory_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.propose_beneficiary(
+                    managed_address!(&sale_address),
+                    managed_address!(&user_address),
+                );
+            },
+        )
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&sale, |sc| {
+            assert_eq!(
+                sc.get_pending_beneficiary().into_option(),
+                Some(managed_address!(&user_address))
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &user_address,
+            &setup.factory_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.owner_redeem(managed_address!(&sale_address));
+            },
+        )
+        .assert_user_error("Only the sale owner may call this endpoint");
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME);
+    setup
+        .b_mock
+        .execute_tx(
+            &sale_owner_address,
+            &setup.factory_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.owner_redeem(managed_address!(&sale_address));
+            },
+        )
+        .assert_ok();
+
+    setup.b_mock.check_esdt_balance(
+        &sale_owner_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(1_000),
+    );
+    setup.b_mock.check_esdt_balance(
+        setup.factory_wrapper.address_ref(),
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn upgrade_sales_test() {
+    let mut setup = FactorySetup::new(price_discovery_factory::contract_obj);
+    let first_sale = setup.deploy_sale(FIRST_LAUNCHED_TOKEN_ID, START_TIME);
+    let second_sale = setup.deploy_sale(SECOND_LAUNCHED_TOKEN_ID, START_TIME + 50);
+
+    setup
+        .call_upgrade_next_sale()
+        .assert_user_error("All sales are up to date");
+
+    // a failed upgrade leaves the sale outdated
+    let broken_template_wrapper = setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        Some(&setup.owner_address),
+        broken_template::contract_obj,
+        BROKEN_TEMPLATE_WASM_PATH,
+    );
+    setup
+        .call_set_template_address(broken_template_wrapper.address_ref())
+        .assert_ok();
+    let result = setup.call_upgrade_next_sale();
+    result.assert_ok();
+    assert!(!result
+        .result_logs
+        .iter()
+        .any(|log| log.topics[0] == b"upgradeSaleEvent"));
+    setup
+        .b_mock
+        .execute_query(&setup.factory_wrapper, |sc| {
+            assert_eq!(sc.get_outdated_sales().len(), 2);
+            for sale in [&first_sale, &second_sale] {
+                let sale_info = sc.sale_info(&managed_address!(sale.address_ref())).get();
+                assert_eq!(sale_info.template_version, 1);
+            }
+        })
+        .assert_ok();
+
+    let new_template_wrapper = setup.b_mock.create_sc_account(
+        &rust_biguint!(0),
+        Some(&setup.owner_address),
+        price_discovery_v2::contract_obj,
+        NEW_TEMPLATE_WASM_PATH,
+    );
+    setup
+        .call_set_template_address(new_template_wrapper.address_ref())
+        .assert_ok();
+
+    // sales deployed after the template change are up to date
+    let third_sale = setup.deploy_sale(THIRD_LAUNCHED_TOKEN_ID, START_TIME + 100);
+    setup
+        .b_mock
+        .execute_query(&setup.factory_wrapper, |sc| {
+            assert_eq!(sc.template_version().get(), 3);
+            let outdated_sales = sc.get_outdated_sales().to_vec();
+            assert_eq!(outdated_sales.len(), 2);
+            assert!(!outdated_sales.contains(&managed_address!(third_sale.address_ref())));
+        })
+        .assert_ok();
+
+    // the upgrade runs the code of the new template, then the callback records the new version
+    for sale in [&first_sale, &second_sale] {
+        let result = setup.call_upgrade_next_sale();
+        result.assert_ok();
+
+        let upgrade_log = result
+            .result_logs
+            .iter()
+            .find(|log| log.topics[0] == b"upgradeEvent")
+            .expect("upgradeEvent not emitted");
+        assert_eq!(
+            upgrade_log.address.as_bytes(),
+            sale.address_ref().as_bytes()
+        );
+        assert_eq!(upgrade_log.topics[1], [2]);
+
+        let upgrade_sale_log = result
+            .result_logs
+            .iter()
+            .find(|log| log.topics[0] == b"upgradeSaleEvent")
+            .expect("upgradeSaleEvent not emitted");
+        assert_eq!(upgrade_sale_log.topics[1], sale.address_ref().as_bytes());
+    }
+    setup
+        .call_upgrade_next_sale()
+        .assert_user_error("All sales are up to date");
+
+    setup
+        .b_mock
+        .execute_query(&setup.factory_wrapper, |sc| {
+            assert_eq!(sc.get_outdated_sales().len(), 0);
+            for sale in [&first_sale, &second_sale, &third_sale] {
+                let sale_info = sc.sale_info(&managed_address!(sale.address_ref())).get();
+                assert_eq!(sale_info.template_version, 3);
+            }
+        })
+        .assert_ok();
+
+    // the upgraded sales keep their state
+    setup
+        .b_mock
+        .execute_query(&first_sale, |sc| {
+            assert_eq!(
+                sc.launched_token_id().get(),
+                managed_token_id!(FIRST_LAUNCHED_TOKEN_ID)
+            );
+        })
+        .assert_ok();
+}
+
+/// Stands for the next version of the price discovery contract
+mod price_discovery_v2 {
+    use price_discovery::phase::{Phase, PhaseInterval};
+
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::contract]
+    pub trait PriceDiscoveryV2 {
+        #[init]
+        fn init(&self, _init_args: MultiValueEncoded<ManagedBuffer>) {}
+
+        #[upgrade]
+        fn upgrade(&self) {
+            self.upgrade_event(2);
+        }
+
+        /// Read by the factory when the sale is deployed
+        #[view(getPhaseSchedule)]
+        fn get_phase_schedule(&self) -> MultiValueEncoded<PhaseInterval> {
+            let mut schedule = MultiValueEncoded::new();
+            schedule.push(PhaseInterval {
+                phase: Phase::UserRedeem,
+                start: 0,
+                end: None,
+            });
+
+            schedule
+        }
+
+        #[event("upgradeEvent")]
+        fn upgrade_event(&self, #[indexed] contract_version: u32);
+    }
+}
+
+/// A template whose upgrade always fails
+mod broken_template {
+    multiversx_sc::imports!();
+
+    #[multiversx_sc::contract]
+    pub trait BrokenTemplate {
+        #[init]
+        fn init(&self) {}
+
+        #[upgrade]
+        fn upgrade(&self) {
+            sc_panic!("Upgrade not supported");
+        }
+    }
+}
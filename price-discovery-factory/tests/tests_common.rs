@@ -0,0 +1,269 @@
+#![allow(deprecated, dead_code)]
+
+use multiversx_sc::{
+    codec::multi_types::OptionalValue,
+    types::{Address, ManagedVec, MultiValueEncoded},
+};
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, managed_token_id_wrapped, rust_biguint,
+    whitebox_legacy::*, DebugApi,
+};
+
+use price_discovery::{
+    price_discovery_proxy::{Phase, PhaseDefinition, SaleMode, TimeBase},
+    user_actions::admin_actions::AdminActionsModule,
+    Timestamp,
+};
+use price_discovery_factory::{sale_registry::SaleRegistryModule, PriceDiscoveryFactory};
+
+static FACTORY_WASM_PATH: &str = "../output/price-discovery-factory.wasm";
+pub static TEMPLATE_WASM_PATH: &str = "../output/price-discovery.wasm";
+pub static NEW_TEMPLATE_WASM_PATH: &str = "../output/price-discovery-v2.wasm";
+pub static BROKEN_TEMPLATE_WASM_PATH: &str = "../output/price-discovery-broken.wasm";
+
+pub static ACCEPTED_TOKEN_ID: &[u8] = b"USDC-123456";
+pub const USER_BALANCE: u64 = 1_000_000_000;
+
+pub const START_TIME: Timestamp = 10;
+pub const USER_DEPOSIT_TIME: Timestamp = 100;
+pub const OWNER_DEPOSIT_TIME: Timestamp = 100;
+pub const OWNER_REDEEM_TIME: Timestamp = 100;
+pub const USER_MIN_DEPOSIT: u64 = 100;
+
+pub type PriceDiscWrapper = ContractObjWrapper<
+    price_discovery::ContractObj<DebugApi>,
+    fn() -> price_discovery::ContractObj<DebugApi>,
+>;
+
+pub struct FactorySetup<FactoryObjBuilder>
+where
+    FactoryObjBuilder: 'static + Copy + Fn() -> price_discovery_factory::ContractObj<DebugApi>,
+{
+    pub b_mock: BlockchainStateWrapper,
+    pub owner_address: Address,
+    pub sale_owner_address: Address,
+    pub user_address: Address,
+    pub template_wrapper: PriceDiscWrapper,
+    pub factory_wrapper:
+        ContractObjWrapper<price_discovery_factory::ContractObj<DebugApi>, FactoryObjBuilder>,
+}
+
+impl<FactoryObjBuilder> FactorySetup<FactoryObjBuilder>
+where
+    FactoryObjBuilder: 'static + Copy + Fn() -> price_discovery_factory::ContractObj<DebugApi>,
+{
+    pub fn new(factory_builder: FactoryObjBuilder) -> Self {
+        let rust_zero = rust_biguint!(0u64);
+        let mut b_mock = BlockchainStateWrapper::new();
+        let owner_address = b_mock.create_user_account(&rust_zero);
+        let sale_owner_address = b_mock.create_user_account(&rust_zero);
+        let user_address = b_mock.create_user_account(&rust_zero);
+
+        let template_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner_address),
+            price_discovery::contract_obj as fn() -> _,
+            TEMPLATE_WASM_PATH,
+        );
+        let factory_wrapper = b_mock.create_sc_account(
+            &rust_zero,
+            Some(&owner_address),
+            factory_builder,
+            FACTORY_WASM_PATH,
+        );
+
+        b_mock.set_esdt_balance(
+            &user_address,
+            ACCEPTED_TOKEN_ID,
+            &rust_biguint!(USER_BALANCE),
+        );
+        b_mock.set_block_timestamp(START_TIME - 1);
+
+        let template_address = template_wrapper.address_ref().clone();
+        b_mock
+            .execute_tx(&owner_address, &factory_wrapper, &rust_zero, |sc| {
+                sc.init(managed_address!(&template_address));
+            })
+            .assert_ok();
+
+        FactorySetup {
+            b_mock,
+            owner_address,
+            sale_owner_address,
+            user_address,
+            template_wrapper,
+            factory_wrapper,
+        }
+    }
+
+    /// Also gives `USER_BALANCE` launched tokens to the sale owner
+    pub fn deploy_sale(
+        &mut self,
+        launched_token_id: &[u8],
+        start_time: Timestamp,
+    ) -> PriceDiscWrapper {
+        let sale_wrapper = self.b_mock.prepare_deploy_from_sc(
+            self.factory_wrapper.address_ref(),
+            price_discovery::contract_obj as fn() -> _,
+        );
+
+        self.call_deploy_sale(
+            launched_token_id,
+            ACCEPTED_TOKEN_ID,
+            start_time,
+            USER_DEPOSIT_TIME,
+        )
+        .assert_ok();
+        self.b_mock.set_esdt_balance(
+            &self.sale_owner_address,
+            launched_token_id,
+            &rust_biguint!(USER_BALANCE),
+        );
+
+        sale_wrapper
+    }
+
+    pub fn call_deploy_sale(
+        &mut self,
+        launched_token_id: &[u8],
+        accepted_token_id: &[u8],
+        start_time: Timestamp,
+        user_deposit_withdraw_time: Timestamp,
+    ) -> TxResult {
+        self.call_deploy_sale_with_time_base(
+            launched_token_id,
+            accepted_token_id,
+            start_time,
+            user_deposit_withdraw_time,
+            None,
+        )
+    }
+
+    /// The optional init arguments are positional, so a time base is sent
+    /// together with the standard sale mode and the default phase pipeline
+    pub fn call_deploy_sale_with_time_base(
+        &mut self,
+        launched_token_id: &[u8],
+        accepted_token_id: &[u8],
+        start_time: Timestamp,
+        user_deposit_withdraw_time: Timestamp,
+        opt_time_base: Option<TimeBase>,
+    ) -> TxResult {
+        let sale_owner_address = self.sale_owner_address.clone();
+        let admin_address = self.owner_address.clone();
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.factory_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let (opt_sale_mode, opt_phase_pipeline, opt_time_base) = match opt_time_base {
+                    Some(time_base) => {
+                        let mut pipeline = ManagedVec::new();
+                        for (kind, duration) in [
+                            (Phase::UserDepositWithdraw, user_deposit_withdraw_time),
+                            (Phase::OwnerDepositWithdraw, OWNER_DEPOSIT_TIME),
+                            (Phase::OwnerRedeem, OWNER_REDEEM_TIME),
+                        ] {
+                            pipeline.push(PhaseDefinition { kind, duration });
+                        }
+
+                        (
+                            OptionalValue::Some(SaleMode::Standard),
+                            OptionalValue::Some(pipeline),
+                            OptionalValue::Some(time_base),
+                        )
+                    }
+                    None => (
+                        OptionalValue::None,
+                        OptionalValue::None,
+                        OptionalValue::None,
+                    ),
+                };
+                let _ = sc.deploy_sale(
+                    managed_address!(&sale_owner_address),
+                    managed_token_id!(launched_token_id),
+                    managed_token_id_wrapped!(accepted_token_id),
+                    18,
+                    start_time,
+                    user_deposit_withdraw_time,
+                    OWNER_DEPOSIT_TIME,
+                    OWNER_REDEEM_TIME,
+                    managed_biguint!(USER_MIN_DEPOSIT),
+                    managed_address!(&admin_address),
+                    opt_sale_mode,
+                    opt_phase_pipeline,
+                    opt_time_base,
+                );
+            },
+        )
+    }
+
+    pub fn call_set_template_address(&mut self, template_address: &Address) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.factory_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_template_address(managed_address!(template_address));
+            },
+        )
+    }
+
+    pub fn call_upgrade_next_sale(&mut self) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.factory_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.upgrade_next_sale();
+            },
+        )
+    }
+
+    pub fn call_refresh_sale_end_time(&mut self, sale_address: &Address) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.user_address,
+            &self.factory_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.refresh_sale_end_time(managed_address!(sale_address));
+            },
+        )
+    }
+
+    pub fn call_whitelist_user(&mut self, sale_wrapper: &PriceDiscWrapper) -> TxResult {
+        let user_address = self.user_address.clone();
+        self.b_mock
+            .execute_tx(&self.owner_address, sale_wrapper, &rust_biguint!(0), |sc| {
+                let mut pairs = MultiValueEncoded::new();
+                pairs.push((managed_address!(&user_address), managed_biguint!(0)).into());
+                sc.add_users_to_whitelist(pairs);
+            })
+    }
+
+    pub fn call_set_min_launched_tokens(
+        &mut self,
+        sale_wrapper: &PriceDiscWrapper,
+        min_launched_tokens: u64,
+    ) -> TxResult {
+        self.b_mock
+            .execute_tx(&self.owner_address, sale_wrapper, &rust_biguint!(0), |sc| {
+                sc.set_min_launched_tokens(managed_biguint!(min_launched_tokens));
+            })
+    }
+
+    pub fn call_set_user_deposit_withdraw_time(
+        &mut self,
+        sale_wrapper: &PriceDiscWrapper,
+        user_deposit_withdraw_time: Timestamp,
+    ) -> TxResult {
+        self.b_mock
+            .execute_tx(&self.owner_address, sale_wrapper, &rust_biguint!(0), |sc| {
+                sc.set_user_deposit_withdraw_time(user_deposit_withdraw_time);
+            })
+    }
+
+    pub fn sale_end(start_time: Timestamp) -> Timestamp {
+        start_time + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME
+    }
+}
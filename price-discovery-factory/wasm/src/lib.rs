@@ -0,0 +1,43 @@
+// Code generated by the multiversx-sc build system. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+// Init:                                 1
+// Upgrade:                              1
+// Endpoints:                           17
+// Async Callback:                       1
+// Total number of exported functions:  20
+
+#![no_std]
+
+multiversx_sc_wasm_adapter::allocator!();
+multiversx_sc_wasm_adapter::panic_handler!();
+
+multiversx_sc_wasm_adapter::endpoints! {
+    price_discovery_factory
+    (
+        init => init
+        upgrade => upgrade
+        setTemplateAddress => set_template_address
+        deploySale => deploy_sale
+        upgradeNextSale => upgrade_next_sale
+        getSaleByLaunchedToken => get_sale_by_launched_token
+        getAllSales => get_all_sales
+        getActiveSales => get_active_sales
+        getFinishedSales => get_finished_sales
+        getOutdatedSales => get_outdated_sales
+        refreshSaleEndTime => refresh_sale_end_time
+        getTemplateAddress => template_address
+        getTemplateVersion => template_version
+        getSaleInfo => sale_info
+        ownerDeposit => owner_deposit
+        ownerWithdraw => owner_withdraw
+        ownerRedeem => owner_redeem
+        withdrawLaunchpadTokens => withdraw_launchpad_tokens
+        proposeBeneficiary => propose_beneficiary
+    )
+}
+
+multiversx_sc_wasm_adapter::async_callback! { price_discovery_factory }
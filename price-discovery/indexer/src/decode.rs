@@ -0,0 +1,138 @@
+use multiversx_sc::{
+    codec::{DecodeError, TopDecode},
+    types::{Address, BigUint, ManagedAddress},
+};
+use multiversx_sc_scenario::api::StaticApi;
+use price_discovery::events::{
+    FinalizeSaleEvent, OwnerDepositEvent, OwnerWithdrawEvent, RedeemEvent, RefundUserEvent,
+    UserDepositEvent, UserWithdrawEvent, WhitelistUserEvent, WithdrawLaunchpadTokensEvent,
+};
+
+use crate::event_log::{EventHeader, EventLog};
+
+#[derive(Debug, PartialEq)]
+pub enum DecodeEventError {
+    MissingIdentifier,
+    MissingTopics {
+        identifier: String,
+    },
+    InvalidTopic {
+        identifier: String,
+        err: DecodeError,
+    },
+    InvalidData {
+        identifier: String,
+        err: DecodeError,
+    },
+}
+
+/// Events that are not needed to rebuild the sale state are kept as `Other`
+pub enum PriceDiscoveryEvent {
+    UserDeposit(EventHeader, UserDepositEvent<StaticApi>),
+    UserWithdraw(EventHeader, UserWithdrawEvent<StaticApi>),
+    OwnerDeposit(EventHeader, OwnerDepositEvent<StaticApi>),
+    OwnerWithdraw(EventHeader, OwnerWithdrawEvent<StaticApi>),
+    Redeem(EventHeader, RedeemEvent<StaticApi>),
+    RefundUser(EventHeader, RefundUserEvent<StaticApi>),
+    WhitelistUser(EventHeader, WhitelistUserEvent<StaticApi>),
+    SetMinLaunchedTokens(EventHeader, BigUint<StaticApi>),
+    WithdrawLaunchpadTokens(EventHeader, WithdrawLaunchpadTokensEvent<StaticApi>),
+    FinalizeSale(EventHeader, FinalizeSaleEvent<StaticApi>),
+    SetUserLimit {
+        user: Address,
+        limit: BigUint<StaticApi>,
+    },
+    Other {
+        identifier: String,
+    },
+}
+
+pub fn decode_event(log: &EventLog) -> Result<PriceDiscoveryEvent, DecodeEventError> {
+    let (identifier, topics) = log
+        .topics
+        .split_first()
+        .ok_or(DecodeEventError::MissingIdentifier)?;
+    let identifier = String::from_utf8_lossy(identifier).into_owned();
+    let decoder = LogDecoder {
+        identifier: &identifier,
+        topics,
+        data: log.data.first().map(Vec::as_slice).unwrap_or_default(),
+    };
+
+    let event = match identifier.as_str() {
+        "userDepositEvent" => PriceDiscoveryEvent::UserDeposit(decoder.header()?, decoder.data()?),
+        "userWithdrawEvent" => {
+            PriceDiscoveryEvent::UserWithdraw(decoder.header()?, decoder.data()?)
+        }
+        "ownerDepositEvent" => {
+            PriceDiscoveryEvent::OwnerDeposit(decoder.header()?, decoder.data()?)
+        }
+        "ownerWithdrawEvent" => {
+            PriceDiscoveryEvent::OwnerWithdraw(decoder.header()?, decoder.data()?)
+        }
+        "redeemEvent" => PriceDiscoveryEvent::Redeem(decoder.header()?, decoder.data()?),
+        "refundUserEvent" => PriceDiscoveryEvent::RefundUser(decoder.header()?, decoder.data()?),
+        "whitelistUserEvent" => {
+            PriceDiscoveryEvent::WhitelistUser(decoder.header()?, decoder.data()?)
+        }
+        "setMinLaunchedTokensEvent" => {
+            PriceDiscoveryEvent::SetMinLaunchedTokens(decoder.header()?, decoder.data()?)
+        }
+        "withdrawLaunchpadTokensEvent" => {
+            PriceDiscoveryEvent::WithdrawLaunchpadTokens(decoder.header()?, decoder.data()?)
+        }
+        "finalizeSaleEvent" => {
+            PriceDiscoveryEvent::FinalizeSale(decoder.header()?, decoder.data()?)
+        }
+        "setUserLimitEvent" => PriceDiscoveryEvent::SetUserLimit {
+            user: decoder.topic::<ManagedAddress<StaticApi>>(0)?.to_address(),
+            limit: decoder.data()?,
+        },
+        _ => PriceDiscoveryEvent::Other { identifier },
+    };
+
+    Ok(event)
+}
+
+/// Stops at the first log that fails to decode
+pub fn decode_events(logs: &[EventLog]) -> Result<Vec<PriceDiscoveryEvent>, DecodeEventError> {
+    logs.iter().map(decode_event).collect()
+}
+
+struct LogDecoder<'a> {
+    identifier: &'a str,
+    topics: &'a [Vec<u8>],
+    data: &'a [u8],
+}
+
+impl LogDecoder<'_> {
+    fn header(&self) -> Result<EventHeader, DecodeEventError> {
+        Ok(EventHeader {
+            caller: self.topic::<ManagedAddress<StaticApi>>(0)?.to_address(),
+            block: self.topic(1)?,
+            epoch: self.topic(2)?,
+            timestamp: self.topic(3)?,
+        })
+    }
+
+    fn topic<T: TopDecode>(&self, index: usize) -> Result<T, DecodeEventError> {
+        let topic = self
+            .topics
+            .get(index)
+            .ok_or_else(|| DecodeEventError::MissingTopics {
+                identifier: self.identifier.to_string(),
+            })?;
+
+        T::top_decode(topic.as_slice()).map_err(|err| DecodeEventError::InvalidTopic {
+            identifier: self.identifier.to_string(),
+            err,
+        })
+    }
+
+    fn data<T: TopDecode>(&self) -> Result<T, DecodeEventError> {
+        T::top_decode(self.data).map_err(|err| DecodeEventError::InvalidData {
+            identifier: self.identifier.to_string(),
+            err,
+        })
+    }
+}
@@ -0,0 +1,19 @@
+use multiversx_sc::types::Address;
+use price_discovery::{Block, Epoch, Timestamp};
+
+/// A raw log entry, as returned by the API or by the whitebox tests.
+/// The first topic is the event identifier
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventLog {
+    pub topics: Vec<Vec<u8>>,
+    pub data: Vec<Vec<u8>>,
+}
+
+/// The indexed topics shared by the user, owner and configuration events
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventHeader {
+    pub caller: Address,
+    pub block: Block,
+    pub epoch: Epoch,
+    pub timestamp: Timestamp,
+}
@@ -0,0 +1,10 @@
+//! Decodes the event logs of the price discovery contract,
+//! and replays them into the state of the sale, for reconciliation.
+
+pub mod decode;
+pub mod event_log;
+pub mod sale_state;
+
+pub use decode::{decode_event, decode_events, DecodeEventError, PriceDiscoveryEvent};
+pub use event_log::{EventHeader, EventLog};
+pub use sale_state::SaleState;
@@ -0,0 +1,122 @@
+use std::collections::{HashMap, HashSet};
+
+use multiversx_sc::types::{Address, BigUint};
+use multiversx_sc_scenario::api::StaticApi;
+
+use crate::{
+    decode::{decode_events, DecodeEventError, PriceDiscoveryEvent},
+    event_log::EventLog,
+};
+
+/// The state of a sale, as rebuilt from the contract's events.
+/// The balances are taken from the events, so they match the contract storage after each event
+pub struct SaleState {
+    /// The caller of the owner deposits
+    pub owner: Option<Address>,
+    pub accepted_token_balance: BigUint<StaticApi>,
+    pub launched_token_balance: BigUint<StaticApi>,
+    pub implied_price: Option<BigUint<StaticApi>>,
+    pub min_launched_tokens: Option<BigUint<StaticApi>>,
+    /// The deposit limit of each whitelisted user, `0` if there is no limit
+    pub whitelist: HashMap<Address, BigUint<StaticApi>>,
+    pub user_deposits: HashMap<Address, BigUint<StaticApi>>,
+    pub redeemed_users: HashSet<Address>,
+    pub owner_redeemed: bool,
+    pub nr_events: usize,
+}
+
+impl Default for SaleState {
+    fn default() -> Self {
+        SaleState {
+            owner: None,
+            accepted_token_balance: BigUint::zero(),
+            launched_token_balance: BigUint::zero(),
+            implied_price: None,
+            min_launched_tokens: None,
+            whitelist: HashMap::new(),
+            user_deposits: HashMap::new(),
+            redeemed_users: HashSet::new(),
+            owner_redeemed: false,
+            nr_events: 0,
+        }
+    }
+}
+
+impl SaleState {
+    /// The logs must all come from the same contract, in the order they were emitted
+    pub fn replay(logs: &[EventLog]) -> Result<Self, DecodeEventError> {
+        let mut state = SaleState::default();
+        for event in decode_events(logs)? {
+            state.apply(event);
+        }
+
+        Ok(state)
+    }
+
+    pub fn apply(&mut self, event: PriceDiscoveryEvent) {
+        match event {
+            PriceDiscoveryEvent::UserDeposit(header, event) => {
+                self.accepted_token_balance = event.accepted_token_amount;
+                self.launched_token_balance = event.launched_token_amount;
+                self.implied_price = event.implied_price;
+                let _ = self
+                    .user_deposits
+                    .insert(header.caller, event.user_total_deposit);
+            }
+            PriceDiscoveryEvent::UserWithdraw(header, event) => {
+                self.accepted_token_balance = event.accepted_token_amount;
+                self.launched_token_balance = event.launched_token_amount;
+                self.implied_price = event.implied_price;
+                let _ = self
+                    .user_deposits
+                    .insert(header.caller, event.user_total_deposit);
+            }
+            PriceDiscoveryEvent::RefundUser(_, event) => {
+                let user = event.user.to_address();
+                self.accepted_token_balance = event.accepted_token_amount;
+                self.launched_token_balance = event.launched_token_amount;
+                self.implied_price = event.implied_price;
+                let _ = self.whitelist.remove(&user);
+                let _ = self.user_deposits.remove(&user);
+            }
+            PriceDiscoveryEvent::OwnerDeposit(header, event) => {
+                self.owner = Some(header.caller);
+                self.launched_token_balance = event.launched_token_amount;
+            }
+            PriceDiscoveryEvent::OwnerWithdraw(header, event) => {
+                self.owner = Some(header.caller);
+                self.launched_token_balance = event.launched_token_amount;
+            }
+            // the owner redeem is tracked through the finalize event
+            PriceDiscoveryEvent::Redeem(header, _) => {
+                if self.owner.as_ref() != Some(&header.caller) {
+                    let _ = self.user_deposits.remove(&header.caller);
+                    let _ = self.redeemed_users.insert(header.caller);
+                }
+            }
+            PriceDiscoveryEvent::WhitelistUser(_, event) => {
+                let _ = self
+                    .whitelist
+                    .insert(event.user.to_address(), event.deposit_limit);
+            }
+            PriceDiscoveryEvent::SetUserLimit { user, limit } => {
+                let _ = self.whitelist.insert(user, limit);
+            }
+            PriceDiscoveryEvent::SetMinLaunchedTokens(_, min_launched_tokens) => {
+                self.min_launched_tokens = Some(min_launched_tokens);
+            }
+            PriceDiscoveryEvent::FinalizeSale(header, _) => {
+                self.owner = Some(header.caller);
+                self.owner_redeemed = true;
+            }
+            PriceDiscoveryEvent::WithdrawLaunchpadTokens(header, _) => {
+                self.owner = Some(header.caller);
+                self.owner_redeemed = true;
+                self.launched_token_balance = BigUint::zero();
+            }
+            PriceDiscoveryEvent::Other { .. } => {}
+        }
+
+        self.nr_events += 1;
+    }
+}
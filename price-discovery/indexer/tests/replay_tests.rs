@@ -0,0 +1,157 @@
+#![allow(deprecated)]
+
+#[path = "../../tests/tests_common.rs"]
+mod tests_common;
+
+use multiversx_sc::types::MultiValueEncoded;
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, rust_biguint, whitebox_legacy::TxResult,
+};
+use price_discovery::{
+    common_storage::CommonStorageModule,
+    user_actions::{
+        admin_actions::AdminActionsModule, user_deposit_withdraw::UserDepositWithdrawModule,
+    },
+};
+use price_discovery_indexer::{
+    decode_event, DecodeEventError, EventLog, PriceDiscoveryEvent, SaleState,
+};
+use tests_common::*;
+
+fn event_logs(result: &TxResult) -> Vec<EventLog> {
+    result
+        .result_logs
+        .iter()
+        .map(|log| EventLog {
+            topics: log.topics.clone(),
+            data: log.data.clone(),
+        })
+        .collect()
+}
+
+fn collect_logs(logs: &mut Vec<EventLog>, result: TxResult) {
+    result.assert_ok();
+    logs.extend(event_logs(&result));
+}
+
+#[test]
+fn decode_user_deposit_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let first_user = setup.first_user_address.clone();
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+
+    let result = setup.call_user_deposit(&first_user, 1_000);
+    result.assert_ok();
+    let logs = event_logs(&result);
+    assert_eq!(logs.len(), 1);
+
+    match decode_event(&logs[0]).unwrap() {
+        PriceDiscoveryEvent::UserDeposit(header, event) => {
+            assert_eq!(header.caller, first_user);
+            assert_eq!(header.timestamp, START_TIME + 1);
+            assert_eq!(event.token_amount_in, managed_biguint!(1_000));
+            assert_eq!(event.user_total_deposit, managed_biguint!(1_000));
+            assert_eq!(event.accepted_token_amount, managed_biguint!(1_000));
+            assert_eq!(event.launched_token_amount, managed_biguint!(0));
+            assert!(event.implied_price.is_none());
+        }
+        _ => panic!("Invalid event"),
+    }
+
+    let mut missing_topics = logs[0].clone();
+    missing_topics.topics.truncate(2);
+    assert!(matches!(
+        decode_event(&missing_topics),
+        Err(DecodeEventError::MissingTopics { .. })
+    ));
+    assert!(matches!(
+        decode_event(&EventLog {
+            topics: Vec::new(),
+            data: Vec::new(),
+        }),
+        Err(DecodeEventError::MissingIdentifier)
+    ));
+}
+
+#[test]
+fn replay_sale_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let owner = setup.owner_address.clone();
+    let first_user = setup.first_user_address.clone();
+    let second_user = setup.second_user_address.clone();
+    let third_user = setup.b_mock.create_user_account(&rust_biguint!(0));
+    let mut logs = Vec::new();
+
+    collect_logs(
+        &mut logs,
+        setup
+            .b_mock
+            .execute_tx(&owner, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+                let mut pairs = MultiValueEncoded::new();
+                pairs.push((managed_address!(&third_user), managed_biguint!(5_000)).into());
+                sc.add_users_to_whitelist(pairs);
+                sc.set_min_launched_tokens(managed_biguint!(2_000));
+            }),
+    );
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    let result = setup.call_user_deposit(&first_user, 1_000);
+    collect_logs(&mut logs, result);
+    let result = setup.call_user_deposit(&second_user, 2_000);
+    collect_logs(&mut logs, result);
+    let result = setup.call_user_withdraw(&first_user, 400);
+    collect_logs(&mut logs, result);
+    let result = setup.call_set_user_limit(&second_user, 20_000);
+    collect_logs(&mut logs, result);
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+    let result = setup.call_owner_deposit(5_000);
+    collect_logs(&mut logs, result);
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + 1);
+    let result = setup.call_owner_redeem();
+    collect_logs(&mut logs, result);
+
+    setup.b_mock.set_block_timestamp(
+        START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME + 1,
+    );
+    let result = setup.call_user_redeem(&first_user);
+    collect_logs(&mut logs, result);
+
+    let state = SaleState::replay(&logs).unwrap();
+    assert_eq!(state.nr_events, logs.len());
+    assert_eq!(state.owner, Some(owner));
+    assert!(state.owner_redeemed);
+    assert!(state.redeemed_users.contains(&first_user));
+    assert!(!state.user_deposits.contains_key(&first_user));
+    assert_eq!(state.whitelist[&third_user], managed_biguint!(5_000));
+    assert_eq!(state.whitelist[&second_user], managed_biguint!(20_000));
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(
+                state.accepted_token_balance.to_u64(),
+                sc.accepted_token_balance().get().to_u64()
+            );
+            assert_eq!(
+                state.launched_token_balance.to_u64(),
+                sc.launched_token_balance().get().to_u64()
+            );
+            assert_eq!(
+                state.min_launched_tokens.as_ref().unwrap().to_u64(),
+                sc.min_launched_tokens().get().to_u64()
+            );
+
+            let second_user_id = sc.user_id_mapper().get_id(&managed_address!(&second_user));
+            assert_eq!(
+                state.user_deposits[&second_user].to_u64(),
+                sc.total_deposit_by_user(second_user_id).get().to_u64()
+            );
+        })
+        .assert_ok();
+}
@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use multiversx_sc_snippets::imports::*;
+use serde_json::json;
+
+/// Big enough to pay for the gas of any interaction
+const WALLET_BALANCE: &str = "100000000000000000000000";
+const BLOCK_INTERVAL: Duration = Duration::from_millis(200);
+
+/// The chain simulator only produces blocks on request
+pub struct ChainSimulator {
+    gateway_uri: String,
+    client: reqwest::Client,
+}
+
+impl ChainSimulator {
+    pub fn new(gateway_uri: &str) -> Self {
+        ChainSimulator {
+            gateway_uri: gateway_uri.trim_end_matches('/').to_string(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub async fn fund_wallet(&self, address: &Bech32Address) {
+        let accounts = json!([{
+            "address": address.to_bech32_string(),
+            "balance": WALLET_BALANCE,
+        }]);
+
+        self.client
+            .post(format!("{}/simulator/set-state", self.gateway_uri))
+            .json(&accounts)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .expect("failed to fund wallet on the chain simulator");
+    }
+
+    pub async fn generate_blocks(&self, nr_blocks: u64) {
+        self.client
+            .post(format!(
+                "{}/simulator/generate-blocks/{nr_blocks}",
+                self.gateway_uri
+            ))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .expect("failed to generate blocks on the chain simulator");
+    }
+
+    /// Keeps generating blocks in the background, so the sent transactions get executed
+    pub fn spawn_block_generator(self) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                self.generate_blocks(1).await;
+                tokio::time::sleep(BLOCK_INTERVAL).await;
+            }
+        })
+    }
+}
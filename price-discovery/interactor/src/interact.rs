@@ -0,0 +1,1395 @@
+mod chain_simulator;
+mod interact_cli;
+mod interact_config;
+mod interact_state;
+
+use clap::Parser;
+use multiversx_sc_snippets::imports::*;
+
+pub use chain_simulator::ChainSimulator;
+pub use interact_cli::{InteractCli, InteractCliCommand};
+pub use interact_config::{ChainType, Config};
+use interact_state::State;
+pub use price_discovery::price_discovery_proxy;
+use price_discovery_proxy::{
+    BoardAction, BondingCurve, ParameterChange, Phase, Role, SaleMode, SimulationResult, TimeBase,
+};
+
+const PRICE_DISCOVERY_CODE_PATH: MxscPath = MxscPath::new("../output/price-discovery.mxsc.json");
+
+const INTERACTOR_SCENARIO_TRACE_PATH: &str = "interactor_trace.scen.json";
+
+const ROLES: [Role; 4] = [
+    Role::TimingManager,
+    Role::WhitelistManager,
+    Role::RefundOperator,
+    Role::Pauser,
+];
+
+const DEPLOY_GAS: u64 = 200_000_000;
+const CALL_GAS: u64 = 30_000_000;
+
+pub async fn price_discovery_cli() {
+    env_logger::init();
+
+    let cli = InteractCli::parse();
+    let mut interact = PriceDiscoveryInteract::new(Config::load_config()).await;
+    match cli.command {
+        Some(InteractCliCommand::Deploy) => interact.deploy().await,
+        Some(InteractCliCommand::Upgrade) => interact.upgrade().await,
+        Some(InteractCliCommand::AddToWhitelist(args)) => {
+            interact
+                .add_users_to_whitelist(&args.address, args.limit)
+                .await
+        }
+        Some(InteractCliCommand::SetUserLimit(args)) => {
+            interact.set_user_limit(&args.address, args.limit).await
+        }
+        Some(InteractCliCommand::RefundUsers(args)) => interact.refund_users(&args.addresses).await,
+        Some(InteractCliCommand::UserDeposit(args)) => {
+            interact.user_deposit(args.amount, args.max_price).await
+        }
+        Some(InteractCliCommand::UserWithdraw(args)) => interact.user_withdraw(args.amount).await,
+        Some(InteractCliCommand::OwnerDeposit(args)) => interact.owner_deposit(args.amount).await,
+        Some(InteractCliCommand::OwnerWithdraw(args)) => interact.owner_withdraw(args.amount).await,
+        Some(InteractCliCommand::UserRedeem) => interact.user_redeem().await,
+        Some(InteractCliCommand::OwnerRedeem) => interact.owner_redeem().await,
+        Some(InteractCliCommand::CurrentPhase) => interact.print_current_phase().await,
+        Some(InteractCliCommand::PhaseSchedule) => interact.print_phase_schedule().await,
+        Some(InteractCliCommand::CurrentPrice) => interact.print_current_price().await,
+        Some(InteractCliCommand::ContractState) => interact.print_contract_state().await,
+        Some(InteractCliCommand::UserInfo(args)) => interact.print_user_info(&args.address).await,
+        Some(InteractCliCommand::Participants(args)) => {
+            interact.print_participants(args.offset, args.limit).await
+        }
+        Some(InteractCliCommand::SimulateDeposit(args)) => {
+            interact
+                .print_simulate_deposit(&args.address, args.amount, args.max_price)
+                .await
+        }
+        Some(InteractCliCommand::SimulateWithdraw(args)) => {
+            interact
+                .print_simulate_withdraw(&args.address, args.amount)
+                .await
+        }
+        Some(InteractCliCommand::TimeUntilNextPhase) => {
+            interact.print_time_until_next_phase().await
+        }
+        Some(InteractCliCommand::PhasePipeline) => interact.print_phase_pipeline().await,
+        Some(InteractCliCommand::SaleConfig) => interact.print_sale_config().await,
+        Some(InteractCliCommand::SaleStats) => interact.print_sale_stats().await,
+        Some(InteractCliCommand::PriceHistory(args)) => {
+            interact.print_price_history(args.from, args.to).await
+        }
+        Some(InteractCliCommand::Twap) => interact.print_twap().await,
+        Some(InteractCliCommand::TwapBalance(args)) => {
+            interact.print_twap_balance(args.from, args.to).await
+        }
+        Some(InteractCliCommand::AuctionPrice) => interact.print_auction_price().await,
+        Some(InteractCliCommand::LimitOrders) => interact.print_limit_orders().await,
+        Some(InteractCliCommand::DemandAtPrice(args)) => {
+            interact.print_demand_at_price(args.price).await
+        }
+        Some(InteractCliCommand::Roles) => interact.print_roles().await,
+        Some(InteractCliCommand::HasRole(args)) => {
+            interact.print_has_role(&args.role, &args.address).await
+        }
+        Some(InteractCliCommand::PendingChanges) => interact.print_pending_changes().await,
+        Some(InteractCliCommand::Board) => interact.print_board().await,
+        Some(InteractCliCommand::UserDepositState(args)) => {
+            interact.print_user_deposit_state(&args.address).await
+        }
+        Some(InteractCliCommand::Beneficiary) => interact.print_beneficiary().await,
+        None => {}
+    }
+}
+
+pub struct PriceDiscoveryInteract {
+    pub interactor: Interactor,
+    pub owner_address: Address,
+    pub user_address: Address,
+    pub config: Config,
+    state: State,
+    block_generator: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl PriceDiscoveryInteract {
+    /// On the chain simulator, the wallets are funded and the blocks are generated in the background
+    pub async fn new(config: Config) -> Self {
+        let mut interactor = Interactor::new(&config.gateway_uri)
+            .await
+            .with_tracer(INTERACTOR_SCENARIO_TRACE_PATH)
+            .await;
+
+        let owner_wallet = load_wallet(&config.owner_pem, test_wallets::alice);
+        let user_wallet = load_wallet(&config.user_pem, test_wallets::bob);
+        let owner_address = interactor.register_wallet(owner_wallet);
+        let user_address = interactor.register_wallet(user_wallet);
+
+        let block_generator = if config.is_chain_simulator() {
+            let chain_simulator = ChainSimulator::new(&config.gateway_uri);
+            chain_simulator
+                .fund_wallet(&Bech32Address::from(&owner_address))
+                .await;
+            chain_simulator
+                .fund_wallet(&Bech32Address::from(&user_address))
+                .await;
+
+            Some(chain_simulator.spawn_block_generator())
+        } else {
+            None
+        };
+
+        PriceDiscoveryInteract {
+            interactor,
+            owner_address,
+            user_address,
+            config,
+            state: State::load_state(),
+            block_generator,
+        }
+    }
+
+    pub async fn deploy(&mut self) {
+        let deploy_config = &self.config.deploy;
+        let admin = match &deploy_config.admin {
+            Some(admin) => Bech32Address::from_bech32_string(admin.clone()).to_address(),
+            None => self.owner_address.clone(),
+        };
+
+        let new_address = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .gas(DEPLOY_GAS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .init(
+                TokenIdentifier::from(deploy_config.launched_token_id.as_str()),
+                parse_token_id(&deploy_config.accepted_token_id),
+                deploy_config.launched_token_decimals,
+                deploy_config.start_time,
+                deploy_config.user_deposit_withdraw_time,
+                deploy_config.owner_deposit_withdraw_time,
+                deploy_config.owner_redeem_time,
+                BigUint::<StaticApi>::from(parse_amount(&deploy_config.user_min_deposit)),
+                admin,
+                OptionalValue::<SaleMode<StaticApi>>::None,
+                OptionalValue::<ManagedVec<StaticApi, price_discovery_proxy::PhaseDefinition>>::None,
+                OptionalValue::<TimeBase>::None,
+            )
+            .code(PRICE_DISCOVERY_CODE_PATH)
+            .returns(ReturnsNewBech32Address)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("new address: {new_address}");
+        self.state.set_address(new_address);
+    }
+
+    pub async fn upgrade(&mut self) {
+        self.interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(self.state.current_address())
+            .gas(DEPLOY_GAS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .upgrade()
+            .code(PRICE_DISCOVERY_CODE_PATH)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("upgraded: {}", self.state.current_address());
+    }
+
+    /// Pass `0` for `limit` if there is no limit
+    pub async fn add_users_to_whitelist(&mut self, address: &str, limit: u128) {
+        let whitelist = MultiValueVec::from(vec![MultiValue2::from((
+            parse_address(address),
+            BigUint::<StaticApi>::from(limit),
+        ))]);
+
+        self.interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(self.state.current_address())
+            .gas(CALL_GAS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .add_users_to_whitelist(whitelist)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("whitelisted {address}");
+    }
+
+    pub async fn set_user_limit(&mut self, address: &str, limit: u128) {
+        self.interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(self.state.current_address())
+            .gas(CALL_GAS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .set_user_limit(parse_address(address), BigUint::<StaticApi>::from(limit))
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("set limit of {address} to {limit}");
+    }
+
+    pub async fn refund_users(&mut self, addresses: &[String]) {
+        let users = MultiValueVec::from(
+            addresses
+                .iter()
+                .map(|address| parse_address(address))
+                .collect::<Vec<_>>(),
+        );
+
+        self.interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(self.state.current_address())
+            .gas(CALL_GAS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .refund_users(users)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("refunded {} users", addresses.len());
+    }
+
+    /// Without `max_price`, the whole deposit is accepted at any price
+    pub async fn user_deposit(&mut self, amount: u128, max_price: Option<u128>) {
+        let opt_max_price: OptionalValue<BigUint<StaticApi>> = max_price.map(BigUint::from).into();
+        let payment = EgldOrEsdtTokenPayment::<StaticApi>::new(
+            parse_token_id(&self.config.deploy.accepted_token_id),
+            0,
+            BigUint::from(amount),
+        );
+
+        self.interactor
+            .tx()
+            .from(&self.user_address)
+            .to(self.state.current_address())
+            .gas(CALL_GAS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .user_deposit(opt_max_price)
+            .payment(payment)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("deposited {amount}");
+    }
+
+    pub async fn user_withdraw(&mut self, amount: u128) {
+        self.interactor
+            .tx()
+            .from(&self.user_address)
+            .to(self.state.current_address())
+            .gas(CALL_GAS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .user_withdraw_endpoint(BigUint::<StaticApi>::from(amount))
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("withdrew {amount}");
+    }
+
+    pub async fn owner_deposit(&mut self, amount: u128) {
+        let launched_token_id =
+            TokenIdentifier::<StaticApi>::from(self.config.deploy.launched_token_id.as_str());
+
+        self.interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(self.state.current_address())
+            .gas(CALL_GAS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .owner_deposit()
+            .single_esdt(&launched_token_id, 0, &BigUint::from(amount))
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("owner deposited {amount}");
+    }
+
+    pub async fn owner_withdraw(&mut self, amount: u128) {
+        self.interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(self.state.current_address())
+            .gas(CALL_GAS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .owner_withdraw(BigUint::<StaticApi>::from(amount))
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("owner withdrew {amount}");
+    }
+
+    pub async fn user_redeem(&mut self) {
+        let payment = self
+            .interactor
+            .tx()
+            .from(&self.user_address)
+            .to(self.state.current_address())
+            .gas(CALL_GAS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .user_redeem_endpoint()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        print_payment("redeemed", &payment);
+    }
+
+    pub async fn owner_redeem(&mut self) {
+        let payment = self
+            .interactor
+            .tx()
+            .from(&self.owner_address)
+            .to(self.state.current_address())
+            .gas(CALL_GAS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .owner_redeem_endpoint()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        print_payment("owner redeemed", &payment);
+    }
+
+    pub async fn current_phase(&mut self) -> Phase {
+        self.interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_current_phase()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await
+    }
+
+    pub async fn print_current_phase(&mut self) {
+        let phase = self.current_phase().await;
+        println!("current phase: {}", phase_name(&phase));
+    }
+
+    pub async fn print_phase_schedule(&mut self) {
+        let schedule = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_phase_schedule()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        for interval in schedule.into_iter() {
+            let end = interval
+                .end
+                .map_or_else(|| "-".to_string(), |end| end.to_string());
+            println!(
+                "{}: {} - {end}",
+                phase_name(&interval.phase),
+                interval.start
+            );
+        }
+    }
+
+    pub async fn print_current_price(&mut self) {
+        let price = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_current_price()
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("current price: {price}");
+    }
+
+    pub async fn print_contract_state(&mut self) {
+        let state = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_contract_state()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("launched token: {}", state.launched_token_id);
+        println!(
+            "accepted token: {}",
+            format_token_id(&state.accepted_token_id)
+        );
+        println!(
+            "launched token balance: {}",
+            state.launched_token_balance.to_display()
+        );
+        println!(
+            "accepted token balance: {}",
+            state.accepted_token_balance.to_display()
+        );
+        println!(
+            "min launched tokens: {}",
+            state.min_launched_tokens.to_display()
+        );
+        println!("user min deposit: {}", state.user_min_deposit.to_display());
+        println!("current price: {}", format_optional(&state.current_price));
+        println!("current phase: {}", phase_name(&state.current_phase));
+        println!("start time: {}", state.start_time);
+        println!(
+            "user deposit/withdraw end: {}",
+            state.user_deposit_withdraw_end
+        );
+        println!(
+            "owner deposit/withdraw end: {}",
+            state.owner_deposit_withdraw_end
+        );
+        println!("owner redeem end: {}", state.owner_redeem_end);
+        println!(
+            "min launched tokens reached: {}",
+            state.min_launched_tokens_reached
+        );
+        println!("owner redeemed: {}", state.owner_redeemed);
+        println!("paused: {}", state.paused);
+        println!("owner: {}", Bech32Address::from(state.owner.to_address()));
+        println!("admin: {}", Bech32Address::from(state.admin.to_address()));
+    }
+
+    pub async fn print_user_info(&mut self, address: &str) {
+        let info = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_user_info(parse_address(address))
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("user id: {}", info.user_id);
+        println!("whitelisted: {}", info.whitelisted);
+        println!("total deposit: {}", info.total_deposit.to_display());
+        println!("deposit limit: {}", format_optional(&info.deposit_limit));
+        println!("redeemed: {}", info.redeemed);
+        println!(
+            "projected bought tokens: {}",
+            info.projected_bought_tokens.to_display()
+        );
+        println!(
+            "redeem now: {} launched tokens, {} refunded",
+            info.redeem_amounts.bought_tokens.to_display(),
+            info.redeem_amounts.refund_amount.to_display()
+        );
+    }
+
+    pub async fn print_participants(&mut self, offset: usize, limit: usize) {
+        let participants = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_participants(offset, limit)
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        for participant in participants.into_iter() {
+            let (address, total_deposit, deposit_limit, redeemed) = participant.into_tuple();
+            println!(
+                "{}: deposit {}, limit {}, redeemed {redeemed}",
+                Bech32Address::from(address.to_address()),
+                total_deposit.to_display(),
+                deposit_limit.to_display()
+            );
+        }
+    }
+
+    pub async fn print_simulate_deposit(
+        &mut self,
+        address: &str,
+        amount: u128,
+        max_price: Option<u128>,
+    ) {
+        let opt_max_price: OptionalValue<BigUint<StaticApi>> = max_price.map(BigUint::from).into();
+        let result = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .simulate_deposit(
+                parse_address(address),
+                BigUint::<StaticApi>::from(amount),
+                opt_max_price,
+            )
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        print_simulation(result);
+    }
+
+    pub async fn print_simulate_withdraw(&mut self, address: &str, amount: u128) {
+        let result = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .simulate_withdraw(parse_address(address), BigUint::<StaticApi>::from(amount))
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        print_simulation(result);
+    }
+
+    pub async fn print_time_until_next_phase(&mut self) {
+        let time_left = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_time_until_next_phase()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        match time_left.into_option() {
+            Some(time_left) => println!("time until next phase: {time_left}"),
+            None => println!("time until next phase: -"),
+        }
+    }
+
+    pub async fn print_phase_pipeline(&mut self) {
+        let start_time = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .start_time()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let time_base = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .time_base()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let pipeline = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .phase_pipeline()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let user_deposit_withdraw_time = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_user_deposit_withdraw_time()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let owner_deposit_withdraw_time = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_owner_deposit_withdraw_time()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let owner_redeem_time = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_owner_redeem_time()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("start time: {start_time}");
+        println!("time base: {}", time_base_name(&time_base));
+        for (index, phase) in pipeline.into_iter().enumerate() {
+            println!(
+                "phase {}: {} for {}",
+                index + 1,
+                phase_name(&phase.kind),
+                phase.duration
+            );
+        }
+        println!("user deposit/withdraw time: {user_deposit_withdraw_time}");
+        println!("owner deposit/withdraw time: {owner_deposit_withdraw_time}");
+        println!("owner redeem time: {owner_redeem_time}");
+    }
+
+    pub async fn print_sale_config(&mut self) {
+        let sale_mode = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .sale_mode()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let min_launched_tokens = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .min_launched_tokens()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let user_min_deposit = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .user_min_deposit()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let target_raise = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .target_raise()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let twap_settlement_window = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .twap_settlement_window()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let soft_close_config = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .soft_close_config()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let soft_close_total_extension = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .soft_close_total_extension()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let timelock_delay = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .timelock_delay()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("sale mode: {}", format_sale_mode(&sale_mode));
+        println!("min launched tokens: {}", min_launched_tokens.to_display());
+        println!("user min deposit: {}", user_min_deposit.to_display());
+        println!("target raise: {}", target_raise.to_display());
+        println!("TWAP settlement window: {twap_settlement_window}");
+        println!(
+            "soft close: trigger window {}, extension {}, max total extension {}",
+            soft_close_config.trigger_window,
+            soft_close_config.extension,
+            soft_close_config.max_total_extension
+        );
+        println!("soft close total extension: {soft_close_total_extension}");
+        println!("timelock delay: {timelock_delay}");
+    }
+
+    pub async fn print_sale_stats(&mut self) {
+        let nr_whitelisted_users = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_nr_whitelisted_users()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let nr_depositors = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .nr_depositors()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let nr_redeemers = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .nr_redeemers()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let bonding_curve_tokens_sold = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .bonding_curve_tokens_sold()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("whitelisted users: {nr_whitelisted_users}");
+        println!("depositors: {nr_depositors}");
+        println!("redeemers: {nr_redeemers}");
+        println!(
+            "bonding curve tokens sold: {}",
+            bonding_curve_tokens_sold.to_display()
+        );
+    }
+
+    pub async fn print_price_history(&mut self, from: u64, to: u64) {
+        let checkpoints = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_price_history(from, to)
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        for checkpoint in checkpoints.into_iter() {
+            println!(
+                "{}: accepted {}, launched {}, cumulative accepted {}",
+                checkpoint.timestamp,
+                checkpoint.accepted_token_balance.to_display(),
+                checkpoint.launched_token_balance.to_display(),
+                checkpoint.cumulative_accepted_balance.to_display()
+            );
+        }
+    }
+
+    pub async fn print_twap(&mut self) {
+        let average_price = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_time_weighted_average_price()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let average_accepted_balance = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_user_phase_average_accepted_balance()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let settlement_price = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_twap_settlement_price()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!(
+            "time-weighted average price: {}",
+            format_optional(&average_price.into_option())
+        );
+        println!(
+            "time-weighted accepted balance: {}",
+            average_accepted_balance.to_display()
+        );
+        println!("TWAP settlement price: {}", settlement_price.to_display());
+    }
+
+    pub async fn print_twap_balance(&mut self, from: u64, to: u64) {
+        let average_accepted_balance = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_time_weighted_accepted_balance(from, to)
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!(
+            "time-weighted accepted balance: {}",
+            average_accepted_balance.to_display()
+        );
+    }
+
+    pub async fn print_auction_price(&mut self) {
+        let price = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_auction_price()
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("auction price: {price}");
+    }
+
+    pub async fn print_limit_orders(&mut self) {
+        let demand = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_demand_by_max_price()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let clearing_price = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_limit_order_clearing_price()
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        for entry in demand.into_iter() {
+            let (max_price, total_deposit) = entry.into_tuple();
+            println!(
+                "max price {}: deposit {}",
+                max_price.to_display(),
+                total_deposit.to_display()
+            );
+        }
+        println!("clearing price: {clearing_price}");
+    }
+
+    pub async fn print_demand_at_price(&mut self, price: u128) {
+        let demand = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_demand_at_price(BigUint::<StaticApi>::from(price))
+            .returns(ReturnsResultUnmanaged)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("demand at price {price}: {demand}");
+    }
+
+    pub async fn print_roles(&mut self) {
+        let admin = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .admin()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let pending_admin = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_pending_admin()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let paused = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .paused()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("admin: {}", format_address(&admin));
+        println!(
+            "pending admin: {}",
+            format_optional_address(&pending_admin.into_option())
+        );
+        println!("paused: {paused}");
+        for role in ROLES {
+            let holders = self
+                .interactor
+                .query()
+                .to(self.state.current_address())
+                .typed(price_discovery_proxy::PriceDiscoveryProxy)
+                .get_role_holders(role)
+                .returns(ReturnsResult)
+                .prepare_async()
+                .run()
+                .await;
+
+            let holders = holders
+                .into_iter()
+                .map(|holder| format_address(&holder).to_string())
+                .collect::<Vec<_>>();
+            println!("{}: {}", role_name(&role), holders.join(", "));
+        }
+    }
+
+    pub async fn print_has_role(&mut self, role: &str, address: &str) {
+        let has_role = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .has_role(parse_role(role), parse_address(address))
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("{address} has role {role}: {has_role}");
+    }
+
+    pub async fn print_pending_changes(&mut self) {
+        let pending_changes = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_pending_changes()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        for pending_change in pending_changes.into_iter() {
+            println!(
+                "{}: {}, executable at {}",
+                pending_change.id,
+                format_parameter_change(&pending_change.change),
+                pending_change.executable_at
+            );
+        }
+    }
+
+    pub async fn print_board(&mut self) {
+        let quorum = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .quorum()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let members = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_board_members()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let action_ids = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_pending_action_ids()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("quorum: {quorum}");
+        for member in members.into_iter() {
+            println!("member: {}", format_address(&member));
+        }
+        for action_id in action_ids.into_iter() {
+            let action = self
+                .interactor
+                .query()
+                .to(self.state.current_address())
+                .typed(price_discovery_proxy::PriceDiscoveryProxy)
+                .get_action_data(action_id)
+                .returns(ReturnsResult)
+                .prepare_async()
+                .run()
+                .await;
+            let signers = self
+                .interactor
+                .query()
+                .to(self.state.current_address())
+                .typed(price_discovery_proxy::PriceDiscoveryProxy)
+                .get_action_signers(action_id)
+                .returns(ReturnsResult)
+                .prepare_async()
+                .run()
+                .await;
+
+            let signers = signers
+                .into_iter()
+                .map(|signer| format_address(&signer).to_string())
+                .collect::<Vec<_>>();
+            println!(
+                "action {action_id}: {}, signed by {}",
+                board_action_name(&action),
+                signers.join(", ")
+            );
+        }
+    }
+
+    pub async fn print_user_deposit_state(&mut self, address: &str) {
+        let whitelisted = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .is_user_whitelisted(parse_address(address))
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let deposit_limit = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_user_deposit_limit(parse_address(address))
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let total_deposit = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_total_deposit_by_user(parse_address(address))
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("whitelisted: {whitelisted}");
+        println!(
+            "deposit limit: {}",
+            format_optional(&deposit_limit.into_option())
+        );
+        println!("total deposit: {}", total_deposit.to_display());
+    }
+
+    pub async fn print_beneficiary(&mut self) {
+        let beneficiary = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_owner_redeem_beneficiary()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+        let pending_beneficiary = self
+            .interactor
+            .query()
+            .to(self.state.current_address())
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_pending_beneficiary()
+            .returns(ReturnsResult)
+            .prepare_async()
+            .run()
+            .await;
+
+        println!("beneficiary: {}", format_address(&beneficiary));
+        println!(
+            "pending beneficiary: {}",
+            format_optional_address(&pending_beneficiary.into_option())
+        );
+    }
+}
+
+impl Drop for PriceDiscoveryInteract {
+    fn drop(&mut self) {
+        if let Some(block_generator) = self.block_generator.take() {
+            block_generator.abort();
+        }
+    }
+}
+
+fn load_wallet(pem_path: &Option<String>, default_wallet: fn() -> Wallet) -> Wallet {
+    match pem_path {
+        Some(pem_path) => Wallet::from_pem_file(pem_path).expect("invalid PEM file"),
+        None => default_wallet(),
+    }
+}
+
+fn parse_address(address: &str) -> Address {
+    Bech32Address::from_bech32_string(address.to_string()).to_address()
+}
+
+fn parse_amount(amount: &str) -> u128 {
+    amount.parse().expect("invalid amount")
+}
+
+/// `EGLD` stands for the native token, any other value is an ESDT identifier
+fn parse_token_id(token_id: &str) -> EgldOrEsdtTokenIdentifier<StaticApi> {
+    if token_id == "EGLD" {
+        EgldOrEsdtTokenIdentifier::egld()
+    } else {
+        EgldOrEsdtTokenIdentifier::esdt(token_id)
+    }
+}
+
+fn format_token_id(token_id: &EgldOrEsdtTokenIdentifier<StaticApi>) -> String {
+    if token_id.is_egld() {
+        "EGLD".to_string()
+    } else {
+        token_id.clone().unwrap_esdt().to_string()
+    }
+}
+
+fn print_payment(action: &str, payment: &EgldOrEsdtTokenPayment<StaticApi>) {
+    println!(
+        "{action} {} {}",
+        payment.amount.to_display(),
+        format_token_id(&payment.token_identifier)
+    );
+}
+
+fn format_optional(value: &Option<BigUint<StaticApi>>) -> String {
+    value
+        .as_ref()
+        .map_or_else(|| "-".to_string(), |value| value.to_display().to_string())
+}
+
+/// Role names as listed by `role_name`
+fn parse_role(role: &str) -> Role {
+    ROLES
+        .into_iter()
+        .find(|candidate| role_name(candidate) == role)
+        .expect("invalid role")
+}
+
+fn format_address(address: &ManagedAddress<StaticApi>) -> Bech32Address {
+    Bech32Address::from(address.to_address())
+}
+
+fn format_optional_address(address: &Option<ManagedAddress<StaticApi>>) -> String {
+    address.as_ref().map_or_else(
+        || "-".to_string(),
+        |address| format_address(address).to_string(),
+    )
+}
+
+fn format_sale_mode(sale_mode: &SaleMode<StaticApi>) -> String {
+    match sale_mode {
+        SaleMode::Standard => "Standard".to_string(),
+        SaleMode::DutchAuction(config) => format!(
+            "DutchAuction: max price {}, min price {}, price step duration {}",
+            config.max_price.to_display(),
+            config.min_price.to_display(),
+            config.price_step_duration
+        ),
+        SaleMode::BondingCurve(BondingCurve::Linear {
+            initial_price,
+            slope,
+        }) => format!(
+            "linear BondingCurve: initial price {}, slope {}",
+            initial_price.to_display(),
+            slope.to_display()
+        ),
+        SaleMode::BondingCurve(BondingCurve::Exponential {
+            initial_price,
+            growth_step,
+            growth_rate,
+        }) => format!(
+            "exponential BondingCurve: initial price {}, growth step {}, growth rate {growth_rate}",
+            initial_price.to_display(),
+            growth_step.to_display()
+        ),
+    }
+}
+
+fn format_parameter_change(change: &ParameterChange<StaticApi>) -> String {
+    match change {
+        ParameterChange::PhaseDuration {
+            phase_index,
+            kind,
+            duration,
+        } => format!(
+            "set phase {phase_index} ({}) duration to {duration}",
+            phase_name(kind)
+        ),
+        ParameterChange::MinLaunchedTokens(min_launched_tokens) => format!(
+            "set min launched tokens to {}",
+            min_launched_tokens.to_display()
+        ),
+        ParameterChange::StartTime(start_time) => format!("set start time to {start_time}"),
+        ParameterChange::AddCooldownPhase {
+            phase_index,
+            duration,
+        } => format!("add cooldown phase {phase_index} for {duration}"),
+        ParameterChange::RemoveCooldownPhase(phase_index) => {
+            format!("remove cooldown phase {phase_index}")
+        }
+        ParameterChange::CloseUserPhase => "close user phase".to_string(),
+    }
+}
+
+fn board_action_name(action: &BoardAction<StaticApi>) -> &'static str {
+    match action {
+        BoardAction::Nothing => "Nothing",
+        BoardAction::SetMinLaunchedTokens(_) => "SetMinLaunchedTokens",
+        BoardAction::RefundUsers(_) => "RefundUsers",
+        BoardAction::SetUserLimit(_, _) => "SetUserLimit",
+        BoardAction::SetStartTime(_) => "SetStartTime",
+        BoardAction::SetUserDepositWithdrawTime(_) => "SetUserDepositWithdrawTime",
+        BoardAction::SetOwnerDepositWithdrawTime(_) => "SetOwnerDepositWithdrawTime",
+        BoardAction::SetOwnerRedeemTime(_) => "SetOwnerRedeemTime",
+        BoardAction::SetPhaseDuration(_, _) => "SetPhaseDuration",
+        BoardAction::ExecuteChange(_) => "ExecuteChange",
+        BoardAction::CancelChange(_) => "CancelChange",
+        BoardAction::AddUsersToWhitelist(_) => "AddUsersToWhitelist",
+        BoardAction::GrantRole(_, _) => "GrantRole",
+        BoardAction::RevokeRole(_, _) => "RevokeRole",
+        BoardAction::Pause => "Pause",
+        BoardAction::Unpause => "Unpause",
+        BoardAction::SetTargetRaise(_) => "SetTargetRaise",
+        BoardAction::SetTwapSettlementWindow(_) => "SetTwapSettlementWindow",
+        BoardAction::AddCooldownPhase(_, _) => "AddCooldownPhase",
+        BoardAction::RemoveCooldownPhase(_) => "RemoveCooldownPhase",
+        BoardAction::CloseUserPhase => "CloseUserPhase",
+        BoardAction::SetSoftCloseConfig(_) => "SetSoftCloseConfig",
+        BoardAction::SetTimelockDelay(_) => "SetTimelockDelay",
+        BoardAction::ProposeAdmin(_) => "ProposeAdmin",
+        BoardAction::ProposeBeneficiary(_) => "ProposeBeneficiary",
+    }
+}
+
+fn role_name(role: &Role) -> &'static str {
+    match role {
+        Role::TimingManager => "TimingManager",
+        Role::WhitelistManager => "WhitelistManager",
+        Role::RefundOperator => "RefundOperator",
+        Role::Pauser => "Pauser",
+    }
+}
+
+fn time_base_name(time_base: &TimeBase) -> &'static str {
+    match time_base {
+        TimeBase::Timestamp => "Timestamp",
+        TimeBase::BlockNonce => "BlockNonce",
+    }
+}
+
+fn phase_name(phase: &Phase) -> &'static str {
+    match phase {
+        Phase::Idle => "Idle",
+        Phase::UserDepositWithdraw => "UserDepositWithdraw",
+        Phase::OwnerDepositWithdraw => "OwnerDepositWithdraw",
+        Phase::OwnerRedeem => "OwnerRedeem",
+        Phase::UserRedeem => "UserRedeem",
+        Phase::Cooldown => "Cooldown",
+    }
+}
+
+fn print_simulation(result: SimulationResult<StaticApi>) {
+    match result {
+        SimulationResult::Error(err_msg) => println!("error: {err_msg}"),
+        SimulationResult::Success(simulation) => {
+            println!(
+                "user total deposit: {}",
+                simulation.user_total_deposit.to_display()
+            );
+            println!("total deposit: {}", simulation.total_deposit.to_display());
+            println!(
+                "implied price: {}",
+                format_optional(&simulation.implied_price)
+            );
+        }
+    }
+}
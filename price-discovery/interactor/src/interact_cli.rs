@@ -0,0 +1,209 @@
+use clap::{Args, Parser, Subcommand};
+
+/// Price Discovery Interact CLI
+#[derive(Default, PartialEq, Eq, Debug, Parser)]
+#[command(version, about)]
+#[command(propagate_version = true)]
+pub struct InteractCli {
+    #[command(subcommand)]
+    pub command: Option<InteractCliCommand>,
+}
+
+/// Price Discovery Interact CLI Commands
+#[derive(Clone, PartialEq, Eq, Debug, Subcommand)]
+pub enum InteractCliCommand {
+    #[command(
+        name = "deploy",
+        about = "Deploy contract, with the arguments of the config file"
+    )]
+    Deploy,
+    #[command(name = "upgrade", about = "Upgrade contract")]
+    Upgrade,
+    #[command(name = "add-to-whitelist", about = "Add user to whitelist")]
+    AddToWhitelist(UserLimitArgs),
+    #[command(name = "set-user-limit", about = "Set user deposit limit")]
+    SetUserLimit(UserLimitArgs),
+    #[command(name = "refund-users", about = "Refund users")]
+    RefundUsers(RefundUsersArgs),
+    #[command(name = "user-deposit", about = "Deposit accepted tokens")]
+    UserDeposit(UserDepositArgs),
+    #[command(name = "user-withdraw", about = "Withdraw accepted tokens")]
+    UserWithdraw(AmountArgs),
+    #[command(name = "owner-deposit", about = "Deposit launched tokens")]
+    OwnerDeposit(AmountArgs),
+    #[command(name = "owner-withdraw", about = "Withdraw launched tokens")]
+    OwnerWithdraw(AmountArgs),
+    #[command(name = "user-redeem", about = "Redeem user tokens")]
+    UserRedeem,
+    #[command(name = "owner-redeem", about = "Redeem owner tokens")]
+    OwnerRedeem,
+    #[command(name = "current-phase", about = "Print current phase")]
+    CurrentPhase,
+    #[command(name = "phase-schedule", about = "Print phase schedule")]
+    PhaseSchedule,
+    #[command(name = "current-price", about = "Print current price")]
+    CurrentPrice,
+    #[command(name = "contract-state", about = "Print contract state")]
+    ContractState,
+    #[command(name = "user-info", about = "Print user info")]
+    UserInfo(AddressArgs),
+    #[command(name = "participants", about = "Print whitelisted users")]
+    Participants(PageArgs),
+    #[command(name = "simulate-deposit", about = "Simulate user deposit")]
+    SimulateDeposit(SimulateDepositArgs),
+    #[command(name = "simulate-withdraw", about = "Simulate user withdraw")]
+    SimulateWithdraw(UserAmountArgs),
+    #[command(name = "time-until-next-phase", about = "Print time until next phase")]
+    TimeUntilNextPhase,
+    #[command(
+        name = "phase-pipeline",
+        about = "Print start time, time base and phase pipeline"
+    )]
+    PhasePipeline,
+    #[command(name = "sale-config", about = "Print sale configuration")]
+    SaleConfig,
+    #[command(
+        name = "sale-stats",
+        about = "Print number of whitelisted users, depositors and redeemers"
+    )]
+    SaleStats,
+    #[command(name = "price-history", about = "Print price checkpoints")]
+    PriceHistory(TimeRangeArgs),
+    #[command(
+        name = "twap",
+        about = "Print time-weighted average and TWAP settlement prices"
+    )]
+    Twap,
+    #[command(
+        name = "twap-balance",
+        about = "Print time-weighted accepted balance between two timestamps"
+    )]
+    TwapBalance(TimeRangeArgs),
+    #[command(name = "auction-price", about = "Print Dutch auction price")]
+    AuctionPrice,
+    #[command(
+        name = "limit-orders",
+        about = "Print demand by max price and clearing price"
+    )]
+    LimitOrders,
+    #[command(name = "demand-at-price", about = "Print demand at a given price")]
+    DemandAtPrice(PriceArgs),
+    #[command(name = "roles", about = "Print admin, role holders and pause status")]
+    Roles,
+    #[command(name = "has-role", about = "Print whether an address has a role")]
+    HasRole(RoleArgs),
+    #[command(name = "pending-changes", about = "Print timelocked changes")]
+    PendingChanges,
+    #[command(
+        name = "board",
+        about = "Print board members, quorum and pending actions"
+    )]
+    Board,
+    #[command(
+        name = "user-deposit-state",
+        about = "Print user whitelist status, deposit limit and total deposit"
+    )]
+    UserDepositState(AddressArgs),
+    #[command(name = "beneficiary", about = "Print owner redeem beneficiary")]
+    Beneficiary,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct AddressArgs {
+    /// Bech32 address
+    #[arg(short = 'a', long = "address")]
+    pub address: String,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct UserLimitArgs {
+    /// Bech32 address
+    #[arg(short = 'a', long = "address")]
+    pub address: String,
+
+    /// `0` if there is no limit
+    #[arg(short = 'l', long = "limit", default_value_t = 0)]
+    pub limit: u128,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct RefundUsersArgs {
+    /// Bech32 addresses
+    #[arg(short = 'a', long = "addresses", num_args = 1..)]
+    pub addresses: Vec<String>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct AmountArgs {
+    #[arg(short = 'v', long = "value")]
+    pub amount: u128,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct UserDepositArgs {
+    #[arg(short = 'v', long = "value")]
+    pub amount: u128,
+
+    /// The max price the user accepts for their whole deposit
+    #[arg(short = 'm', long = "max-price")]
+    pub max_price: Option<u128>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct UserAmountArgs {
+    /// Bech32 address
+    #[arg(short = 'a', long = "address")]
+    pub address: String,
+
+    #[arg(short = 'v', long = "value")]
+    pub amount: u128,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct SimulateDepositArgs {
+    /// Bech32 address
+    #[arg(short = 'a', long = "address")]
+    pub address: String,
+
+    #[arg(short = 'v', long = "value")]
+    pub amount: u128,
+
+    /// The max price the user accepts for their whole deposit
+    #[arg(short = 'm', long = "max-price")]
+    pub max_price: Option<u128>,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct PageArgs {
+    #[arg(short = 'o', long = "offset", default_value_t = 0)]
+    pub offset: usize,
+
+    #[arg(short = 'l', long = "limit", default_value_t = 100)]
+    pub limit: usize,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct TimeRangeArgs {
+    #[arg(short = 'f', long = "from", default_value_t = 0)]
+    pub from: u64,
+
+    #[arg(short = 't', long = "to", default_value_t = u64::MAX)]
+    pub to: u64,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct PriceArgs {
+    #[arg(short = 'p', long = "price")]
+    pub price: u128,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug, Args)]
+pub struct RoleArgs {
+    /// One of TimingManager, WhitelistManager, RefundOperator and Pauser
+    #[arg(short = 'r', long = "role")]
+    pub role: String,
+
+    /// Bech32 address
+    #[arg(short = 'a', long = "address")]
+    pub address: String,
+}
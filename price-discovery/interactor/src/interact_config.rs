@@ -0,0 +1,50 @@
+use serde::Deserialize;
+use std::io::Read;
+
+/// Config file
+const CONFIG_FILE: &str = "config.toml";
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChainType {
+    Real,
+    Simulator,
+}
+
+/// The `init` arguments. The sale mode and the phase pipeline use their default values
+#[derive(Debug, Deserialize)]
+pub struct DeployConfig {
+    pub launched_token_id: String,
+    pub accepted_token_id: String,
+    pub launched_token_decimals: u32,
+    pub start_time: u64,
+    pub user_deposit_withdraw_time: u64,
+    pub owner_deposit_withdraw_time: u64,
+    pub owner_redeem_time: u64,
+    pub user_min_deposit: String,
+    pub admin: Option<String>,
+}
+
+/// Price Discovery Interact configuration
+#[derive(Debug, Deserialize)]
+pub struct Config {
+    pub chain_type: ChainType,
+    pub gateway_uri: String,
+    pub owner_pem: Option<String>,
+    pub user_pem: Option<String>,
+    pub deploy: DeployConfig,
+}
+
+impl Config {
+    // Deserializes config from file
+    pub fn load_config() -> Self {
+        let mut file = std::fs::File::open(CONFIG_FILE).unwrap();
+        let mut content = String::new();
+        file.read_to_string(&mut content).unwrap();
+        toml::from_str(&content).unwrap()
+    }
+
+    pub fn is_chain_simulator(&self) -> bool {
+        self.chain_type == ChainType::Simulator
+    }
+}
@@ -0,0 +1,7 @@
+use multiversx_sc_snippets::imports::*;
+use price_discovery_interactor::price_discovery_cli;
+
+#[tokio::main]
+async fn main() {
+    price_discovery_cli().await;
+}
@@ -0,0 +1,18 @@
+use multiversx_sc_snippets::imports::*;
+use price_discovery_interactor::{price_discovery_proxy::Phase, Config, PriceDiscoveryInteract};
+
+// Simple deploy test that runs on the local chain simulator configuration.
+// In order for this test to run, make sure that the `config.toml` file contains the chain simulator config
+// and that the chain simulator is running on the `gateway_uri`.
+#[tokio::test]
+#[cfg_attr(not(feature = "chain-simulator-tests"), ignore)]
+async fn deploy_and_whitelist_test() {
+    let mut interact = PriceDiscoveryInteract::new(Config::load_config()).await;
+
+    interact.deploy().await;
+    assert!(interact.current_phase().await == Phase::Idle);
+
+    let user_address = Bech32Address::from(&interact.user_address).to_bech32_string();
+    interact.add_users_to_whitelist(&user_address, 0).await;
+    interact.print_user_info(&user_address).await;
+}
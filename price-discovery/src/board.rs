@@ -0,0 +1,209 @@
+use crate::{roles::Role, soft_close::SoftCloseConfig, timelock::ChangeId, Timestamp};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type ActionId = usize;
+
+/// Performed actions are replaced by `Nothing`
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum BoardAction<M: ManagedTypeApi> {
+    Nothing,
+    SetMinLaunchedTokens(BigUint<M>),
+    RefundUsers(ManagedVec<M, ManagedAddress<M>>),
+    SetUserLimit(ManagedAddress<M>, BigUint<M>),
+    SetStartTime(Timestamp),
+    SetUserDepositWithdrawTime(Timestamp),
+    SetOwnerDepositWithdrawTime(Timestamp),
+    SetOwnerRedeemTime(Timestamp),
+    SetPhaseDuration(usize, Timestamp),
+    ExecuteChange(ChangeId),
+    CancelChange(ChangeId),
+    AddUsersToWhitelist(ManagedVec<M, WhitelistEntry<M>>),
+    GrantRole(Role, ManagedAddress<M>),
+    RevokeRole(Role, ManagedAddress<M>),
+    Pause,
+    Unpause,
+    SetTargetRaise(BigUint<M>),
+    SetTwapSettlementWindow(Timestamp),
+    AddCooldownPhase(usize, Timestamp),
+    RemoveCooldownPhase(usize),
+    CloseUserPhase,
+    SetSoftCloseConfig(SoftCloseConfig),
+    SetTimelockDelay(Timestamp),
+    ProposeAdmin(ManagedAddress<M>),
+    ProposeBeneficiary(ManagedAddress<M>),
+}
+
+/// Pass `0` for `limit` if there is no limit
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct WhitelistEntry<M: ManagedTypeApi> {
+    pub address: ManagedAddress<M>,
+    pub limit: BigUint<M>,
+}
+
+impl<M: ManagedTypeApi> BoardAction<M> {
+    pub fn is_pending(&self) -> bool {
+        !matches!(self, BoardAction::Nothing)
+    }
+}
+
+#[multiversx_sc::module]
+pub trait BoardModule:
+    crate::common_storage::CommonStorageModule + crate::events::EventsModule + crate::roles::RolesModule
+{
+    /// Once the board is set, the contract becomes its own admin,
+    /// so the admin endpoints may only be called through board actions.
+    /// All the granted roles are revoked as well
+    #[endpoint(setBoard)]
+    fn set_board(&self, quorum: usize, members: MultiValueEncoded<ManagedAddress>) {
+        self.require_caller_admin();
+        require!(self.board_members().is_empty(), "Board already set");
+
+        let members = members.to_vec();
+        require!(
+            quorum > 0 && quorum <= members.len(),
+            "Invalid board quorum"
+        );
+
+        let mut board_members_mapper = self.board_members();
+        for member in members.iter() {
+            let added = board_members_mapper.insert(member.clone_value());
+            require!(added, "Duplicate board member");
+        }
+        self.quorum().set(quorum);
+
+        self.pending_admin().clear();
+        self.clear_role_holders();
+        self.admin().set(self.blockchain().get_sc_address());
+
+        self.emit_set_board_event(quorum, members);
+    }
+
+    /// The proposer signs the action as well
+    #[endpoint(proposeAction)]
+    fn propose_action(&self, action: BoardAction<Self::Api>) -> ActionId {
+        let caller = self.require_caller_board_member();
+        require!(action.is_pending(), "Invalid action");
+
+        let action_id = self.action_data().push(&action);
+        let _ = self.action_signers(action_id).insert(caller);
+
+        self.emit_propose_action_event(action_id, action);
+        self.emit_sign_action_event(action_id);
+
+        action_id
+    }
+
+    #[endpoint(signAction)]
+    fn sign_action(&self, action_id: ActionId) {
+        let caller = self.require_caller_board_member();
+        self.require_pending_action(action_id);
+
+        let signed = self.action_signers(action_id).insert(caller);
+        require!(signed, "Action already signed");
+
+        self.emit_sign_action_event(action_id);
+    }
+
+    #[endpoint(unsignAction)]
+    fn unsign_action(&self, action_id: ActionId) {
+        let caller = self.require_caller_board_member();
+        self.require_pending_action(action_id);
+
+        let unsigned = self.action_signers(action_id).swap_remove(&caller);
+        require!(unsigned, "Action not signed");
+
+        self.emit_unsign_action_event(action_id);
+    }
+
+    /// Only actions without signatures may be discarded
+    #[endpoint(discardAction)]
+    fn discard_action(&self, action_id: ActionId) {
+        let _ = self.require_caller_board_member();
+        self.require_pending_action(action_id);
+        require!(
+            self.action_signers(action_id).is_empty(),
+            "Cannot discard action with valid signatures"
+        );
+
+        self.action_data().set(action_id, &BoardAction::Nothing);
+
+        self.emit_discard_action_event(action_id);
+    }
+
+    #[view(getBoardMembers)]
+    fn get_board_members(&self) -> MultiValueEncoded<ManagedAddress> {
+        let mut members = MultiValueEncoded::new();
+        for member in self.board_members().iter() {
+            members.push(member);
+        }
+
+        members
+    }
+
+    /// `Nothing` for performed or discarded actions
+    #[view(getActionData)]
+    fn get_action_data(&self, action_id: ActionId) -> BoardAction<Self::Api> {
+        let action_mapper = self.action_data();
+        if action_mapper.item_is_empty_unchecked(action_id) {
+            return BoardAction::Nothing;
+        }
+
+        action_mapper.get(action_id)
+    }
+
+    #[view(getActionSigners)]
+    fn get_action_signers(&self, action_id: ActionId) -> MultiValueEncoded<ManagedAddress> {
+        let mut signers = MultiValueEncoded::new();
+        for signer in self.action_signers(action_id).iter() {
+            signers.push(signer);
+        }
+
+        signers
+    }
+
+    #[view(getPendingActionIds)]
+    fn get_pending_action_ids(&self) -> MultiValueEncoded<ActionId> {
+        let mut action_ids = MultiValueEncoded::new();
+        for (index, action) in self.action_data().iter().enumerate() {
+            if action.is_pending() {
+                action_ids.push(index + 1);
+            }
+        }
+
+        action_ids
+    }
+
+    fn require_caller_board_member(&self) -> ManagedAddress {
+        let caller = self.blockchain().get_caller();
+        require!(
+            self.board_members().contains(&caller),
+            "Only board members may call this function"
+        );
+
+        caller
+    }
+
+    fn require_pending_action(&self, action_id: ActionId) {
+        require!(
+            self.get_action_data(action_id).is_pending(),
+            "Action not found"
+        );
+    }
+
+    #[storage_mapper("boardMembers")]
+    fn board_members(&self) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[view(getQuorum)]
+    #[storage_mapper("quorum")]
+    fn quorum(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("actionData")]
+    fn action_data(&self) -> VecMapper<BoardAction<Self::Api>>;
+
+    #[storage_mapper("actionSigners")]
+    fn action_signers(&self, action_id: ActionId) -> UnorderedSetMapper<ManagedAddress>;
+}
@@ -0,0 +1,258 @@
+use crate::sale_mode::{BondingCurve, SaleMode};
+
+multiversx_sc::imports!();
+
+pub const MAX_PERCENTAGE: u64 = 10_000;
+/// Caps the cost of the exponential curve formulas, which raise the growth factor to the number of steps.
+/// An exponential curve accepts at most `growth_step * MAX_BONDING_CURVE_STEPS` tokens
+pub const MAX_BONDING_CURVE_STEPS: u32 = 500;
+
+#[multiversx_sc::module]
+pub trait BondingCurveModule: crate::common_storage::CommonStorageModule {
+    fn is_bonding_curve(&self) -> bool {
+        matches!(self.sale_mode().get(), SaleMode::BondingCurve(_))
+    }
+
+    fn get_bonding_curve(&self) -> BondingCurve<Self::Api> {
+        match self.sale_mode().get() {
+            SaleMode::BondingCurve(curve) => curve,
+            _ => sc_panic!("Sale is not a bonding curve"),
+        }
+    }
+
+    fn require_valid_bonding_curve(&self, curve: &BondingCurve<Self::Api>) {
+        match curve {
+            BondingCurve::Linear { initial_price, .. } => {
+                require!(initial_price > &0, "Invalid initial price");
+            }
+            BondingCurve::Exponential {
+                initial_price,
+                growth_step,
+                growth_rate,
+            } => {
+                require!(initial_price > &0, "Invalid initial price");
+                require!(
+                    growth_step > &0 && *growth_rate > 0 && *growth_rate <= MAX_PERCENTAGE,
+                    "Invalid bonding curve growth"
+                );
+            }
+        }
+    }
+
+    /// Same formula as `getCurrentPrice`, for `total_deposit` deposited tokens.
+    /// `None` while no launched tokens are deposited, except for bonding curve sales
+    fn compute_implied_price(&self, total_deposit: &BigUint) -> Option<BigUint> {
+        if self.is_bonding_curve() {
+            return Some(self.get_bonding_curve_price(total_deposit));
+        }
+
+        let launched_token_balance = self.launched_token_balance().get();
+        if launched_token_balance == 0 {
+            return None;
+        }
+
+        let price_precision = self.price_precision().get();
+        Some(total_deposit * price_precision / launched_token_balance)
+    }
+
+    /// The marginal price after `accepted_token_reserve` tokens were deposited
+    fn get_bonding_curve_price(&self, accepted_token_reserve: &BigUint) -> BigUint {
+        match self.get_bonding_curve() {
+            BondingCurve::Linear {
+                initial_price,
+                slope,
+            } => {
+                let price_precision = self.price_precision().get();
+                self.get_linear_curve_scaled_price(&initial_price, &slope, accepted_token_reserve)
+                    / price_precision
+            }
+            BondingCurve::Exponential {
+                initial_price,
+                growth_step,
+                growth_rate,
+            } => {
+                let nr_steps = self.get_nr_growth_steps(accepted_token_reserve, &growth_step);
+                let base = BigUint::from(MAX_PERCENTAGE);
+                let grown_base = &base + growth_rate;
+
+                initial_price * grown_base.pow(nr_steps) / base.pow(nr_steps)
+            }
+        }
+    }
+
+    /// The launched tokens sold along the curve for `accepted_token_reserve` deposited tokens,
+    /// i.e. the integral of the inverse price over the accepted tokens
+    fn get_bonding_curve_tokens(&self, accepted_token_reserve: &BigUint) -> BigUint {
+        let price_precision = self.price_precision().get();
+        match self.get_bonding_curve() {
+            BondingCurve::Linear {
+                initial_price,
+                slope,
+            } => {
+                if slope == 0 {
+                    return accepted_token_reserve * price_precision / initial_price;
+                }
+
+                let scaled_final_price = self.get_linear_curve_scaled_price(
+                    &initial_price,
+                    &slope,
+                    accepted_token_reserve,
+                );
+                (scaled_final_price - initial_price * price_precision) / slope
+            }
+            BondingCurve::Exponential {
+                initial_price,
+                growth_step,
+                growth_rate,
+            } => {
+                let nr_steps = self.get_nr_growth_steps(accepted_token_reserve, &growth_step);
+                let base = BigUint::from(MAX_PERCENTAGE);
+                let grown_base = &base + growth_rate;
+                let base_pow = base.pow(nr_steps);
+                let grown_base_pow = grown_base.pow(nr_steps);
+
+                // full steps form a geometric series, the last step is only partially filled
+                let full_steps_reserve = &growth_step * nr_steps;
+                let partial_step_reserve = accepted_token_reserve - &full_steps_reserve;
+                let full_steps_tokens = growth_step * &grown_base * (&grown_base_pow - &base_pow);
+                let partial_step_tokens = partial_step_reserve * growth_rate * base_pow;
+
+                (full_steps_tokens + partial_step_tokens) * price_precision
+                    / (initial_price * growth_rate * grown_base_pow)
+            }
+        }
+    }
+
+    /// The linear curve price multiplied by the price precision,
+    /// so the integer square root keeps the digits below the price unit
+    fn get_linear_curve_scaled_price(
+        &self,
+        initial_price: &BigUint,
+        slope: &BigUint,
+        accepted_token_reserve: &BigUint,
+    ) -> BigUint {
+        let price_precision = BigUint::from(self.price_precision().get());
+        let price_squared = initial_price * initial_price + slope * accepted_token_reserve * 2u32;
+        (price_squared * &price_precision * &price_precision).sqrt()
+    }
+
+    /// The most accepted tokens the curve can take, `None` if it is not capped
+    fn get_bonding_curve_capacity(&self, curve: &BondingCurve<Self::Api>) -> Option<BigUint> {
+        match curve {
+            BondingCurve::Linear { .. } => None,
+            BondingCurve::Exponential { growth_step, .. } => {
+                Some(growth_step * MAX_BONDING_CURVE_STEPS)
+            }
+        }
+    }
+
+    fn get_nr_growth_steps(&self, accepted_token_reserve: &BigUint, growth_step: &BigUint) -> u32 {
+        let nr_steps = (accepted_token_reserve / growth_step).to_u64();
+        match nr_steps {
+            Some(nr_steps) if nr_steps <= MAX_BONDING_CURVE_STEPS as u64 => nr_steps as u32,
+            _ => sc_panic!("Too many bonding curve steps"),
+        }
+    }
+
+    /// Called before the deposit is added to the accepted token balance
+    fn check_bonding_curve_deposit(&self, payment_amount: &BigUint) -> Result<(), &'static [u8]> {
+        let reserve_before = self.accepted_token_balance().get();
+        let reserve_after = &reserve_before + payment_amount;
+        if let Some(capacity) = self.get_bonding_curve_capacity(&self.get_bonding_curve()) {
+            if reserve_after > capacity {
+                return Err(b"Too many bonding curve steps");
+            }
+        }
+
+        if self.get_bonding_curve_purchase(&reserve_before, &reserve_after) == 0 {
+            return Err(b"Deposit too small for the current curve price");
+        }
+
+        Ok(())
+    }
+
+    fn get_bonding_curve_purchase(
+        &self,
+        reserve_before: &BigUint,
+        reserve_after: &BigUint,
+    ) -> BigUint {
+        self.get_bonding_curve_tokens(reserve_after) - self.get_bonding_curve_tokens(reserve_before)
+    }
+
+    /// Called after the deposit was added to the accepted token balance.
+    /// The deposit is checked by `check_bonding_curve_deposit` beforehand
+    fn buy_bonding_curve_tokens(&self, user_id: AddressId, payment_amount: &BigUint) {
+        let reserve_after = self.accepted_token_balance().get();
+        let reserve_before = &reserve_after - payment_amount;
+        let bought_tokens = self.get_bonding_curve_purchase(&reserve_before, &reserve_after);
+
+        self.user_bonding_curve_tokens(user_id)
+            .update(|tokens| *tokens += &bought_tokens);
+        let tokens_sold = self.bonding_curve_tokens_sold().update(|tokens| {
+            *tokens += bought_tokens;
+            tokens.clone()
+        });
+
+        // the owner may deposit before the users if the phase pipeline allows it
+        let launched_token_balance = self.launched_token_balance().get();
+        if launched_token_balance > 0 {
+            require!(
+                tokens_sold <= launched_token_balance,
+                "Not enough launched tokens for the bonding curve sales"
+            );
+        }
+    }
+
+    /// Called before the withdrawal is removed from the accepted token balance.
+    /// The reserve moves back down the curve, so a user whose allocation is smaller
+    /// than the tokens returned to the curve may only withdraw a smaller amount
+    fn check_bonding_curve_withdraw(
+        &self,
+        user_id: AddressId,
+        withdraw_amount: &BigUint,
+    ) -> Result<(), &'static [u8]> {
+        let sold_tokens = self.get_bonding_curve_sale(withdraw_amount);
+        if sold_tokens > self.user_bonding_curve_tokens(user_id).get() {
+            return Err(b"Not enough bonding curve tokens for the withdrawal");
+        }
+
+        Ok(())
+    }
+
+    /// The launched tokens returned to the curve when `withdraw_amount` tokens are withdrawn
+    fn get_bonding_curve_sale(&self, withdraw_amount: &BigUint) -> BigUint {
+        let reserve_before = self.accepted_token_balance().get();
+        let reserve_after = &reserve_before - withdraw_amount;
+        self.get_bonding_curve_purchase(&reserve_after, &reserve_before)
+    }
+
+    /// Called before the withdrawal is removed from the accepted token balance.
+    /// The withdrawal is checked by `check_bonding_curve_withdraw` beforehand,
+    /// so the tokens sold stay equal to the curve tokens for the reserve
+    fn sell_bonding_curve_tokens(&self, user_id: AddressId, withdraw_amount: &BigUint) {
+        let sold_tokens = self.get_bonding_curve_sale(withdraw_amount);
+
+        self.user_bonding_curve_tokens(user_id)
+            .update(|tokens| *tokens -= &sold_tokens);
+        self.bonding_curve_tokens_sold()
+            .update(|tokens| *tokens -= sold_tokens);
+    }
+
+    fn require_enough_launched_tokens_for_curve(&self, launched_token_amount: &BigUint) {
+        if !self.is_bonding_curve() {
+            return;
+        }
+
+        require!(
+            launched_token_amount >= &self.bonding_curve_tokens_sold().get(),
+            "Not enough launched tokens for the bonding curve sales"
+        );
+    }
+
+    #[view(getBondingCurveTokensSold)]
+    #[storage_mapper("bondingCurveTokensSold")]
+    fn bonding_curve_tokens_sold(&self) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("userBondingCurveTokens")]
+    fn user_bonding_curve_tokens(&self, user_id: AddressId) -> SingleValueMapper<BigUint>;
+}
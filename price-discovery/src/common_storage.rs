@@ -1,4 +1,4 @@
-use crate::Timestamp;
+use crate::{phase::TimeBase, sale_mode::SaleMode, Timestamp};
 
 multiversx_sc::imports!();
 
@@ -10,6 +10,7 @@ pub trait CommonStorageModule {
     #[storage_mapper("launchedTokenBalance")]
     fn launched_token_balance(&self) -> SingleValueMapper<BigUint>;
 
+    #[view(getMinLaunchedTokens)]
     #[storage_mapper("minLaunchedTokens")]
     fn min_launched_tokens(&self) -> SingleValueMapper<BigUint>;
 
@@ -23,6 +24,29 @@ pub trait CommonStorageModule {
     #[storage_mapper("startTime")]
     fn start_time(&self) -> SingleValueMapper<Timestamp>;
 
+    /// Contracts deployed before the time base was configurable use timestamps
+    #[view(getTimeBase)]
+    #[storage_mapper("timeBase")]
+    fn time_base(&self) -> SingleValueMapper<TimeBase>;
+
     #[storage_mapper("pricePrecision")]
     fn price_precision(&self) -> SingleValueMapper<u64>;
+
+    #[view(getSaleMode)]
+    #[storage_mapper("saleMode")]
+    fn sale_mode(&self) -> SingleValueMapper<SaleMode<Self::Api>>;
+
+    /// `0` if the user deposit phase may not be closed early
+    #[view(getTargetRaise)]
+    #[storage_mapper("targetRaise")]
+    fn target_raise(&self) -> SingleValueMapper<BigUint>;
+
+    /// `0` if the settlement uses the final price instead of the TWAP
+    #[view(getTwapSettlementWindow)]
+    #[storage_mapper("twapSettlementWindow")]
+    fn twap_settlement_window(&self) -> SingleValueMapper<Timestamp>;
+
+    #[view(getAdmin)]
+    #[storage_mapper("admin")]
+    fn admin(&self) -> SingleValueMapper<ManagedAddress>;
 }
@@ -0,0 +1,228 @@
+use crate::{
+    sale_mode::{DutchAuctionConfig, SaleMode},
+    Timestamp,
+};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub static NOT_DUTCH_AUCTION_ERR_MSG: &[u8] = b"Sale is not a Dutch auction";
+
+pub type PriceLevel = usize;
+pub const NO_MARGINAL_LEVEL: PriceLevel = 0;
+
+/// `marginal_level` is the last (partially) filled price level,
+/// or `NO_MARGINAL_LEVEL` if the supply did not sell out and all bids are filled.
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct AuctionClearing<M: ManagedTypeApi> {
+    pub price: BigUint<M>,
+    pub marginal_level: PriceLevel,
+    pub marginal_level_fill: BigUint<M>,
+    pub tokens_sold: BigUint<M>,
+}
+
+pub struct AuctionAllocation<M: ManagedTypeApi> {
+    pub bought_tokens: BigUint<M>,
+    pub cost: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait DutchAuctionModule:
+    crate::common_storage::CommonStorageModule + crate::events::EventsModule + crate::phase::PhaseModule
+{
+    #[view(getAuctionPrice)]
+    fn get_auction_price(&self) -> BigUint {
+        let config = self.get_dutch_auction_config();
+        let current_time = self.get_current_time();
+
+        self.get_auction_price_at(&config, current_time)
+    }
+
+    fn get_auction_price_at(
+        &self,
+        config: &DutchAuctionConfig<Self::Api>,
+        timestamp: Timestamp,
+    ) -> BigUint {
+        let start_time = self.get_user_deposit_withdraw_start();
+        let auction_duration = self.get_user_deposit_withdraw_time();
+        let mut elapsed_time =
+            core::cmp::min(timestamp.saturating_sub(start_time), auction_duration);
+        if config.price_step_duration > 0 {
+            elapsed_time -= elapsed_time % config.price_step_duration;
+        }
+
+        let price_range = &config.max_price - &config.min_price;
+        let price_decrease = price_range * elapsed_time / auction_duration;
+        &config.max_price - &price_decrease
+    }
+
+    fn is_dutch_auction(&self) -> bool {
+        matches!(self.sale_mode().get(), SaleMode::DutchAuction(_))
+    }
+
+    fn get_dutch_auction_config(&self) -> DutchAuctionConfig<Self::Api> {
+        match self.sale_mode().get() {
+            SaleMode::DutchAuction(config) => config,
+            _ => sc_panic!(NOT_DUTCH_AUCTION_ERR_MSG),
+        }
+    }
+
+    fn require_valid_auction_config(&self, config: &DutchAuctionConfig<Self::Api>) {
+        require!(
+            config.min_price > 0 && config.min_price <= config.max_price,
+            "Invalid auction prices"
+        );
+
+        let auction_duration = self.get_user_deposit_withdraw_time();
+        require!(
+            config.price_step_duration <= auction_duration,
+            "Invalid auction price step duration"
+        );
+    }
+
+    fn check_auction_bid(&self, payment_amount: &BigUint) -> Result<(), &'static [u8]> {
+        let price = self.get_auction_price();
+        if self.get_auction_requested_tokens(payment_amount, &price) == 0 {
+            return Err(b"Deposit too small for the current auction price");
+        }
+
+        Ok(())
+    }
+
+    fn get_auction_requested_tokens(&self, payment_amount: &BigUint, price: &BigUint) -> BigUint {
+        let price_precision = self.price_precision().get();
+        payment_amount * price_precision / price
+    }
+
+    /// Records the deposit as a request for launched tokens at the current auction price.
+    /// The bid is checked by `check_auction_bid` beforehand
+    fn add_auction_bid(&self, user_id: AddressId, payment_amount: &BigUint) {
+        let price = self.get_auction_price();
+        let requested_tokens = self.get_auction_requested_tokens(payment_amount, &price);
+
+        // the auction price never increases, so levels are stored in descending price order
+        let mut levels_mapper = self.auction_price_levels();
+        let nr_levels = levels_mapper.len();
+        let level = if nr_levels > 0 && levels_mapper.get(nr_levels) == price {
+            nr_levels
+        } else {
+            levels_mapper.push(&price)
+        };
+
+        self.auction_level_demand(level)
+            .update(|demand| *demand += &requested_tokens);
+
+        let mut user_bids = self.user_auction_bids(user_id);
+        let user_level_tokens = match user_bids.get(&level) {
+            Some(prev_tokens) => prev_tokens + requested_tokens,
+            None => requested_tokens,
+        };
+        user_bids.insert(level, user_level_tokens);
+    }
+
+    fn remove_auction_bids(&self, user_id: AddressId) {
+        let mut user_bids = self.user_auction_bids(user_id);
+        for (level, tokens) in user_bids.iter() {
+            self.auction_level_demand(level)
+                .update(|demand| *demand -= tokens);
+        }
+
+        user_bids.clear();
+    }
+
+    fn get_or_compute_auction_clearing(&self) -> AuctionClearing<Self::Api> {
+        let clearing_mapper = self.auction_clearing();
+        if !clearing_mapper.is_empty() {
+            return clearing_mapper.get();
+        }
+
+        let clearing = self.compute_auction_clearing();
+        clearing_mapper.set(&clearing);
+
+        clearing
+    }
+
+    /// Walks the price levels from the highest price down,
+    /// until the launched token supply is sold out
+    fn compute_auction_clearing(&self) -> AuctionClearing<Self::Api> {
+        let launched_token_supply = self.launched_token_balance().get();
+        let levels_mapper = self.auction_price_levels();
+        let mut tokens_sold = BigUint::zero();
+        for level in 1..=levels_mapper.len() {
+            let demand = self.auction_level_demand(level).get();
+            if &tokens_sold + &demand >= launched_token_supply {
+                return AuctionClearing {
+                    price: levels_mapper.get(level),
+                    marginal_level: level,
+                    marginal_level_fill: &launched_token_supply - &tokens_sold,
+                    tokens_sold: launched_token_supply,
+                };
+            }
+
+            tokens_sold += demand;
+        }
+
+        let config = self.get_dutch_auction_config();
+        let auction_end = self.get_user_deposit_withdraw_end();
+        AuctionClearing {
+            price: self.get_auction_price_at(&config, auction_end),
+            marginal_level: NO_MARGINAL_LEVEL,
+            marginal_level_fill: BigUint::zero(),
+            tokens_sold,
+        }
+    }
+
+    /// The cost is rounded up, so the users always cover the owner's proceeds
+    fn compute_user_auction_allocation(
+        &self,
+        user_id: AddressId,
+        clearing: &AuctionClearing<Self::Api>,
+    ) -> AuctionAllocation<Self::Api> {
+        let mut filled_tokens = BigUint::zero();
+        let mut marginal_tokens = BigUint::zero();
+        for (level, tokens) in self.user_auction_bids(user_id).iter() {
+            if clearing.marginal_level == NO_MARGINAL_LEVEL || level < clearing.marginal_level {
+                filled_tokens += tokens;
+            } else if level == clearing.marginal_level {
+                marginal_tokens = tokens;
+            }
+        }
+
+        let marginal_demand = if marginal_tokens > 0 {
+            self.auction_level_demand(clearing.marginal_level).get()
+        } else {
+            BigUint::from(1u32)
+        };
+        let marginal_fill = &marginal_tokens * &clearing.marginal_level_fill;
+        let bought_tokens = &marginal_fill / &marginal_demand + &filled_tokens;
+
+        let price_precision = BigUint::from(self.price_precision().get());
+        let cost_numerator = (filled_tokens * &marginal_demand + marginal_fill) * &clearing.price;
+        let cost_denominator = marginal_demand * price_precision;
+        let cost = (cost_numerator + &cost_denominator - 1u32) / cost_denominator;
+
+        AuctionAllocation {
+            bought_tokens,
+            cost,
+        }
+    }
+
+    fn compute_auction_owner_proceeds(&self, clearing: &AuctionClearing<Self::Api>) -> BigUint {
+        let price_precision = self.price_precision().get();
+
+        &clearing.tokens_sold * &clearing.price / price_precision
+    }
+
+    #[storage_mapper("auctionPriceLevels")]
+    fn auction_price_levels(&self) -> VecMapper<BigUint>;
+
+    #[storage_mapper("auctionLevelDemand")]
+    fn auction_level_demand(&self, level: PriceLevel) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("userAuctionBids")]
+    fn user_auction_bids(&self, user_id: AddressId) -> MapMapper<PriceLevel, BigUint>;
+
+    #[storage_mapper("auctionClearing")]
+    fn auction_clearing(&self) -> SingleValueMapper<AuctionClearing<Self::Api>>;
+}
@@ -1,42 +1,114 @@
-use crate::{Block, Epoch, Timestamp};
+use crate::{
+    board::{ActionId, BoardAction},
+    roles::Role,
+    soft_close::SoftCloseConfig,
+    timelock::{ChangeId, PendingChange},
+    Block, Epoch, Timestamp,
+};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-#[derive(TypeAbi, TopEncode)]
-pub struct UserDepositEvent<'a, M: ManagedTypeApi> {
-    token_amount_in: &'a BigUint<M>,
-    accepted_token_amount: &'a BigUint<M>,
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct UserDepositEvent<M: ManagedTypeApi> {
+    pub token_amount_in: BigUint<M>,
+    pub accepted_token_amount: BigUint<M>,
+    pub user_total_deposit: BigUint<M>,
+    pub launched_token_amount: BigUint<M>,
+    pub implied_price: Option<BigUint<M>>,
 }
 
-#[derive(TypeAbi, TopEncode)]
-pub struct UserWithdrawEvent<'a, M: ManagedTypeApi> {
-    token_amount_out: &'a BigUint<M>,
-    accepted_token_amount: &'a BigUint<M>,
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct UserWithdrawEvent<M: ManagedTypeApi> {
+    pub token_amount_out: BigUint<M>,
+    pub accepted_token_amount: BigUint<M>,
+    pub user_total_deposit: BigUint<M>,
+    pub launched_token_amount: BigUint<M>,
+    pub implied_price: Option<BigUint<M>>,
 }
 
-#[derive(TypeAbi, TopEncode)]
-pub struct OwnerDepositEvent<'a, M: ManagedTypeApi> {
-    token_amount_in: &'a BigUint<M>,
-    launched_token_amount: &'a BigUint<M>,
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct OwnerDepositEvent<M: ManagedTypeApi> {
+    pub token_amount_in: BigUint<M>,
+    pub launched_token_amount: BigUint<M>,
 }
 
-#[derive(TypeAbi, TopEncode)]
-pub struct OwnerWithdrawEvent<'a, M: ManagedTypeApi> {
-    token_amount_out: &'a BigUint<M>,
-    launched_token_amount: &'a BigUint<M>,
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct OwnerWithdrawEvent<M: ManagedTypeApi> {
+    pub token_amount_out: BigUint<M>,
+    pub launched_token_amount: BigUint<M>,
 }
 
-#[derive(TypeAbi, TopEncode)]
-pub struct RedeemEvent<'a, M: ManagedTypeApi> {
-    bought_token_id: &'a EgldOrEsdtTokenIdentifier<M>,
-    bought_token_amount: &'a BigUint<M>,
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct RedeemEvent<M: ManagedTypeApi> {
+    pub bought_token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub bought_token_amount: BigUint<M>,
 }
 
-#[derive(TypeAbi, TopEncode)]
-pub struct RefundUserEvent<'a, M: ManagedTypeApi> {
-    user: &'a ManagedAddress<M>,
-    accepted_token_amount: &'a BigUint<M>,
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct RefundUserEvent<M: ManagedTypeApi> {
+    pub user: ManagedAddress<M>,
+    pub accepted_token_amount: BigUint<M>,
+    pub refunded_amount: BigUint<M>,
+    pub user_total_deposit: BigUint<M>,
+    pub launched_token_amount: BigUint<M>,
+    pub implied_price: Option<BigUint<M>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct WhitelistUserEvent<M: ManagedTypeApi> {
+    pub user: ManagedAddress<M>,
+    pub deposit_limit: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct WithdrawLaunchpadTokensEvent<M: ManagedTypeApi> {
+    pub launched_token_id: TokenIdentifier<M>,
+    pub launched_token_amount: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct FinalizeSaleEvent<M: ManagedTypeApi> {
+    pub beneficiary: ManagedAddress<M>,
+    pub accepted_token_amount: BigUint<M>,
+    pub unsold_launched_tokens: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct RoleEvent<M: ManagedTypeApi> {
+    pub role: Role,
+    pub address: ManagedAddress<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct PhaseDurationEvent {
+    pub phase_index: usize,
+    pub duration: Timestamp,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct SetBoardEvent<M: ManagedTypeApi> {
+    pub quorum: usize,
+    pub members: ManagedVec<M, ManagedAddress<M>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct ProposeActionEvent<M: ManagedTypeApi> {
+    pub action_id: ActionId,
+    pub action: BoardAction<M>,
 }
 
 pub struct GenericEventData<M: ManagedTypeApi> {
@@ -46,104 +118,537 @@ pub struct GenericEventData<M: ManagedTypeApi> {
     timestamp: Timestamp,
 }
 
-#[multiversx_sc::module]
-pub trait EventsModule: crate::common_storage::CommonStorageModule {
-    fn emit_user_deposit_event(&self, token_amount_in: &BigUint) {
+#[multiversx_sc::module]
+pub trait EventsModule: crate::common_storage::CommonStorageModule {
+    /// The amounts and the implied price are the ones after the deposit.
+    /// The implied price is computed by the caller, with the same formula as `getCurrentPrice`
+    fn emit_user_deposit_event(
+        &self,
+        token_amount_in: &BigUint,
+        user_total_deposit: &BigUint,
+        implied_price: &Option<BigUint>,
+    ) {
+        let generic_event_data = self.get_generic_event_data();
+        let accepted_token_amount = self.accepted_token_balance().get();
+        let launched_token_amount = self.launched_token_balance().get();
+
+        self.user_deposit_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            UserDepositEvent {
+                token_amount_in: token_amount_in.clone(),
+                accepted_token_amount,
+                user_total_deposit: user_total_deposit.clone(),
+                launched_token_amount,
+                implied_price: implied_price.clone(),
+            },
+        );
+    }
+
+    fn emit_user_withdraw_event(
+        &self,
+        token_amount_out: &BigUint,
+        user_total_deposit: &BigUint,
+        implied_price: &Option<BigUint>,
+    ) {
+        let generic_event_data = self.get_generic_event_data();
+        let accepted_token_amount = self.accepted_token_balance().get();
+        let launched_token_amount = self.launched_token_balance().get();
+
+        self.user_withdraw_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            UserWithdrawEvent {
+                token_amount_out: token_amount_out.clone(),
+                accepted_token_amount,
+                user_total_deposit: user_total_deposit.clone(),
+                launched_token_amount,
+                implied_price: implied_price.clone(),
+            },
+        );
+    }
+
+    fn emit_owner_deposit_event(&self, token_amount_in: &BigUint) {
+        let generic_event_data = self.get_generic_event_data();
+        let launched_token_amount = self.launched_token_balance().get();
+
+        self.owner_deposit_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            OwnerDepositEvent {
+                token_amount_in: token_amount_in.clone(),
+                launched_token_amount,
+            },
+        );
+    }
+
+    fn emit_owner_withdraw_event(&self, token_amount_out: &BigUint) {
+        let generic_event_data = self.get_generic_event_data();
+        let launched_token_amount = self.launched_token_balance().get();
+
+        self.owner_withdraw_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            OwnerWithdrawEvent {
+                token_amount_out: token_amount_out.clone(),
+                launched_token_amount,
+            },
+        );
+    }
+
+    fn emit_redeem_event(
+        &self,
+        bought_token_id: &EgldOrEsdtTokenIdentifier,
+        bought_token_amount: &BigUint,
+    ) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.redeem_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            RedeemEvent {
+                bought_token_id: bought_token_id.clone(),
+                bought_token_amount: bought_token_amount.clone(),
+            },
+        )
+    }
+
+    /// The user's total deposit is always `0` after a refund
+    fn emit_refund_user_event(
+        &self,
+        user: &ManagedAddress,
+        refunded_amount: &BigUint,
+        implied_price: &Option<BigUint>,
+    ) {
+        let generic_event_data = self.get_generic_event_data();
+        let accepted_token_amount = self.accepted_token_balance().get();
+        let launched_token_amount = self.launched_token_balance().get();
+
+        self.refund_user_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            RefundUserEvent {
+                user: user.clone(),
+                accepted_token_amount,
+                refunded_amount: refunded_amount.clone(),
+                user_total_deposit: BigUint::zero(),
+                launched_token_amount,
+                implied_price: implied_price.clone(),
+            },
+        );
+    }
+
+    /// `deposit_limit` is `0` if there is no limit
+    fn emit_whitelist_user_event(&self, user: &ManagedAddress, deposit_limit: &BigUint) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.whitelist_user_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            WhitelistUserEvent {
+                user: user.clone(),
+                deposit_limit: deposit_limit.clone(),
+            },
+        );
+    }
+
+    fn emit_set_min_launched_tokens_event(&self, min_launched_tokens: &BigUint) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.set_min_launched_tokens_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            min_launched_tokens,
+        );
+    }
+
+    fn emit_withdraw_launchpad_tokens_event(
+        &self,
+        launched_token_id: &TokenIdentifier,
+        launched_token_amount: &BigUint,
+    ) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.withdraw_launchpad_tokens_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            WithdrawLaunchpadTokensEvent {
+                launched_token_id: launched_token_id.clone(),
+                launched_token_amount: launched_token_amount.clone(),
+            },
+        );
+    }
+
+    fn emit_finalize_sale_event(
+        &self,
+        beneficiary: &ManagedAddress,
+        accepted_token_amount: &BigUint,
+        unsold_launched_tokens: &BigUint,
+    ) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.finalize_sale_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            FinalizeSaleEvent {
+                beneficiary: beneficiary.clone(),
+                accepted_token_amount: accepted_token_amount.clone(),
+                unsold_launched_tokens: unsold_launched_tokens.clone(),
+            },
+        );
+    }
+
+    fn emit_grant_role_event(&self, role: Role, address: &ManagedAddress) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.grant_role_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            RoleEvent {
+                role,
+                address: address.clone(),
+            },
+        );
+    }
+
+    fn emit_revoke_role_event(&self, role: Role, address: &ManagedAddress) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.revoke_role_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            RoleEvent {
+                role,
+                address: address.clone(),
+            },
+        );
+    }
+
+    fn emit_pause_event(&self) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.pause_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+        );
+    }
+
+    fn emit_unpause_event(&self) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.unpause_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+        );
+    }
+
+    fn emit_set_twap_settlement_window_event(&self, window: Timestamp) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.set_twap_settlement_window_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            window,
+        );
+    }
+
+    fn emit_set_phase_duration_event(&self, phase_index: usize, duration: Timestamp) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.set_phase_duration_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            PhaseDurationEvent {
+                phase_index,
+                duration,
+            },
+        );
+    }
+
+    fn emit_add_cooldown_phase_event(&self, phase_index: usize, duration: Timestamp) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.add_cooldown_phase_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            PhaseDurationEvent {
+                phase_index,
+                duration,
+            },
+        );
+    }
+
+    fn emit_remove_cooldown_phase_event(&self, phase_index: usize) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.remove_cooldown_phase_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            phase_index,
+        );
+    }
+
+    fn emit_set_target_raise_event(&self, target_raise: &BigUint) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.set_target_raise_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            target_raise,
+        );
+    }
+
+    fn emit_close_user_phase_event(&self, user_deposit_withdraw_time: Timestamp) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.close_user_phase_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            user_deposit_withdraw_time,
+        );
+    }
+
+    fn emit_set_soft_close_config_event(&self, config: &SoftCloseConfig) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.set_soft_close_config_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            config,
+        );
+    }
+
+    fn emit_extend_user_phase_event(&self, user_deposit_withdraw_time: Timestamp) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.extend_user_phase_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            user_deposit_withdraw_time,
+        );
+    }
+
+    fn emit_set_start_time_event(&self, start_time: Timestamp) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.set_start_time_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            start_time,
+        );
+    }
+
+    fn emit_propose_admin_event(&self, new_admin: &ManagedAddress) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.propose_admin_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            new_admin,
+        );
+    }
+
+    fn emit_accept_admin_event(&self, new_admin: &ManagedAddress) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.accept_admin_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            new_admin,
+        );
+    }
+
+    fn emit_propose_beneficiary_event(&self, new_beneficiary: &ManagedAddress) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.propose_beneficiary_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            new_beneficiary,
+        );
+    }
+
+    fn emit_accept_beneficiary_event(&self, new_beneficiary: &ManagedAddress) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.accept_beneficiary_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            new_beneficiary,
+        );
+    }
+
+    fn emit_set_timelock_delay_event(&self, delay: Timestamp) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.set_timelock_delay_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            delay,
+        );
+    }
+
+    fn emit_queue_change_event(&self, pending_change: &PendingChange<Self::Api>) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.queue_change_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            pending_change,
+        );
+    }
+
+    fn emit_execute_change_event(&self, id: ChangeId) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.execute_change_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            id,
+        );
+    }
+
+    fn emit_cancel_change_event(&self, id: ChangeId) {
+        let generic_event_data = self.get_generic_event_data();
+
+        self.cancel_change_event(
+            &generic_event_data.caller,
+            generic_event_data.block,
+            generic_event_data.epoch,
+            generic_event_data.timestamp,
+            id,
+        );
+    }
+
+    fn emit_set_board_event(&self, quorum: usize, members: ManagedVec<ManagedAddress>) {
         let generic_event_data = self.get_generic_event_data();
-        let accepted_token_amount = self.accepted_token_balance().get();
 
-        self.user_deposit_event(
+        self.set_board_event(
             &generic_event_data.caller,
             generic_event_data.block,
             generic_event_data.epoch,
             generic_event_data.timestamp,
-            UserDepositEvent {
-                token_amount_in,
-                accepted_token_amount: &accepted_token_amount,
-            },
+            SetBoardEvent { quorum, members },
         );
     }
 
-    fn emit_user_withdraw_event(&self, token_amount_out: &BigUint) {
+    fn emit_propose_action_event(&self, action_id: ActionId, action: BoardAction<Self::Api>) {
         let generic_event_data = self.get_generic_event_data();
-        let accepted_token_amount = self.accepted_token_balance().get();
 
-        self.user_withdraw_event(
+        self.propose_action_event(
             &generic_event_data.caller,
             generic_event_data.block,
             generic_event_data.epoch,
             generic_event_data.timestamp,
-            UserWithdrawEvent {
-                token_amount_out,
-                accepted_token_amount: &accepted_token_amount,
-            },
+            ProposeActionEvent { action_id, action },
         );
     }
 
-    fn emit_owner_deposit_event(&self, token_amount_in: &BigUint) {
+    fn emit_sign_action_event(&self, action_id: ActionId) {
         let generic_event_data = self.get_generic_event_data();
-        let launched_token_amount = self.launched_token_balance().get();
 
-        self.owner_deposit_event(
+        self.sign_action_event(
             &generic_event_data.caller,
             generic_event_data.block,
             generic_event_data.epoch,
             generic_event_data.timestamp,
-            OwnerDepositEvent {
-                token_amount_in,
-                launched_token_amount: &launched_token_amount,
-            },
+            action_id,
         );
     }
 
-    fn emit_owner_withdraw_event(&self, token_amount_out: &BigUint) {
+    fn emit_unsign_action_event(&self, action_id: ActionId) {
         let generic_event_data = self.get_generic_event_data();
-        let launched_token_amount = self.launched_token_balance().get();
 
-        self.owner_withdraw_event(
+        self.unsign_action_event(
             &generic_event_data.caller,
             generic_event_data.block,
             generic_event_data.epoch,
             generic_event_data.timestamp,
-            OwnerWithdrawEvent {
-                token_amount_out,
-                launched_token_amount: &launched_token_amount,
-            },
+            action_id,
         );
     }
 
-    fn emit_redeem_event(
-        &self,
-        bought_token_id: &EgldOrEsdtTokenIdentifier,
-        bought_token_amount: &BigUint,
-    ) {
+    fn emit_discard_action_event(&self, action_id: ActionId) {
         let generic_event_data = self.get_generic_event_data();
 
-        self.redeem_event(
+        self.discard_action_event(
             &generic_event_data.caller,
             generic_event_data.block,
             generic_event_data.epoch,
             generic_event_data.timestamp,
-            RedeemEvent {
-                bought_token_id,
-                bought_token_amount,
-            },
-        )
+            action_id,
+        );
     }
 
-    fn emit_refund_user_event(&self, user: &ManagedAddress) {
+    fn emit_perform_action_event(&self, action_id: ActionId) {
         let generic_event_data = self.get_generic_event_data();
-        let accepted_token_amount = self.accepted_token_balance().get();
 
-        self.refund_user_event(
+        self.perform_action_event(
             &generic_event_data.caller,
             generic_event_data.block,
             generic_event_data.epoch,
             generic_event_data.timestamp,
-            RefundUserEvent {
-                user,
-                accepted_token_amount: &accepted_token_amount,
-            },
+            action_id,
         );
     }
 
@@ -221,9 +726,60 @@ pub trait EventsModule: crate::common_storage::CommonStorageModule {
         refund_event: RefundUserEvent<Self::Api>,
     );
 
+    #[event("whitelistUserEvent")]
+    fn whitelist_user_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        whitelist_event: WhitelistUserEvent<Self::Api>,
+    );
+
+    #[event("setMinLaunchedTokensEvent")]
+    fn set_min_launched_tokens_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        min_launched_tokens: &BigUint,
+    );
+
+    #[event("withdrawLaunchpadTokensEvent")]
+    fn withdraw_launchpad_tokens_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        withdraw_event: WithdrawLaunchpadTokensEvent<Self::Api>,
+    );
+
+    /// Emitted by `ownerRedeem`, after which users may redeem their launched tokens
+    #[event("finalizeSaleEvent")]
+    fn finalize_sale_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        finalize_event: FinalizeSaleEvent<Self::Api>,
+    );
+
     #[event("setUserLimitEvent")]
     fn set_user_limit_event(&self, #[indexed] user: &ManagedAddress, limit: &BigUint);
 
+    #[event("setStartTimeEvent")]
+    fn set_start_time_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        new_start_time: Timestamp,
+    );
+
     #[event("setUserDepositWithdrawTimeEvent")]
     fn set_user_deposit_withdraw_time_event(&self, new_time: Timestamp);
 
@@ -232,4 +788,262 @@ pub trait EventsModule: crate::common_storage::CommonStorageModule {
 
     #[event("setOwnerRedeemTimeEvent")]
     fn set_owner_redeem_time_event(&self, new_time: Timestamp);
+
+    #[event("setPhaseDurationEvent")]
+    fn set_phase_duration_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        phase_duration_event: PhaseDurationEvent,
+    );
+
+    #[event("addCooldownPhaseEvent")]
+    fn add_cooldown_phase_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        phase_duration_event: PhaseDurationEvent,
+    );
+
+    #[event("removeCooldownPhaseEvent")]
+    fn remove_cooldown_phase_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        phase_index: usize,
+    );
+
+    #[event("setTwapSettlementWindowEvent")]
+    fn set_twap_settlement_window_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        new_window: Timestamp,
+    );
+
+    #[event("setSoftCloseConfigEvent")]
+    fn set_soft_close_config_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        config: &SoftCloseConfig,
+    );
+
+    #[event("extendUserPhaseEvent")]
+    fn extend_user_phase_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        new_time: Timestamp,
+    );
+
+    #[event("setTargetRaiseEvent")]
+    fn set_target_raise_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        new_target_raise: &BigUint,
+    );
+
+    #[event("closeUserPhaseEvent")]
+    fn close_user_phase_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        new_time: Timestamp,
+    );
+
+    #[event("grantRoleEvent")]
+    fn grant_role_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        role_event: RoleEvent<Self::Api>,
+    );
+
+    #[event("revokeRoleEvent")]
+    fn revoke_role_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        role_event: RoleEvent<Self::Api>,
+    );
+
+    #[event("pauseEvent")]
+    fn pause_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+    );
+
+    #[event("unpauseEvent")]
+    fn unpause_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+    );
+
+    #[event("proposeAdminEvent")]
+    fn propose_admin_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        new_admin: &ManagedAddress,
+    );
+
+    #[event("acceptAdminEvent")]
+    fn accept_admin_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        new_admin: &ManagedAddress,
+    );
+
+    #[event("proposeBeneficiaryEvent")]
+    fn propose_beneficiary_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        new_beneficiary: &ManagedAddress,
+    );
+
+    #[event("acceptBeneficiaryEvent")]
+    fn accept_beneficiary_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        new_beneficiary: &ManagedAddress,
+    );
+
+    #[event("setTimelockDelayEvent")]
+    fn set_timelock_delay_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        new_delay: Timestamp,
+    );
+
+    #[event("queueChangeEvent")]
+    fn queue_change_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        pending_change: &PendingChange<Self::Api>,
+    );
+
+    #[event("executeChangeEvent")]
+    fn execute_change_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        id: ChangeId,
+    );
+
+    #[event("cancelChangeEvent")]
+    fn cancel_change_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        id: ChangeId,
+    );
+
+    #[event("setBoardEvent")]
+    fn set_board_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        set_board_event: SetBoardEvent<Self::Api>,
+    );
+
+    #[event("proposeActionEvent")]
+    fn propose_action_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        propose_event: ProposeActionEvent<Self::Api>,
+    );
+
+    #[event("signActionEvent")]
+    fn sign_action_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        action_id: ActionId,
+    );
+
+    #[event("unsignActionEvent")]
+    fn unsign_action_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        action_id: ActionId,
+    );
+
+    #[event("discardActionEvent")]
+    fn discard_action_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        action_id: ActionId,
+    );
+
+    #[event("performActionEvent")]
+    fn perform_action_event(
+        &self,
+        #[indexed] caller: &ManagedAddress,
+        #[indexed] block: Block,
+        #[indexed] epoch: Epoch,
+        #[indexed] timestamp: Timestamp,
+        action_id: ActionId,
+    );
 }
@@ -1,10 +1,25 @@
 #![no_std]
 
+use phase::{PhaseDefinition, TimeBase};
+use sale_mode::SaleMode;
+use user_actions::admin_actions::START_TIME_IN_PAST_ERR_MSG;
+
 multiversx_sc::imports!();
 
+pub mod board;
+pub mod bonding_curve;
 pub mod common_storage;
+pub mod dutch_auction;
 pub mod events;
+pub mod limit_orders;
 pub mod phase;
+pub mod price_discovery_proxy;
+pub mod price_history;
+pub mod roles;
+pub mod sale_mode;
+pub mod soft_close;
+pub mod timelock;
+pub mod twap_settlement;
 pub mod user_actions;
 pub mod views;
 
@@ -20,6 +35,15 @@ pub trait PriceDiscovery:
     common_storage::CommonStorageModule
     + events::EventsModule
     + phase::PhaseModule
+    + dutch_auction::DutchAuctionModule
+    + bonding_curve::BondingCurveModule
+    + limit_orders::LimitOrdersModule
+    + price_history::PriceHistoryModule
+    + twap_settlement::TwapSettlementModule
+    + soft_close::SoftCloseModule
+    + roles::RolesModule
+    + timelock::TimelockModule
+    + board::BoardModule
     + user_actions::user_deposit_withdraw::UserDepositWithdrawModule
     + user_actions::owner_deposit_withdraw::OwnerDepositWithdrawModule
     + user_actions::redeem::RedeemModule
@@ -28,6 +52,7 @@ pub trait PriceDiscovery:
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     /// For explanations regarding what each parameter means, please refer to docs/setup.md
+    #[allow_multiple_var_args]
     #[init]
     fn init(
         &self,
@@ -40,6 +65,9 @@ pub trait PriceDiscovery:
         owner_redeem_time: Timestamp,
         user_min_deposit: BigUint,
         admin: ManagedAddress,
+        opt_sale_mode: OptionalValue<SaleMode<Self::Api>>,
+        opt_phase_pipeline: OptionalValue<ManagedVec<PhaseDefinition>>,
+        opt_time_base: OptionalValue<TimeBase>,
     ) {
         require!(
             launched_token_id.is_valid_esdt_identifier(),
@@ -55,11 +83,14 @@ pub trait PriceDiscovery:
             "Launched token has too many decimals"
         );
 
-        let current_time = self.blockchain().get_block_timestamp();
-        require!(
-            current_time < start_time,
-            "Start time cannot be in the past"
-        );
+        let time_base = match opt_time_base {
+            OptionalValue::Some(time_base) => time_base,
+            OptionalValue::None => TimeBase::Timestamp,
+        };
+        self.time_base().set(time_base);
+
+        let current_time = self.get_current_time();
+        require!(current_time < start_time, START_TIME_IN_PAST_ERR_MSG);
         self.require_valid_timestamp(user_deposit_withdraw_time);
         self.require_valid_timestamp(owner_deposit_withdraw_time);
         self.require_valid_long_timestamp(owner_redeem_time);
@@ -67,19 +98,47 @@ pub trait PriceDiscovery:
         self.launched_token_id().set(launched_token_id);
         self.accepted_token_id().set(accepted_token_id);
         self.start_time().set(start_time);
-        self.user_deposit_withdraw_time()
-            .set(user_deposit_withdraw_time);
-        self.owner_deposit_withdraw_time()
-            .set(owner_deposit_withdraw_time);
-        self.owner_redeem_time().set(owner_redeem_time);
+
+        let phase_pipeline = match opt_phase_pipeline {
+            OptionalValue::Some(phase_pipeline) => phase_pipeline,
+            OptionalValue::None => self.build_default_phase_pipeline(
+                user_deposit_withdraw_time,
+                owner_deposit_withdraw_time,
+                owner_redeem_time,
+            ),
+        };
+        self.require_valid_phase_pipeline(&phase_pipeline);
+        self.set_phase_pipeline(&phase_pipeline);
         self.user_min_deposit().set(user_min_deposit);
 
         let price_precision = 10u64.pow(launched_token_decimals);
         self.price_precision().set(price_precision);
 
         self.admin().set(admin);
+
+        let sale_mode = match opt_sale_mode {
+            OptionalValue::Some(sale_mode) => sale_mode,
+            OptionalValue::None => SaleMode::Standard,
+        };
+        match &sale_mode {
+            SaleMode::Standard => {}
+            SaleMode::DutchAuction(config) => self.require_valid_auction_config(config),
+            SaleMode::BondingCurve(curve) => self.require_valid_bonding_curve(curve),
+        }
+        self.sale_mode().set(sale_mode);
     }
 
     #[upgrade]
-    fn upgrade(&self) {}
+    fn upgrade(&self) {
+        if !self.phase_pipeline().is_empty() {
+            return;
+        }
+
+        let phase_pipeline = self.build_default_phase_pipeline(
+            self.legacy_user_deposit_withdraw_time().take(),
+            self.legacy_owner_deposit_withdraw_time().take(),
+            self.legacy_owner_redeem_time().take(),
+        );
+        self.set_phase_pipeline(&phase_pipeline);
+    }
 }
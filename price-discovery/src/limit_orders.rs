@@ -0,0 +1,192 @@
+use crate::{sale_mode::SaleMode, twap_settlement::TWAP_SETTLEMENT_DISABLED};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub const NO_MAX_PRICE: u64 = 0;
+/// Bounds the price levels iterated when computing the clearing price
+pub const MAX_LIMIT_ORDER_PRICES: usize = 100;
+
+/// All the limit orders with a max price of at most `max_excluded_price` are excluded from the sale.
+/// `max_excluded_price` is `0` if no order was excluded.
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct LimitOrderClearing<M: ManagedTypeApi> {
+    pub price: BigUint<M>,
+    pub included_deposits: BigUint<M>,
+    pub max_excluded_price: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait LimitOrdersModule: crate::common_storage::CommonStorageModule {
+    /// Returns pairs of (max_price, total_deposits), sorted by price.
+    /// Deposits without a max price are not included.
+    #[view(getDemandByMaxPrice)]
+    fn get_demand_by_max_price(&self) -> MultiValueEncoded<MultiValue2<BigUint, BigUint>> {
+        let mut result = MultiValueEncoded::new();
+        for price in self.get_sorted_max_prices().iter() {
+            let deposits = self.deposits_by_max_price(&price).get();
+            result.push((price.clone_value(), deposits).into());
+        }
+
+        result
+    }
+
+    /// The total deposits of the users that accept to buy at `price`
+    #[view(getDemandAtPrice)]
+    fn get_demand_at_price(&self, price: BigUint) -> BigUint {
+        let mut demand = self.accepted_token_balance().get();
+        for max_price in self.max_prices().iter() {
+            if max_price < price {
+                demand -= self.deposits_by_max_price(&max_price).get();
+            }
+        }
+
+        demand
+    }
+
+    /// The clearing price for the currently deposited launched tokens
+    #[view(getLimitOrderClearingPrice)]
+    fn get_limit_order_clearing_price(&self) -> BigUint {
+        let launched_token_balance = self.launched_token_balance().get();
+        require!(launched_token_balance > 0, "No launched tokens available");
+
+        self.compute_limit_order_clearing().price
+    }
+
+    fn has_limit_orders(&self) -> bool {
+        !self.max_prices().is_empty()
+    }
+
+    fn check_user_max_price(
+        &self,
+        opt_max_price: &OptionalValue<BigUint>,
+    ) -> Result<(), &'static [u8]> {
+        let max_price = match opt_max_price {
+            OptionalValue::Some(max_price) => max_price,
+            OptionalValue::None => return Ok(()),
+        };
+        if self.sale_mode().get() != SaleMode::Standard {
+            return Err(b"Max price not allowed in this sale mode");
+        }
+        if self.twap_settlement_window().get() != TWAP_SETTLEMENT_DISABLED {
+            return Err(b"Max price not allowed with TWAP settlement");
+        }
+        if max_price == &NO_MAX_PRICE {
+            return Err(b"Invalid max price");
+        }
+
+        Ok(())
+    }
+
+    /// The max price is checked by `check_user_max_price` beforehand
+    fn set_user_max_price(&self, user_id: AddressId, opt_max_price: OptionalValue<BigUint>) {
+        match opt_max_price {
+            OptionalValue::Some(max_price) => self.user_max_price(user_id).set(max_price),
+            OptionalValue::None => self.user_max_price(user_id).clear(),
+        }
+    }
+
+    fn add_limit_order_deposit(&self, user_id: AddressId, amount: &BigUint) {
+        let max_price = self.user_max_price(user_id).get();
+        if max_price == NO_MAX_PRICE {
+            return;
+        }
+
+        self.deposits_by_max_price(&max_price)
+            .update(|deposits| *deposits += amount);
+        let mut max_prices = self.max_prices();
+        if max_prices.insert(max_price) {
+            require!(
+                max_prices.len() <= MAX_LIMIT_ORDER_PRICES,
+                "Too many distinct max prices"
+            );
+        }
+    }
+
+    fn remove_limit_order_deposit(&self, user_id: AddressId, amount: &BigUint) {
+        let max_price = self.user_max_price(user_id).get();
+        if max_price == NO_MAX_PRICE {
+            return;
+        }
+
+        let deposits_mapper = self.deposits_by_max_price(&max_price);
+        let remaining_deposits = deposits_mapper.get() - amount;
+        if remaining_deposits == 0 {
+            deposits_mapper.clear();
+            let _ = self.max_prices().swap_remove(&max_price);
+        } else {
+            deposits_mapper.set(remaining_deposits);
+        }
+    }
+
+    fn get_or_compute_limit_order_clearing(&self) -> LimitOrderClearing<Self::Api> {
+        let clearing_mapper = self.limit_order_clearing();
+        if !clearing_mapper.is_empty() {
+            return clearing_mapper.get();
+        }
+
+        let clearing = self.compute_limit_order_clearing();
+        clearing_mapper.set(&clearing);
+
+        clearing
+    }
+
+    /// Excludes the orders with the lowest max price, one price level at a time,
+    /// and recomputes the price over the remaining deposits,
+    /// until all the remaining orders accept the price
+    fn compute_limit_order_clearing(&self) -> LimitOrderClearing<Self::Api> {
+        let launched_token_supply = self.launched_token_balance().get();
+        let price_precision = self.price_precision().get();
+        let mut included_deposits = self.accepted_token_balance().get();
+        let mut clearing_price = &included_deposits * price_precision / &launched_token_supply;
+        let mut max_excluded_price = BigUint::zero();
+        for max_price in self.get_sorted_max_prices().iter() {
+            if *max_price >= clearing_price {
+                break;
+            }
+
+            included_deposits -= self.deposits_by_max_price(&max_price).get();
+            clearing_price = &included_deposits * price_precision / &launched_token_supply;
+            max_excluded_price = max_price.clone_value();
+        }
+
+        LimitOrderClearing {
+            price: clearing_price,
+            included_deposits,
+            max_excluded_price,
+        }
+    }
+
+    fn is_limit_order_excluded(
+        &self,
+        user_id: AddressId,
+        clearing: &LimitOrderClearing<Self::Api>,
+    ) -> bool {
+        let max_price = self.user_max_price(user_id).get();
+
+        max_price != NO_MAX_PRICE && max_price <= clearing.max_excluded_price
+    }
+
+    fn get_sorted_max_prices(&self) -> ManagedVec<BigUint> {
+        let mut prices = ManagedVec::new();
+        for price in self.max_prices().iter() {
+            prices.push(price);
+        }
+        prices.sort_unstable();
+
+        prices
+    }
+
+    #[storage_mapper("userMaxPrice")]
+    fn user_max_price(&self, user_id: AddressId) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("maxPrices")]
+    fn max_prices(&self) -> UnorderedSetMapper<BigUint>;
+
+    #[storage_mapper("depositsByMaxPrice")]
+    fn deposits_by_max_price(&self, max_price: &BigUint) -> SingleValueMapper<BigUint>;
+
+    #[storage_mapper("limitOrderClearing")]
+    fn limit_order_clearing(&self) -> SingleValueMapper<LimitOrderClearing<Self::Api>>;
+}
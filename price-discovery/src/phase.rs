@@ -1,18 +1,57 @@
-use crate::Timestamp;
+use crate::{Block, Timestamp};
 
 multiversx_sc::imports!();
 multiversx_sc::derive_imports!();
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, PartialEq, PartialOrd)]
+pub static USER_DEPOSIT_WITHDRAW_NOT_ALLOWED_ERR_MSG: &[u8] =
+    b"User deposit/withdraw not allowed in this phase";
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone)]
 pub enum Phase {
     Idle,
     UserDepositWithdraw,
     OwnerDepositWithdraw,
     OwnerRedeem,
     UserRedeem,
+    Cooldown,
+}
+
+/// `end` is `None` for the open-ended `UserRedeem` phase
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct PhaseInterval {
+    pub phase: Phase,
+    pub start: Timestamp,
+    pub end: Option<Timestamp>,
+}
+
+/// An entry of the phase pipeline. `Idle` and `UserRedeem` are not part of the pipeline,
+/// as they are always the first and the last phase.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone)]
+pub struct PhaseDefinition {
+    pub kind: Phase,
+    pub duration: Timestamp,
+}
+
+/// The clock used for the start time, the phase durations, and all the other time values
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone, Copy,
+)]
+pub enum TimeBase {
+    Timestamp,
+    BlockNonce,
 }
 
 pub const MAX_PHASE_DURATION: Timestamp = 60 * 60 * 24 * 30; // ~ 1 month
+pub const SECONDS_PER_BLOCK: Timestamp = 6;
+pub const MAX_PHASE_DURATION_BLOCKS: Block = MAX_PHASE_DURATION / SECONDS_PER_BLOCK;
+
+/// Index of the `Idle` phase. The pipeline phases start from index 1.
+pub const IDLE_PHASE_INDEX: usize = 0;
+pub const MAX_COOLDOWN_PHASES: usize = 5;
 
 #[multiversx_sc::module]
 pub trait PhaseModule:
@@ -20,37 +59,227 @@ pub trait PhaseModule:
 {
     #[view(getCurrentPhase)]
     fn get_current_phase(&self) -> Phase {
-        let current_time = self.blockchain().get_block_timestamp();
-        let start_time = self.start_time().get();
-        if current_time < start_time {
-            return Phase::Idle;
+        let phase_index = self.get_current_phase_index();
+        self.get_phase_kind(phase_index)
+    }
+
+    /// The `Idle` phase starts at `0`
+    #[view(getPhaseSchedule)]
+    fn get_phase_schedule(&self) -> MultiValueEncoded<PhaseInterval> {
+        let mut phase_start = self.start_time().get();
+
+        let mut schedule = MultiValueEncoded::new();
+        schedule.push(PhaseInterval {
+            phase: Phase::Idle,
+            start: 0,
+            end: Some(phase_start),
+        });
+        for definition in self.phase_pipeline().iter() {
+            let phase_end = phase_start + self.get_extended_duration(&definition);
+            schedule.push(PhaseInterval {
+                phase: definition.kind,
+                start: phase_start,
+                end: Some(phase_end),
+            });
+
+            phase_start = phase_end;
+        }
+        schedule.push(PhaseInterval {
+            phase: Phase::UserRedeem,
+            start: phase_start,
+            end: None,
+        });
+
+        schedule
+    }
+
+    /// Returns nothing in the last phase
+    #[view(getTimeUntilNextPhase)]
+    fn get_time_until_next_phase(&self) -> OptionalValue<Timestamp> {
+        let current_time = self.get_current_time();
+        let mut phase_end = self.start_time().get();
+        if current_time < phase_end {
+            return OptionalValue::Some(phase_end - current_time);
+        }
+
+        for definition in self.phase_pipeline().iter() {
+            phase_end += self.get_extended_duration(&definition);
+            if current_time < phase_end {
+                return OptionalValue::Some(phase_end - current_time);
+            }
+        }
+
+        OptionalValue::None
+    }
+
+    #[view(getUserDepositWithdrawTime)]
+    fn get_user_deposit_withdraw_time(&self) -> Timestamp {
+        self.get_phase_duration(&Phase::UserDepositWithdraw)
+    }
+
+    #[view(getOwnerDepositWithdrawTime)]
+    fn get_owner_deposit_withdraw_time(&self) -> Timestamp {
+        self.get_phase_duration(&Phase::OwnerDepositWithdraw)
+    }
+
+    #[view(getOwnerRedeemTime)]
+    fn get_owner_redeem_time(&self) -> Timestamp {
+        self.get_phase_duration(&Phase::OwnerRedeem)
+    }
+
+    /// The current block timestamp or block nonce, depending on the time base
+    fn get_current_time(&self) -> Timestamp {
+        match self.time_base().get() {
+            TimeBase::Timestamp => self.blockchain().get_block_timestamp(),
+            TimeBase::BlockNonce => self.blockchain().get_block_nonce(),
+        }
+    }
+
+    fn get_max_phase_duration(&self) -> Timestamp {
+        match self.time_base().get() {
+            TimeBase::Timestamp => MAX_PHASE_DURATION,
+            TimeBase::BlockNonce => MAX_PHASE_DURATION_BLOCKS,
+        }
+    }
+
+    /// `0` for `Idle`, the pipeline index for the pipeline phases,
+    /// and the pipeline length + 1 for `UserRedeem`
+    fn get_current_phase_index(&self) -> usize {
+        let current_time = self.get_current_time();
+        let mut phase_end = self.start_time().get();
+        if current_time < phase_end {
+            return IDLE_PHASE_INDEX;
+        }
+
+        let pipeline_mapper = self.phase_pipeline();
+        for index in 1..=pipeline_mapper.len() {
+            phase_end += self.get_extended_duration(&pipeline_mapper.get(index));
+            if current_time < phase_end {
+                return index;
+            }
+        }
+
+        pipeline_mapper.len() + 1
+    }
+
+    fn get_phase_kind(&self, phase_index: usize) -> Phase {
+        let pipeline_mapper = self.phase_pipeline();
+        if phase_index == IDLE_PHASE_INDEX {
+            Phase::Idle
+        } else if phase_index > pipeline_mapper.len() {
+            Phase::UserRedeem
+        } else {
+            pipeline_mapper.get(phase_index).kind
         }
+    }
 
-        let user_deposit_time = self.user_deposit_withdraw_time().get();
-        let user_deposit_phase_end = start_time + user_deposit_time;
-        if current_time < user_deposit_phase_end {
-            return Phase::UserDepositWithdraw;
+    /// The pipeline index of the first phase of the given kind
+    fn get_phase_index(&self, kind: &Phase) -> usize {
+        let pipeline_mapper = self.phase_pipeline();
+        for index in 1..=pipeline_mapper.len() {
+            if &pipeline_mapper.get(index).kind == kind {
+                return index;
+            }
         }
 
-        let owner_deposit_time = self.owner_deposit_withdraw_time().get();
-        let owner_deposit_phase_end = user_deposit_phase_end + owner_deposit_time;
-        if current_time < owner_deposit_phase_end {
-            return Phase::OwnerDepositWithdraw;
+        sc_panic!("Phase not found");
+    }
+
+    fn get_phase_duration(&self, kind: &Phase) -> Timestamp {
+        let phase_index = self.get_phase_index(kind);
+        self.get_extended_duration(&self.phase_pipeline().get(phase_index))
+    }
+
+    /// The soft close extension is stored apart from the pipeline,
+    /// so the duration setters never overwrite it
+    fn get_extended_duration(&self, definition: &PhaseDefinition) -> Timestamp {
+        if definition.kind == Phase::UserDepositWithdraw {
+            definition.duration + self.soft_close_total_extension().get()
+        } else {
+            definition.duration
+        }
+    }
+
+    fn get_phase_start_by_index(&self, phase_index: usize) -> Timestamp {
+        let pipeline_mapper = self.phase_pipeline();
+        let mut phase_start = self.start_time().get();
+        for index in 1..phase_index {
+            phase_start += self.get_extended_duration(&pipeline_mapper.get(index));
+        }
+
+        phase_start
+    }
+
+    fn get_phase_start(&self, kind: &Phase) -> Timestamp {
+        let phase_index = self.get_phase_index(kind);
+        self.get_phase_start_by_index(phase_index)
+    }
+
+    fn get_phase_end(&self, kind: &Phase) -> Timestamp {
+        let phase_index = self.get_phase_index(kind);
+        self.get_phase_start_by_index(phase_index + 1)
+    }
+
+    fn build_default_phase_pipeline(
+        &self,
+        user_deposit_withdraw_time: Timestamp,
+        owner_deposit_withdraw_time: Timestamp,
+        owner_redeem_time: Timestamp,
+    ) -> ManagedVec<PhaseDefinition> {
+        let mut pipeline = ManagedVec::new();
+        pipeline.push(PhaseDefinition {
+            kind: Phase::UserDepositWithdraw,
+            duration: user_deposit_withdraw_time,
+        });
+        pipeline.push(PhaseDefinition {
+            kind: Phase::OwnerDepositWithdraw,
+            duration: owner_deposit_withdraw_time,
+        });
+        pipeline.push(PhaseDefinition {
+            kind: Phase::OwnerRedeem,
+            duration: owner_redeem_time,
+        });
+
+        pipeline
+    }
+
+    fn get_phase_pipeline(&self) -> ManagedVec<PhaseDefinition> {
+        let mut pipeline = ManagedVec::new();
+        for definition in self.phase_pipeline().iter() {
+            pipeline.push(definition);
         }
 
-        let owner_redeem_time = self.owner_redeem_time().get();
-        let owner_redeem_phase_end = owner_deposit_phase_end + owner_redeem_time;
-        if current_time < owner_redeem_phase_end {
-            return Phase::OwnerRedeem;
+        pipeline
+    }
+
+    fn set_phase_pipeline(&self, pipeline: &ManagedVec<PhaseDefinition>) {
+        let mut pipeline_mapper = self.phase_pipeline();
+        pipeline_mapper.clear();
+        for definition in pipeline.iter() {
+            let _ = pipeline_mapper.push(&definition);
         }
+    }
+
+    fn get_user_deposit_withdraw_start(&self) -> Timestamp {
+        self.get_phase_start(&Phase::UserDepositWithdraw)
+    }
 
-        Phase::UserRedeem
+    fn get_user_deposit_withdraw_end(&self) -> Timestamp {
+        self.get_phase_end(&Phase::UserDepositWithdraw)
+    }
+
+    fn get_owner_deposit_withdraw_end(&self) -> Timestamp {
+        self.get_phase_end(&Phase::OwnerDepositWithdraw)
+    }
+
+    fn get_owner_redeem_end(&self) -> Timestamp {
+        self.get_phase_end(&Phase::OwnerRedeem)
     }
 
     fn require_user_deposit_withdraw_allowed(&self, phase: &Phase) {
         require!(
             phase == &Phase::UserDepositWithdraw,
-            "User deposit/withdraw not allowed in this phase"
+            USER_DEPOSIT_WITHDRAW_NOT_ALLOWED_ERR_MSG
         );
     }
 
@@ -75,22 +304,33 @@ pub trait PhaseModule:
         );
     }
 
-    fn require_before_redeem(&self, phase: &Phase) {
+    fn require_before_redeem(&self) {
+        let current_phase_index = self.get_current_phase_index();
+        let owner_redeem_index = self.get_phase_index(&Phase::OwnerRedeem);
         require!(
-            phase < &Phase::OwnerRedeem,
+            current_phase_index < owner_redeem_index,
             "May only call this endpoint before redeem phase"
         );
     }
 
-    #[view(getUserDepositWithdrawTime)]
+    #[view(getPhasePipeline)]
+    #[storage_mapper("phasePipeline")]
+    fn phase_pipeline(&self) -> VecMapper<PhaseDefinition>;
+
+    /// Added to the duration of the user deposit phase
+    #[view(getSoftCloseTotalExtension)]
+    #[storage_mapper("softCloseTotalExtension")]
+    fn soft_close_total_extension(&self) -> SingleValueMapper<Timestamp>;
+
+    /// Only used to migrate the contracts deployed before the phase pipeline
     #[storage_mapper("userDepositWithdrawTime")]
-    fn user_deposit_withdraw_time(&self) -> SingleValueMapper<Timestamp>;
+    fn legacy_user_deposit_withdraw_time(&self) -> SingleValueMapper<Timestamp>;
 
-    #[view(getOwnerDepositWithdrawTime)]
+    /// Only used to migrate the contracts deployed before the phase pipeline
     #[storage_mapper("ownerDepositWithdrawTime")]
-    fn owner_deposit_withdraw_time(&self) -> SingleValueMapper<Timestamp>;
+    fn legacy_owner_deposit_withdraw_time(&self) -> SingleValueMapper<Timestamp>;
 
-    #[view(getOwnerRedeemTime)]
+    /// Only used to migrate the contracts deployed before the phase pipeline
     #[storage_mapper("ownerRedeemTime")]
-    fn owner_redeem_time(&self) -> SingleValueMapper<Timestamp>;
+    fn legacy_owner_redeem_time(&self) -> SingleValueMapper<Timestamp>;
 }
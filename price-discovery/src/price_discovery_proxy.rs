@@ -0,0 +1,1626 @@
+// Code generated by the multiversx-sc proxy generator. DO NOT EDIT.
+
+////////////////////////////////////////////////////
+////////////////// AUTO-GENERATED //////////////////
+////////////////////////////////////////////////////
+
+#![allow(dead_code)]
+#![allow(clippy::all)]
+
+use multiversx_sc::proxy_imports::*;
+
+pub struct PriceDiscoveryProxy;
+
+impl<Env, From, To, Gas> TxProxyTrait<Env, From, To, Gas> for PriceDiscoveryProxy
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    type TxProxyMethods = PriceDiscoveryProxyMethods<Env, From, To, Gas>;
+
+    fn proxy_methods(self, tx: Tx<Env, From, To, (), Gas, (), ()>) -> Self::TxProxyMethods {
+        PriceDiscoveryProxyMethods { wrapped_tx: tx }
+    }
+}
+
+pub struct PriceDiscoveryProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    wrapped_tx: Tx<Env, From, To, (), Gas, (), ()>,
+}
+
+#[rustfmt::skip]
+impl<Env, From, Gas> PriceDiscoveryProxyMethods<Env, From, (), Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    Gas: TxGas<Env>,
+{
+    /// For explanations regarding what each parameter means, please refer to docs/setup.md 
+    pub fn init<
+        Arg0: ProxyArg<TokenIdentifier<Env::Api>>,
+        Arg1: ProxyArg<EgldOrEsdtTokenIdentifier<Env::Api>>,
+        Arg2: ProxyArg<u32>,
+        Arg3: ProxyArg<u64>,
+        Arg4: ProxyArg<u64>,
+        Arg5: ProxyArg<u64>,
+        Arg6: ProxyArg<u64>,
+        Arg7: ProxyArg<BigUint<Env::Api>>,
+        Arg8: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg9: ProxyArg<OptionalValue<SaleMode<Env::Api>>>,
+        Arg10: ProxyArg<OptionalValue<ManagedVec<Env::Api, PhaseDefinition>>>,
+        Arg11: ProxyArg<OptionalValue<TimeBase>>,
+    >(
+        self,
+        launched_token_id: Arg0,
+        accepted_token_id: Arg1,
+        launched_token_decimals: Arg2,
+        start_time: Arg3,
+        user_deposit_withdraw_time: Arg4,
+        owner_deposit_withdraw_time: Arg5,
+        owner_redeem_time: Arg6,
+        user_min_deposit: Arg7,
+        admin: Arg8,
+        opt_sale_mode: Arg9,
+        opt_phase_pipeline: Arg10,
+        opt_time_base: Arg11,
+    ) -> TxTypedDeploy<Env, From, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_deploy()
+            .argument(&launched_token_id)
+            .argument(&accepted_token_id)
+            .argument(&launched_token_decimals)
+            .argument(&start_time)
+            .argument(&user_deposit_withdraw_time)
+            .argument(&owner_deposit_withdraw_time)
+            .argument(&owner_redeem_time)
+            .argument(&user_min_deposit)
+            .argument(&admin)
+            .argument(&opt_sale_mode)
+            .argument(&opt_phase_pipeline)
+            .argument(&opt_time_base)
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> PriceDiscoveryProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn upgrade(
+        self,
+    ) -> TxTypedUpgrade<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_upgrade()
+            .original_result()
+    }
+}
+
+#[rustfmt::skip]
+impl<Env, From, To, Gas> PriceDiscoveryProxyMethods<Env, From, To, Gas>
+where
+    Env: TxEnv,
+    Env::Api: VMApi,
+    From: TxFrom<Env>,
+    To: TxTo<Env>,
+    Gas: TxGas<Env>,
+{
+    pub fn min_launched_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getMinLaunchedTokens")
+            .original_result()
+    }
+
+    pub fn start_time(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getStartTime")
+            .original_result()
+    }
+
+    /// Contracts deployed before the time base was configurable use timestamps 
+    pub fn time_base(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, TimeBase> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTimeBase")
+            .original_result()
+    }
+
+    pub fn sale_mode(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, SaleMode<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSaleMode")
+            .original_result()
+    }
+
+    /// `0` if the user deposit phase may not be closed early 
+    pub fn target_raise(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTargetRaise")
+            .original_result()
+    }
+
+    /// `0` if the settlement uses the final price instead of the TWAP 
+    pub fn twap_settlement_window(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTwapSettlementWindow")
+            .original_result()
+    }
+
+    pub fn admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAdmin")
+            .original_result()
+    }
+
+    pub fn get_current_phase(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, Phase> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentPhase")
+            .original_result()
+    }
+
+    /// The `Idle` phase starts at `0` 
+    pub fn get_phase_schedule(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PhaseInterval>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPhaseSchedule")
+            .original_result()
+    }
+
+    /// Returns nothing in the last phase 
+    pub fn get_time_until_next_phase(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<u64>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTimeUntilNextPhase")
+            .original_result()
+    }
+
+    pub fn get_user_deposit_withdraw_time(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserDepositWithdrawTime")
+            .original_result()
+    }
+
+    pub fn get_owner_deposit_withdraw_time(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOwnerDepositWithdrawTime")
+            .original_result()
+    }
+
+    pub fn get_owner_redeem_time(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOwnerRedeemTime")
+            .original_result()
+    }
+
+    pub fn phase_pipeline(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PhaseDefinition>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPhasePipeline")
+            .original_result()
+    }
+
+    /// Added to the duration of the user deposit phase 
+    pub fn soft_close_total_extension(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSoftCloseTotalExtension")
+            .original_result()
+    }
+
+    pub fn get_auction_price(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getAuctionPrice")
+            .original_result()
+    }
+
+    pub fn bonding_curve_tokens_sold(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBondingCurveTokensSold")
+            .original_result()
+    }
+
+    /// Returns pairs of (max_price, total_deposits), sorted by price. 
+    /// Deposits without a max price are not included. 
+    pub fn get_demand_by_max_price(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue2<BigUint<Env::Api>, BigUint<Env::Api>>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDemandByMaxPrice")
+            .original_result()
+    }
+
+    /// The total deposits of the users that accept to buy at `price` 
+    pub fn get_demand_at_price<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        price: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getDemandAtPrice")
+            .argument(&price)
+            .original_result()
+    }
+
+    /// The clearing price for the currently deposited launched tokens 
+    pub fn get_limit_order_clearing_price(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getLimitOrderClearingPrice")
+            .original_result()
+    }
+
+    /// Returns the stored checkpoints between `from` and `to`, inclusive. 
+    /// Only the last `MAX_PRICE_CHECKPOINTS` checkpoints are stored. 
+    pub fn get_price_history<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PriceCheckpoint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPriceHistory")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// The time-weighted average price since the start of the user deposit phase, 
+    /// until its end or the current time, using the current launched token balance. 
+    /// Returns nothing while no launched tokens are deposited 
+    pub fn get_time_weighted_average_price(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTimeWeightedAveragePrice")
+            .original_result()
+    }
+
+    /// The time-weighted average accepted token balance since the start of the user deposit phase, 
+    /// until its end or the current time 
+    pub fn get_user_phase_average_accepted_balance(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserPhaseAverageAcceptedBalance")
+            .original_result()
+    }
+
+    /// The time-weighted average accepted token balance between two timestamps, 
+    /// which must be covered by the stored checkpoints 
+    pub fn get_time_weighted_accepted_balance<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        from: Arg0,
+        to: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTimeWeightedAcceptedBalance")
+            .argument(&from)
+            .argument(&to)
+            .original_result()
+    }
+
+    /// The settlement price for the current balances 
+    pub fn get_twap_settlement_price(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTwapSettlementPrice")
+            .original_result()
+    }
+
+    pub fn soft_close_config(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, SoftCloseConfig> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getSoftCloseConfig")
+            .original_result()
+    }
+
+    pub fn grant_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("grantRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn revoke_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("revokeRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    /// The new admin has to call `acceptAdmin` to take over. 
+    /// A new proposal replaces the previous one 
+    pub fn propose_admin<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_admin: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeAdmin")
+            .argument(&new_admin)
+            .original_result()
+    }
+
+    pub fn accept_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acceptAdmin")
+            .original_result()
+    }
+
+    /// User deposits and withdrawals are not allowed while paused. Redeems are always allowed 
+    pub fn pause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("pause")
+            .original_result()
+    }
+
+    pub fn unpause(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unpause")
+            .original_result()
+    }
+
+    /// Does not include the admin 
+    pub fn get_role_holders<
+        Arg0: ProxyArg<Role>,
+    >(
+        self,
+        role: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getRoleHolders")
+            .argument(&role)
+            .original_result()
+    }
+
+    pub fn get_pending_admin(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingAdmin")
+            .original_result()
+    }
+
+    pub fn has_role<
+        Arg0: ProxyArg<Role>,
+        Arg1: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        role: Arg0,
+        address: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("hasRole")
+            .argument(&role)
+            .argument(&address)
+            .original_result()
+    }
+
+    pub fn paused(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isPaused")
+            .original_result()
+    }
+
+    pub fn get_pending_changes(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, PendingChange<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingChanges")
+            .original_result()
+    }
+
+    pub fn timelock_delay(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, u64> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTimelockDelay")
+            .original_result()
+    }
+
+    /// Once the board is set, the contract becomes its own admin, 
+    /// so the admin endpoints may only be called through board actions. 
+    /// All the granted roles are revoked as well 
+    pub fn set_board<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        quorum: Arg0,
+        members: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setBoard")
+            .argument(&quorum)
+            .argument(&members)
+            .original_result()
+    }
+
+    /// The proposer signs the action as well 
+    pub fn propose_action<
+        Arg0: ProxyArg<BoardAction<Env::Api>>,
+    >(
+        self,
+        action: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeAction")
+            .argument(&action)
+            .original_result()
+    }
+
+    pub fn sign_action<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("signAction")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn unsign_action<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("unsignAction")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    /// Only actions without signatures may be discarded 
+    pub fn discard_action<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("discardAction")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn get_board_members(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getBoardMembers")
+            .original_result()
+    }
+
+    /// `Nothing` for performed or discarded actions 
+    pub fn get_action_data<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BoardAction<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActionData")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn get_action_signers<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getActionSigners")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    pub fn get_pending_action_ids(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, usize>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingActionIds")
+            .original_result()
+    }
+
+    pub fn quorum(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getQuorum")
+            .original_result()
+    }
+
+    /// Users can deposit accepted_tokens. 
+    /// Later, they can withdraw launched tokens 
+    ///  
+    /// `opt_max_price` is the max price the user accepts for their whole deposit, 
+    /// and replaces the one given on previous deposits 
+    pub fn user_deposit<
+        Arg0: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        opt_max_price: Arg0,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("userDeposit")
+            .argument(&opt_max_price)
+            .original_result()
+    }
+
+    /// Withdraw the initially deposited tokens. 
+    pub fn user_withdraw_endpoint<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        withdraw_amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("userWithdraw")
+            .argument(&withdraw_amount)
+            .original_result()
+    }
+
+    pub fn is_user_whitelisted<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, bool> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("isUserWhitelisted")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn get_user_deposit_limit<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<BigUint<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserDepositLimit")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn get_total_deposit_by_user<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getTotalDepositByUser")
+            .argument(&user)
+            .original_result()
+    }
+
+    pub fn user_min_deposit(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserMinDeposit")
+            .original_result()
+    }
+
+    /// The number of users with a non-zero deposit 
+    pub fn nr_depositors(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNrDepositors")
+            .original_result()
+    }
+
+    /// Not blocked while paused, so a pauser may not run out the owner phase 
+    pub fn owner_deposit(
+        self,
+    ) -> TxTypedCall<Env, From, To, (), Gas, ()> {
+        self.wrapped_tx
+            .raw_call("ownerDeposit")
+            .original_result()
+    }
+
+    /// Not blocked while paused, so a pauser may not run out the owner phase 
+    pub fn owner_withdraw<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        withdraw_amount: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("ownerWithdraw")
+            .argument(&withdraw_amount)
+            .original_result()
+    }
+
+    /// After all phases have ended, 
+    /// users can withdraw their fair share of launched tokens. 
+    pub fn user_redeem_endpoint(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("userRedeem")
+            .original_result()
+    }
+
+    /// After the OwnerDepositWithdraw phase has ended, 
+    /// the owner can withdraw the accepted tokens. 
+    pub fn owner_redeem_endpoint(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, EgldOrEsdtTokenPayment<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("ownerRedeem")
+            .original_result()
+    }
+
+    /// The accepted tokens of `ownerRedeem` are sent to the beneficiary, once it calls `acceptBeneficiary`. 
+    /// A new proposal replaces the previous one 
+    pub fn propose_beneficiary<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        new_beneficiary: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("proposeBeneficiary")
+            .argument(&new_beneficiary)
+            .original_result()
+    }
+
+    pub fn accept_beneficiary(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("acceptBeneficiary")
+            .original_result()
+    }
+
+    /// The owner, if no beneficiary was accepted 
+    pub fn get_owner_redeem_beneficiary(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ManagedAddress<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getOwnerRedeemBeneficiary")
+            .original_result()
+    }
+
+    pub fn get_pending_beneficiary(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, OptionalValue<ManagedAddress<Env::Api>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getPendingBeneficiary")
+            .original_result()
+    }
+
+    /// Only to be used in the cases where the owner somehow missed the long owner redeem phase 
+    pub fn withdraw_launchpad_tokens(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("withdrawLaunchpadTokens")
+            .original_result()
+    }
+
+    pub fn nr_redeemers(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNrRedeemers")
+            .original_result()
+    }
+
+    /// Moves the whole schedule, as the phase durations are relative to the start time 
+    pub fn set_start_time<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        start_time: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setStartTime")
+            .argument(&start_time)
+            .original_result()
+    }
+
+    pub fn set_user_deposit_withdraw_time<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        user_deposit_withdraw_time: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setUserDepositWithdrawTime")
+            .argument(&user_deposit_withdraw_time)
+            .original_result()
+    }
+
+    pub fn set_owner_deposit_withdraw_time<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        owner_deposit_withdraw_time: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setOwnerDepositWithdrawTime")
+            .argument(&owner_deposit_withdraw_time)
+            .original_result()
+    }
+
+    pub fn set_owner_redeem_time<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        owner_redeem_time: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setOwnerRedeemTime")
+            .argument(&owner_redeem_time)
+            .original_result()
+    }
+
+    /// Changes the duration of any phase in the pipeline, including the cooldown phases. 
+    /// `phase_index` starts from 1, as returned by `getPhasePipeline` 
+    pub fn set_phase_duration<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        phase_index: Arg0,
+        duration: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setPhaseDuration")
+            .argument(&phase_index)
+            .argument(&duration)
+            .original_result()
+    }
+
+    /// Inserts a cooldown phase at `phase_index`, before the phase currently found there. 
+    /// Pass the pipeline length + 1 to add it after all the other phases 
+    pub fn add_cooldown_phase<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<u64>,
+    >(
+        self,
+        phase_index: Arg0,
+        duration: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addCooldownPhase")
+            .argument(&phase_index)
+            .argument(&duration)
+            .original_result()
+    }
+
+    pub fn remove_cooldown_phase<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        phase_index: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("removeCooldownPhase")
+            .argument(&phase_index)
+            .original_result()
+    }
+
+    /// Pass `0` to settle at the final price instead of the TWAP. 
+    /// Only available for the `Standard` sale mode, before the start 
+    pub fn set_twap_settlement_window<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        window: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTwapSettlementWindow")
+            .argument(&window)
+            .original_result()
+    }
+
+    /// A deposit in the last `trigger_window` of the user deposit phase extends it by `extension`, 
+    /// up to `max_total_extension`. Pass `0` for all the values to disable it. 
+    /// Not available for the `DutchAuction` sale mode, and only before the start 
+    pub fn set_soft_close_config<
+        Arg0: ProxyArg<u64>,
+        Arg1: ProxyArg<u64>,
+        Arg2: ProxyArg<u64>,
+    >(
+        self,
+        trigger_window: Arg0,
+        extension: Arg1,
+        max_total_extension: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setSoftCloseConfig")
+            .argument(&trigger_window)
+            .argument(&extension)
+            .argument(&max_total_extension)
+            .original_result()
+    }
+
+    /// Pass `0` to disable closing the user deposit phase early. 
+    /// May not be changed after the user deposit phase ended 
+    pub fn set_target_raise<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        target_raise: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTargetRaise")
+            .argument(&target_raise)
+            .original_result()
+    }
+
+    /// Ends the user deposit phase at the current time, once the target raise is reached. 
+    /// This is the only way of reducing the user deposit phase after it started. 
+    /// With a timelock, the phase ends when the change is executed 
+    pub fn close_user_phase(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("closeUserPhase")
+            .original_result()
+    }
+
+    pub fn set_min_launched_tokens<
+        Arg0: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        min_launched_tokens: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setMinLaunchedTokens")
+            .argument(&min_launched_tokens)
+            .original_result()
+    }
+
+    /// Schedule and min launched tokens changes are queued for `delay`, if it is not `0`. 
+    /// The delay may only be increased 
+    pub fn set_timelock_delay<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        delay: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setTimelockDelay")
+            .argument(&delay)
+            .original_result()
+    }
+
+    /// The change is validated again on execution, against the current state 
+    pub fn execute_change<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("executeChange")
+            .argument(&id)
+            .original_result()
+    }
+
+    pub fn cancel_change<
+        Arg0: ProxyArg<u64>,
+    >(
+        self,
+        id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("cancelChange")
+            .argument(&id)
+            .original_result()
+    }
+
+    /// Performs the action once enough board members signed it 
+    pub fn perform_action<
+        Arg0: ProxyArg<usize>,
+    >(
+        self,
+        action_id: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("performAction")
+            .argument(&action_id)
+            .original_result()
+    }
+
+    /// Pass `0` for `limit` if there is no limit 
+    pub fn set_user_limit<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("setUserLimit")
+            .argument(&user)
+            .argument(&limit)
+            .original_result()
+    }
+
+    /// `whitelist` arguments are pairs of (address, max_total_deposit). Pass `0` for `max_total_deposit` if there is no limit 
+    pub fn add_users_to_whitelist<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, MultiValue2<ManagedAddress<Env::Api>, BigUint<Env::Api>>>>,
+    >(
+        self,
+        whitelist: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("addUsersToWhitelist")
+            .argument(&whitelist)
+            .original_result()
+    }
+
+    pub fn refund_users<
+        Arg0: ProxyArg<MultiValueEncoded<Env::Api, ManagedAddress<Env::Api>>>,
+    >(
+        self,
+        users: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ()> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("refundUsers")
+            .argument(&users)
+            .original_result()
+    }
+
+    /// For bonding curve sales, this is the marginal price of the curve 
+    pub fn get_current_price(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, BigUint<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getCurrentPrice")
+            .original_result()
+    }
+
+    pub fn get_contract_state(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, ContractState<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getContractState")
+            .original_result()
+    }
+
+    pub fn get_user_info<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, UserInfo<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getUserInfo")
+            .argument(&user)
+            .original_result()
+    }
+
+    /// Returns (address, total_deposit, deposit_limit, redeemed) for each whitelisted user. 
+    /// The order changes when users are refunded. 
+    pub fn get_participants<
+        Arg0: ProxyArg<usize>,
+        Arg1: ProxyArg<usize>,
+    >(
+        self,
+        offset: Arg0,
+        limit: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, MultiValueEncoded<Env::Api, MultiValue4<ManagedAddress<Env::Api>, BigUint<Env::Api>, BigUint<Env::Api>, bool>>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getParticipants")
+            .argument(&offset)
+            .argument(&limit)
+            .original_result()
+    }
+
+    pub fn get_nr_whitelisted_users(
+        self,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, usize> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("getNrWhitelistedUsers")
+            .original_result()
+    }
+
+    /// Runs the same checks as `userDeposit`, without the payment 
+    pub fn simulate_deposit<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+        Arg2: ProxyArg<OptionalValue<BigUint<Env::Api>>>,
+    >(
+        self,
+        user: Arg0,
+        amount: Arg1,
+        opt_max_price: Arg2,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, SimulationResult<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("simulateDeposit")
+            .argument(&user)
+            .argument(&amount)
+            .argument(&opt_max_price)
+            .original_result()
+    }
+
+    /// Runs the same checks as `userWithdraw` 
+    pub fn simulate_withdraw<
+        Arg0: ProxyArg<ManagedAddress<Env::Api>>,
+        Arg1: ProxyArg<BigUint<Env::Api>>,
+    >(
+        self,
+        user: Arg0,
+        amount: Arg1,
+    ) -> TxTypedCall<Env, From, To, NotPayable, Gas, SimulationResult<Env::Api>> {
+        self.wrapped_tx
+            .payment(NotPayable)
+            .raw_call("simulateWithdraw")
+            .argument(&user)
+            .argument(&amount)
+            .original_result()
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum SaleMode<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Standard,
+    DutchAuction(DutchAuctionConfig<Api>),
+    BondingCurve(BondingCurve<Api>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct DutchAuctionConfig<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub max_price: BigUint<Api>,
+    pub min_price: BigUint<Api>,
+    pub price_step_duration: u64,
+}
+
+#[rustfmt::skip]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum BondingCurve<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Linear {
+        initial_price: BigUint<Api>,
+        slope: BigUint<Api>,
+    },
+    Exponential {
+        initial_price: BigUint<Api>,
+        growth_step: BigUint<Api>,
+        growth_rate: u64,
+    },
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone)]
+pub struct PhaseDefinition {
+    pub kind: Phase,
+    pub duration: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone)]
+pub enum Phase {
+    Idle,
+    UserDepositWithdraw,
+    OwnerDepositWithdraw,
+    OwnerRedeem,
+    UserRedeem,
+    Cooldown,
+}
+
+#[type_abi]
+#[derive(
+    TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, PartialEq, Clone, Copy,
+)]
+pub enum TimeBase {
+    Timestamp,
+    BlockNonce,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct UserDepositEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_amount_in: BigUint<Api>,
+    pub accepted_token_amount: BigUint<Api>,
+    pub user_total_deposit: BigUint<Api>,
+    pub launched_token_amount: BigUint<Api>,
+    pub implied_price: Option<BigUint<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct UserWithdrawEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_amount_out: BigUint<Api>,
+    pub accepted_token_amount: BigUint<Api>,
+    pub user_total_deposit: BigUint<Api>,
+    pub launched_token_amount: BigUint<Api>,
+    pub implied_price: Option<BigUint<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct OwnerDepositEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_amount_in: BigUint<Api>,
+    pub launched_token_amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct OwnerWithdrawEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub token_amount_out: BigUint<Api>,
+    pub launched_token_amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct RedeemEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub bought_token_id: EgldOrEsdtTokenIdentifier<Api>,
+    pub bought_token_amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct RefundUserEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub user: ManagedAddress<Api>,
+    pub accepted_token_amount: BigUint<Api>,
+    pub refunded_amount: BigUint<Api>,
+    pub user_total_deposit: BigUint<Api>,
+    pub launched_token_amount: BigUint<Api>,
+    pub implied_price: Option<BigUint<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct WhitelistUserEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub user: ManagedAddress<Api>,
+    pub deposit_limit: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct WithdrawLaunchpadTokensEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub launched_token_id: TokenIdentifier<Api>,
+    pub launched_token_amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct FinalizeSaleEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub beneficiary: ManagedAddress<Api>,
+    pub accepted_token_amount: BigUint<Api>,
+    pub unsold_launched_tokens: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct PhaseDurationEvent {
+    pub phase_index: usize,
+    pub duration: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct SoftCloseConfig {
+    pub trigger_window: u64,
+    pub extension: u64,
+    pub max_total_extension: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct RoleEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub role: Role,
+    pub address: ManagedAddress<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum Role {
+    TimingManager,
+    WhitelistManager,
+    RefundOperator,
+    Pauser,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct PendingChange<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub id: u64,
+    pub change: ParameterChange<Api>,
+    pub executable_at: u64,
+}
+
+#[rustfmt::skip]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum ParameterChange<Api>
+where
+    Api: ManagedTypeApi,
+{
+    PhaseDuration {
+        phase_index: usize,
+        kind: Phase,
+        duration: u64,
+    },
+    MinLaunchedTokens(BigUint<Api>),
+    StartTime(u64),
+    AddCooldownPhase {
+        phase_index: usize,
+        duration: u64,
+    },
+    RemoveCooldownPhase(usize),
+    CloseUserPhase,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct SetBoardEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub quorum: usize,
+    pub members: ManagedVec<Api, ManagedAddress<Api>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct ProposeActionEvent<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub action_id: usize,
+    pub action: BoardAction<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum BoardAction<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Nothing,
+    SetMinLaunchedTokens(BigUint<Api>),
+    RefundUsers(ManagedVec<Api, ManagedAddress<Api>>),
+    SetUserLimit(ManagedAddress<Api>, BigUint<Api>),
+    SetStartTime(u64),
+    SetUserDepositWithdrawTime(u64),
+    SetOwnerDepositWithdrawTime(u64),
+    SetOwnerRedeemTime(u64),
+    SetPhaseDuration(usize, u64),
+    ExecuteChange(u64),
+    CancelChange(u64),
+    AddUsersToWhitelist(ManagedVec<Api, WhitelistEntry<Api>>),
+    GrantRole(Role, ManagedAddress<Api>),
+    RevokeRole(Role, ManagedAddress<Api>),
+    Pause,
+    Unpause,
+    SetTargetRaise(BigUint<Api>),
+    SetTwapSettlementWindow(u64),
+    AddCooldownPhase(usize, u64),
+    RemoveCooldownPhase(usize),
+    CloseUserPhase,
+    SetSoftCloseConfig(SoftCloseConfig),
+    SetTimelockDelay(u64),
+    ProposeAdmin(ManagedAddress<Api>),
+    ProposeBeneficiary(ManagedAddress<Api>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem)]
+pub struct WhitelistEntry<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub address: ManagedAddress<Api>,
+    pub limit: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct PhaseInterval {
+    pub phase: Phase,
+    pub start: u64,
+    pub end: Option<u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct PriceCheckpoint<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub timestamp: u64,
+    pub accepted_token_balance: BigUint<Api>,
+    pub launched_token_balance: BigUint<Api>,
+    pub cumulative_accepted_balance: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct ContractState<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub launched_token_id: TokenIdentifier<Api>,
+    pub accepted_token_id: EgldOrEsdtTokenIdentifier<Api>,
+    pub price_precision: u64,
+    pub sale_mode: SaleMode<Api>,
+    pub twap_settlement_window: u64,
+    pub user_min_deposit: BigUint<Api>,
+    pub min_launched_tokens: BigUint<Api>,
+    pub target_raise: BigUint<Api>,
+    pub launched_token_balance: BigUint<Api>,
+    pub accepted_token_balance: BigUint<Api>,
+    pub current_price: Option<BigUint<Api>>,
+    pub current_phase: Phase,
+    pub start_time: u64,
+    pub user_deposit_withdraw_end: u64,
+    pub owner_deposit_withdraw_end: u64,
+    pub owner_redeem_end: u64,
+    pub min_launched_tokens_reached: bool,
+    pub owner_redeemed: bool,
+    pub owner: ManagedAddress<Api>,
+    pub owner_redeem_beneficiary: ManagedAddress<Api>,
+    pub admin: ManagedAddress<Api>,
+    pub paused: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct UserInfo<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub user_id: u64,
+    pub whitelisted: bool,
+    pub total_deposit: BigUint<Api>,
+    pub deposit_limit: Option<BigUint<Api>>,
+    pub redeemed: bool,
+    pub projected_bought_tokens: BigUint<Api>,
+    pub redeem_amounts: UserRedeemAmounts<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct UserRedeemAmounts<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub bought_tokens: BigUint<Api>,
+    pub refund_amount: BigUint<Api>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub enum SimulationResult<Api>
+where
+    Api: ManagedTypeApi,
+{
+    Error(ManagedBuffer<Api>),
+    Success(ActionSimulation<Api>),
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ActionSimulation<Api>
+where
+    Api: ManagedTypeApi,
+{
+    pub user_total_deposit: BigUint<Api>,
+    pub total_deposit: BigUint<Api>,
+    pub implied_price: Option<BigUint<Api>>,
+}
@@ -0,0 +1,237 @@
+use crate::Timestamp;
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub const MAX_PRICE_CHECKPOINTS: usize = 500;
+
+/// `cumulative_accepted_balance` is the sum of the accepted token balance over every second
+/// since the first checkpoint, up to `timestamp`
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct PriceCheckpoint<M: ManagedTypeApi> {
+    pub timestamp: Timestamp,
+    pub accepted_token_balance: BigUint<M>,
+    pub launched_token_balance: BigUint<M>,
+    pub cumulative_accepted_balance: BigUint<M>,
+}
+
+/// The cumulative accepted balance at a fixed timestamp,
+/// kept so the averages do not depend on the bounded price history
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct CumulativeBalanceSnapshot<M: ManagedTypeApi> {
+    pub timestamp: Timestamp,
+    pub cumulative_accepted_balance: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait PriceHistoryModule:
+    crate::common_storage::CommonStorageModule + crate::events::EventsModule + crate::phase::PhaseModule
+{
+    /// Returns the stored checkpoints between `from` and `to`, inclusive.
+    /// Only the last `MAX_PRICE_CHECKPOINTS` checkpoints are stored.
+    #[view(getPriceHistory)]
+    fn get_price_history(
+        &self,
+        from: Timestamp,
+        to: Timestamp,
+    ) -> MultiValueEncoded<PriceCheckpoint<Self::Api>> {
+        let mut result = MultiValueEncoded::new();
+        for checkpoint in self.get_stored_price_checkpoints().iter() {
+            if checkpoint.timestamp >= from && checkpoint.timestamp <= to {
+                result.push(checkpoint);
+            }
+        }
+
+        result
+    }
+
+    /// The time-weighted average price since the start of the user deposit phase,
+    /// until its end or the current time, using the current launched token balance.
+    /// Returns nothing while no launched tokens are deposited
+    #[view(getTimeWeightedAveragePrice)]
+    fn get_time_weighted_average_price(&self) -> OptionalValue<BigUint> {
+        let average_accepted_balance = self.get_user_phase_average_accepted_balance();
+        let launched_token_balance = self.launched_token_balance().get();
+        if launched_token_balance == 0 {
+            return OptionalValue::None;
+        }
+
+        let price_precision = self.price_precision().get();
+        OptionalValue::Some(average_accepted_balance * price_precision / launched_token_balance)
+    }
+
+    /// The time-weighted average accepted token balance since the start of the user deposit phase,
+    /// until its end or the current time
+    #[view(getUserPhaseAverageAcceptedBalance)]
+    fn get_user_phase_average_accepted_balance(&self) -> BigUint {
+        let start_time = self.get_user_deposit_withdraw_start();
+        let current_time = self.get_current_time();
+        let end_time = core::cmp::min(current_time, self.get_user_deposit_withdraw_end());
+        require!(end_time > start_time, "User deposit phase not started");
+
+        let snapshot_mapper = self.user_phase_start_snapshot();
+        let start_cumulative_balance =
+            if !snapshot_mapper.is_empty() && snapshot_mapper.get().timestamp == start_time {
+                snapshot_mapper.get().cumulative_accepted_balance
+            } else {
+                self.get_cumulative_accepted_balance_at(start_time)
+            };
+        let end_cumulative_balance = self.get_cumulative_accepted_balance_at(end_time);
+
+        (end_cumulative_balance - start_cumulative_balance) / (end_time - start_time)
+    }
+
+    /// The time-weighted average accepted token balance between two timestamps,
+    /// which must be covered by the stored checkpoints
+    #[view(getTimeWeightedAcceptedBalance)]
+    fn get_time_weighted_accepted_balance(&self, from: Timestamp, to: Timestamp) -> BigUint {
+        require!(from < to, "Invalid time interval");
+
+        let cumulative_balance = self.get_cumulative_accepted_balance_at(to)
+            - self.get_cumulative_accepted_balance_at(from);
+        cumulative_balance / (to - from)
+    }
+
+    fn get_cumulative_accepted_balance_at(&self, timestamp: Timestamp) -> BigUint {
+        self.try_get_cumulative_accepted_balance_at(timestamp)
+            .unwrap_or_else(|| sc_panic!("Price history not available"))
+    }
+
+    /// `None` if the checkpoints before `timestamp` were dropped from the history
+    fn try_get_cumulative_accepted_balance_at(&self, timestamp: Timestamp) -> Option<BigUint> {
+        let checkpoints = self.get_stored_price_checkpoints();
+        let mut opt_last_checkpoint = None;
+        for checkpoint in checkpoints.iter() {
+            if checkpoint.timestamp > timestamp {
+                break;
+            }
+
+            opt_last_checkpoint = Some(checkpoint);
+        }
+
+        match opt_last_checkpoint {
+            Some(checkpoint) => {
+                let elapsed_time = timestamp - checkpoint.timestamp;
+                Some(
+                    checkpoint.cumulative_accepted_balance
+                        + checkpoint.accepted_token_balance * elapsed_time,
+                )
+            }
+            None => {
+                // the balance was zero before the first checkpoint
+                if self.nr_price_checkpoints().get() > MAX_PRICE_CHECKPOINTS {
+                    return None;
+                }
+
+                Some(BigUint::zero())
+            }
+        }
+    }
+
+    /// Checkpoints in the same block are merged
+    fn add_price_checkpoint(&self) {
+        self.update_user_phase_start_snapshot();
+
+        let current_time = self.get_current_time();
+        let accepted_token_balance = self.accepted_token_balance().get();
+        let launched_token_balance = self.launched_token_balance().get();
+
+        let nr_checkpoints = self.nr_price_checkpoints().get();
+        let mut checkpoints_mapper = self.price_checkpoints();
+        let cumulative_accepted_balance = if nr_checkpoints > 0 {
+            let last_index = self.get_price_checkpoint_index(nr_checkpoints - 1);
+            let last_checkpoint = checkpoints_mapper.get(last_index);
+            if last_checkpoint.timestamp == current_time {
+                let checkpoint = PriceCheckpoint {
+                    accepted_token_balance,
+                    launched_token_balance,
+                    ..last_checkpoint
+                };
+                checkpoints_mapper.set(last_index, &checkpoint);
+
+                return;
+            }
+
+            let elapsed_time = current_time - last_checkpoint.timestamp;
+            last_checkpoint.cumulative_accepted_balance
+                + last_checkpoint.accepted_token_balance * elapsed_time
+        } else {
+            BigUint::zero()
+        };
+
+        let checkpoint = PriceCheckpoint {
+            timestamp: current_time,
+            accepted_token_balance,
+            launched_token_balance,
+            cumulative_accepted_balance,
+        };
+        self.store_price_checkpoint(&mut checkpoints_mapper, nr_checkpoints, &checkpoint);
+    }
+
+    /// Taken on the first checkpoint of the user deposit phase,
+    /// while the history before the phase start is still stored
+    fn update_user_phase_start_snapshot(&self) {
+        let start_time = self.get_user_deposit_withdraw_start();
+        if self.get_current_time() < start_time {
+            return;
+        }
+
+        let snapshot_mapper = self.user_phase_start_snapshot();
+        if !snapshot_mapper.is_empty() && snapshot_mapper.get().timestamp == start_time {
+            return;
+        }
+
+        let cumulative_accepted_balance = self.get_cumulative_accepted_balance_at(start_time);
+        snapshot_mapper.set(CumulativeBalanceSnapshot {
+            timestamp: start_time,
+            cumulative_accepted_balance,
+        });
+    }
+
+    fn store_price_checkpoint(
+        &self,
+        checkpoints_mapper: &mut VecMapper<PriceCheckpoint<Self::Api>>,
+        nr_checkpoints: usize,
+        checkpoint: &PriceCheckpoint<Self::Api>,
+    ) {
+        if nr_checkpoints < MAX_PRICE_CHECKPOINTS {
+            let _ = checkpoints_mapper.push(checkpoint);
+        } else {
+            let index = self.get_price_checkpoint_index(nr_checkpoints);
+            checkpoints_mapper.set(index, checkpoint);
+        }
+
+        self.nr_price_checkpoints().set(nr_checkpoints + 1);
+    }
+
+    /// The stored checkpoints, from oldest to newest
+    fn get_stored_price_checkpoints(&self) -> ManagedVec<PriceCheckpoint<Self::Api>> {
+        let nr_checkpoints = self.nr_price_checkpoints().get();
+        let first_checkpoint = nr_checkpoints.saturating_sub(MAX_PRICE_CHECKPOINTS);
+        let checkpoints_mapper = self.price_checkpoints();
+
+        let mut checkpoints = ManagedVec::new();
+        for checkpoint_nr in first_checkpoint..nr_checkpoints {
+            let index = self.get_price_checkpoint_index(checkpoint_nr);
+            checkpoints.push(checkpoints_mapper.get(index));
+        }
+
+        checkpoints
+    }
+
+    /// The checkpoints are stored in a ring buffer. `VecMapper` indexes start from 1.
+    fn get_price_checkpoint_index(&self, checkpoint_nr: usize) -> usize {
+        checkpoint_nr % MAX_PRICE_CHECKPOINTS + 1
+    }
+
+    #[storage_mapper("priceCheckpoints")]
+    fn price_checkpoints(&self) -> VecMapper<PriceCheckpoint<Self::Api>>;
+
+    #[storage_mapper("nrPriceCheckpoints")]
+    fn nr_price_checkpoints(&self) -> SingleValueMapper<usize>;
+
+    #[storage_mapper("userPhaseStartSnapshot")]
+    fn user_phase_start_snapshot(&self) -> SingleValueMapper<CumulativeBalanceSnapshot<Self::Api>>;
+}
@@ -0,0 +1,172 @@
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub static PERMISSION_DENIED_ERR_MSG: &[u8] = b"Permission denied";
+pub static CONTRACT_PAUSED_ERR_MSG: &[u8] = b"Contract is paused";
+
+/// The admin has all the roles, and is the only one who may grant or revoke them
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, PartialEq, Clone, Copy)]
+pub enum Role {
+    TimingManager,
+    WhitelistManager,
+    RefundOperator,
+    Pauser,
+}
+
+pub const ALL_ROLES: [Role; 4] = [
+    Role::TimingManager,
+    Role::WhitelistManager,
+    Role::RefundOperator,
+    Role::Pauser,
+];
+
+#[multiversx_sc::module]
+pub trait RolesModule:
+    crate::common_storage::CommonStorageModule + crate::events::EventsModule
+{
+    #[endpoint(grantRole)]
+    fn grant_role(&self, role: Role, address: ManagedAddress) {
+        self.require_caller_admin();
+
+        self.add_role_holder(role, address);
+    }
+
+    #[endpoint(revokeRole)]
+    fn revoke_role(&self, role: Role, address: ManagedAddress) {
+        self.require_caller_admin();
+
+        self.remove_role_holder(role, address);
+    }
+
+    /// The new admin has to call `acceptAdmin` to take over.
+    /// A new proposal replaces the previous one
+    #[endpoint(proposeAdmin)]
+    fn propose_admin(&self, new_admin: ManagedAddress) {
+        self.require_caller_admin();
+
+        self.set_pending_admin(new_admin);
+    }
+
+    #[endpoint(acceptAdmin)]
+    fn accept_admin(&self) {
+        let caller = self.blockchain().get_caller();
+        let pending_admin_mapper = self.pending_admin();
+        require!(
+            !pending_admin_mapper.is_empty() && pending_admin_mapper.get() == caller,
+            "Only the proposed admin may accept"
+        );
+
+        pending_admin_mapper.clear();
+        self.admin().set(&caller);
+
+        self.emit_accept_admin_event(&caller);
+    }
+
+    /// User deposits and withdrawals are not allowed while paused. Redeems are always allowed
+    #[endpoint(pause)]
+    fn pause(&self) {
+        self.require_caller_has_role(Role::Pauser);
+
+        self.set_paused(true);
+    }
+
+    #[endpoint(unpause)]
+    fn unpause(&self) {
+        self.require_caller_has_role(Role::Pauser);
+
+        self.set_paused(false);
+    }
+
+    /// Does not include the admin
+    #[view(getRoleHolders)]
+    fn get_role_holders(&self, role: Role) -> MultiValueEncoded<ManagedAddress> {
+        let mut holders = MultiValueEncoded::new();
+        for address in self.role_holders(role).iter() {
+            holders.push(address);
+        }
+
+        holders
+    }
+
+    #[view(getPendingAdmin)]
+    fn get_pending_admin(&self) -> OptionalValue<ManagedAddress> {
+        let pending_admin_mapper = self.pending_admin();
+        if pending_admin_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(pending_admin_mapper.get())
+        }
+    }
+
+    #[view(hasRole)]
+    fn has_role(&self, role: Role, address: &ManagedAddress) -> bool {
+        address == &self.admin().get() || self.role_holders(role).contains(address)
+    }
+
+    fn add_role_holder(&self, role: Role, address: ManagedAddress) {
+        let granted = self.role_holders(role).insert(address.clone());
+        require!(granted, "Address already has this role");
+
+        self.emit_grant_role_event(role, &address);
+    }
+
+    fn remove_role_holder(&self, role: Role, address: ManagedAddress) {
+        let revoked = self.role_holders(role).swap_remove(&address);
+        require!(revoked, "Address does not have this role");
+
+        self.emit_revoke_role_event(role, &address);
+    }
+
+    /// Revokes the roles of all the holders, leaving only the admin
+    fn clear_role_holders(&self) {
+        for role in ALL_ROLES {
+            let mut holders_mapper = self.role_holders(role);
+            for address in holders_mapper.iter() {
+                self.emit_revoke_role_event(role, &address);
+            }
+            holders_mapper.clear();
+        }
+    }
+
+    fn set_pending_admin(&self, new_admin: ManagedAddress) {
+        self.pending_admin().set(&new_admin);
+
+        self.emit_propose_admin_event(&new_admin);
+    }
+
+    fn set_paused(&self, paused: bool) {
+        self.paused().set(paused);
+
+        if paused {
+            self.emit_pause_event();
+        } else {
+            self.emit_unpause_event();
+        }
+    }
+
+    fn require_caller_admin(&self) {
+        let caller = self.blockchain().get_caller();
+        let admin = self.admin().get();
+        require!(caller == admin, "Only admin may call this function");
+    }
+
+    fn require_caller_has_role(&self, role: Role) {
+        let caller = self.blockchain().get_caller();
+        require!(self.has_role(role, &caller), PERMISSION_DENIED_ERR_MSG);
+    }
+
+    fn require_not_paused(&self) {
+        require!(!self.paused().get(), CONTRACT_PAUSED_ERR_MSG);
+    }
+
+    #[storage_mapper("roleHolders")]
+    fn role_holders(&self, role: Role) -> UnorderedSetMapper<ManagedAddress>;
+
+    #[storage_mapper("pendingAdmin")]
+    fn pending_admin(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[view(isPaused)]
+    #[storage_mapper("paused")]
+    fn paused(&self) -> SingleValueMapper<bool>;
+}
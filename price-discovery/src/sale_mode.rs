@@ -0,0 +1,41 @@
+use crate::Timestamp;
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum SaleMode<M: ManagedTypeApi> {
+    Standard,
+    DutchAuction(DutchAuctionConfig<M>),
+    BondingCurve(BondingCurve<M>),
+}
+
+/// Prices use the same unit as `getCurrentPrice`,
+/// i.e. accepted tokens for one whole launched token, multiplied by the price precision.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub struct DutchAuctionConfig<M: ManagedTypeApi> {
+    pub max_price: BigUint<M>,
+    pub min_price: BigUint<M>,
+    /// Pass `0` for a linear price decrease
+    pub price_step_duration: Timestamp,
+}
+
+/// Prices use the same unit as the Dutch auction prices
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Debug)]
+pub enum BondingCurve<M: ManagedTypeApi> {
+    /// The price increases by `slope` for each whole launched token sold
+    Linear {
+        initial_price: BigUint<M>,
+        slope: BigUint<M>,
+    },
+    /// The price increases by `growth_rate` (out of `MAX_PERCENTAGE`)
+    /// for every `growth_step` accepted tokens deposited
+    Exponential {
+        initial_price: BigUint<M>,
+        growth_step: BigUint<M>,
+        growth_rate: u64,
+    },
+}
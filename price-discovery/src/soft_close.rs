@@ -0,0 +1,82 @@
+use crate::Timestamp;
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// A deposit made less than `trigger_window` before the end of the user deposit phase
+/// extends the phase by `extension`, up to `max_total_extension` in total
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct SoftCloseConfig {
+    pub trigger_window: Timestamp,
+    pub extension: Timestamp,
+    pub max_total_extension: Timestamp,
+}
+
+#[multiversx_sc::module]
+pub trait SoftCloseModule:
+    crate::common_storage::CommonStorageModule
+    + crate::events::EventsModule
+    + crate::phase::PhaseModule
+    + crate::price_history::PriceHistoryModule
+    + crate::twap_settlement::TwapSettlementModule
+{
+    fn is_soft_close(&self) -> bool {
+        !self.soft_close_config().is_empty()
+    }
+
+    fn require_valid_soft_close_config(&self, config: &SoftCloseConfig) {
+        let max_phase_duration = self.get_max_phase_duration();
+        require!(
+            config.trigger_window > 0
+                && config.trigger_window <= max_phase_duration
+                && config.extension > 0
+                && config.extension <= config.max_total_extension
+                && config.max_total_extension <= max_phase_duration,
+            "Invalid soft close config"
+        );
+    }
+
+    /// Called after every user deposit.
+    /// The total extension is added to the user deposit phase duration,
+    /// so all the following phases are moved as well
+    fn apply_soft_close(&self) {
+        if !self.is_soft_close() {
+            return;
+        }
+
+        let config = self.soft_close_config().get();
+        let current_time = self.get_current_time();
+        let phase_end = self.get_user_deposit_withdraw_end();
+        if phase_end - current_time > config.trigger_window {
+            return;
+        }
+
+        // the extended phase may not exceed the max phase duration
+        let total_extension = self.soft_close_total_extension().get();
+        let phase_duration = self.get_user_deposit_withdraw_time();
+        let extension = core::cmp::min(
+            config.extension,
+            config.max_total_extension - total_extension,
+        );
+        let extension = core::cmp::min(
+            extension,
+            self.get_max_phase_duration().saturating_sub(phase_duration),
+        );
+        if extension == 0 {
+            return;
+        }
+
+        self.soft_close_total_extension()
+            .set(total_extension + extension);
+
+        // the TWAP window moves with the end of the phase
+        self.refresh_twap_window_snapshot();
+
+        self.emit_extend_user_phase_event(phase_duration + extension);
+    }
+
+    #[view(getSoftCloseConfig)]
+    #[storage_mapper("softCloseConfig")]
+    fn soft_close_config(&self) -> SingleValueMapper<SoftCloseConfig>;
+}
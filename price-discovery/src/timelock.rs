@@ -0,0 +1,94 @@
+use crate::{phase::Phase, Timestamp};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub type ChangeId = u64;
+
+pub const NO_TIMELOCK: Timestamp = 0;
+
+/// `kind` is checked again on execution, in case the phase pipeline changed in the meantime
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub enum ParameterChange<M: ManagedTypeApi> {
+    PhaseDuration {
+        phase_index: usize,
+        kind: Phase,
+        duration: Timestamp,
+    },
+    MinLaunchedTokens(BigUint<M>),
+    StartTime(Timestamp),
+    AddCooldownPhase {
+        phase_index: usize,
+        duration: Timestamp,
+    },
+    RemoveCooldownPhase(usize),
+    CloseUserPhase,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct PendingChange<M: ManagedTypeApi> {
+    pub id: ChangeId,
+    pub change: ParameterChange<M>,
+    pub executable_at: Timestamp,
+}
+
+#[multiversx_sc::module]
+pub trait TimelockModule:
+    crate::common_storage::CommonStorageModule + crate::events::EventsModule + crate::phase::PhaseModule
+{
+    #[view(getPendingChanges)]
+    fn get_pending_changes(&self) -> MultiValueEncoded<PendingChange<Self::Api>> {
+        let mut pending_changes = MultiValueEncoded::new();
+        for id in self.pending_change_ids().iter() {
+            pending_changes.push(self.pending_change(id).get());
+        }
+
+        pending_changes
+    }
+
+    /// Returns `false` if there is no timelock, in which case the change has to be applied right away
+    fn try_queue_change(&self, change: ParameterChange<Self::Api>) -> bool {
+        let delay = self.timelock_delay().get();
+        if delay == NO_TIMELOCK {
+            return false;
+        }
+
+        let id = self.last_change_id().update(|last_id| {
+            *last_id += 1;
+            *last_id
+        });
+        let pending_change = PendingChange {
+            id,
+            change,
+            executable_at: self.get_current_time() + delay,
+        };
+        let _ = self.pending_change_ids().insert(id);
+        self.emit_queue_change_event(&pending_change);
+        self.pending_change(id).set(pending_change);
+
+        true
+    }
+
+    /// Removes the change from the queue
+    fn take_pending_change(&self, id: ChangeId) -> PendingChange<Self::Api> {
+        let removed = self.pending_change_ids().swap_remove(&id);
+        require!(removed, "Change not found");
+
+        self.pending_change(id).take()
+    }
+
+    #[view(getTimelockDelay)]
+    #[storage_mapper("timelockDelay")]
+    fn timelock_delay(&self) -> SingleValueMapper<Timestamp>;
+
+    #[storage_mapper("lastChangeId")]
+    fn last_change_id(&self) -> SingleValueMapper<ChangeId>;
+
+    #[storage_mapper("pendingChangeIds")]
+    fn pending_change_ids(&self) -> UnorderedSetMapper<ChangeId>;
+
+    #[storage_mapper("pendingChange")]
+    fn pending_change(&self, id: ChangeId) -> SingleValueMapper<PendingChange<Self::Api>>;
+}
@@ -0,0 +1,208 @@
+use crate::{price_history::CumulativeBalanceSnapshot, Timestamp};
+
+multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+pub const TWAP_SETTLEMENT_DISABLED: Timestamp = 0;
+
+/// `settled_accepted_tokens` is the amount paid to the owner,
+/// and `launched_tokens_sold` the amount split between the users
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct TwapSettlement<M: ManagedTypeApi> {
+    pub price: BigUint<M>,
+    pub settled_accepted_tokens: BigUint<M>,
+    pub launched_tokens_sold: BigUint<M>,
+}
+
+#[multiversx_sc::module]
+pub trait TwapSettlementModule:
+    crate::common_storage::CommonStorageModule
+    + crate::events::EventsModule
+    + crate::phase::PhaseModule
+    + crate::price_history::PriceHistoryModule
+{
+    /// The settlement price for the current balances
+    #[view(getTwapSettlementPrice)]
+    fn get_twap_settlement_price(&self) -> BigUint {
+        require!(self.is_twap_settlement(), "TWAP settlement not enabled");
+
+        let launched_token_balance = self.launched_token_balance().get();
+        require!(launched_token_balance > 0, "No launched tokens available");
+
+        self.compute_twap_settlement().price
+    }
+
+    fn is_twap_settlement(&self) -> bool {
+        self.twap_settlement_window().get() != TWAP_SETTLEMENT_DISABLED
+    }
+
+    fn require_valid_twap_settlement_window(&self, window: Timestamp) {
+        require!(
+            window <= self.get_max_phase_duration(),
+            "Invalid TWAP settlement window"
+        );
+    }
+
+    /// The window ends with the user deposit phase, and may not start before the phase
+    fn get_twap_window_start(&self) -> Timestamp {
+        let start_time = self.get_user_deposit_withdraw_start();
+        let window = self.twap_settlement_window().get();
+        let window_start = self.get_user_deposit_withdraw_end().saturating_sub(window);
+
+        core::cmp::max(start_time, window_start)
+    }
+
+    /// Called after every change of the accepted token balance
+    fn update_twap_window_snapshot(&self) {
+        if !self.is_twap_settlement() {
+            return;
+        }
+
+        let snapshot_mapper = self.twap_window_snapshot();
+        if !snapshot_mapper.is_empty() {
+            return;
+        }
+
+        let current_time = self.get_current_time();
+        let window_start = self.get_twap_window_start();
+        if current_time < window_start {
+            return;
+        }
+
+        let cumulative_accepted_balance = self.get_cumulative_accepted_balance_at(window_start);
+        snapshot_mapper.set(CumulativeBalanceSnapshot {
+            timestamp: window_start,
+            cumulative_accepted_balance,
+        });
+    }
+
+    /// Called after every change of the phase pipeline, including the soft close extensions.
+    /// The snapshot is only taken again if the window start moved.
+    /// If the price history no longer covers the new start, the previous snapshot is kept,
+    /// and the window keeps its previous start
+    fn refresh_twap_window_snapshot(&self) {
+        let snapshot_mapper = self.twap_window_snapshot();
+        let window_start = self.get_twap_window_start();
+        if snapshot_mapper.is_empty() || snapshot_mapper.get().timestamp == window_start {
+            return;
+        }
+
+        if self.get_current_time() < window_start {
+            snapshot_mapper.clear();
+            return;
+        }
+
+        if let Some(cumulative_accepted_balance) =
+            self.try_get_cumulative_accepted_balance_at(window_start)
+        {
+            snapshot_mapper.set(CumulativeBalanceSnapshot {
+                timestamp: window_start,
+                cumulative_accepted_balance,
+            });
+        }
+    }
+
+    fn get_or_compute_twap_settlement(&self) -> TwapSettlement<Self::Api> {
+        let settlement_mapper = self.twap_settlement();
+        if !settlement_mapper.is_empty() {
+            return settlement_mapper.get();
+        }
+
+        let settlement = self.compute_twap_settlement();
+        settlement_mapper.set(&settlement);
+
+        settlement
+    }
+
+    /// Users buy at the TWAP instead of the final spot price.
+    /// If the final balance is above the average, users get all the launched tokens
+    /// and the excess deposits are refunded.
+    /// If it is below, users get fewer launched tokens and the rest go back to the owner.
+    fn compute_twap_settlement(&self) -> TwapSettlement<Self::Api> {
+        let accepted_token_balance = self.accepted_token_balance().get();
+        let launched_token_supply = self.launched_token_balance().get();
+        let price_precision = self.price_precision().get();
+
+        let average_accepted_balance = self.get_twap_window_accepted_balance();
+        if average_accepted_balance == 0 {
+            // nothing to average over, so the spot price is used
+            return TwapSettlement {
+                price: &accepted_token_balance * price_precision / &launched_token_supply,
+                settled_accepted_tokens: accepted_token_balance,
+                launched_tokens_sold: launched_token_supply,
+            };
+        }
+
+        let price = &average_accepted_balance * price_precision / &launched_token_supply;
+        if accepted_token_balance >= average_accepted_balance {
+            TwapSettlement {
+                price,
+                settled_accepted_tokens: average_accepted_balance,
+                launched_tokens_sold: launched_token_supply,
+            }
+        } else {
+            let launched_tokens_sold =
+                &launched_token_supply * &accepted_token_balance / &average_accepted_balance;
+            TwapSettlement {
+                price,
+                settled_accepted_tokens: accepted_token_balance,
+                launched_tokens_sold,
+            }
+        }
+    }
+
+    fn get_twap_window_accepted_balance(&self) -> BigUint {
+        // the snapshot keeps the previous window start if the history did not cover the new one
+        let snapshot_mapper = self.twap_window_snapshot();
+        let window_start = if snapshot_mapper.is_empty() {
+            self.get_twap_window_start()
+        } else {
+            snapshot_mapper.get().timestamp
+        };
+        let window_end = self.get_user_deposit_withdraw_end();
+        if window_end <= window_start {
+            return BigUint::zero();
+        }
+
+        let start_cumulative_balance = if snapshot_mapper.is_empty() {
+            self.get_cumulative_accepted_balance_at(window_start)
+        } else {
+            snapshot_mapper.get().cumulative_accepted_balance
+        };
+        let end_cumulative_balance = self.get_cumulative_accepted_balance_at(window_end);
+
+        (end_cumulative_balance - start_cumulative_balance) / (window_end - window_start)
+    }
+
+    /// The user pays their share of the settled accepted tokens, rounded up,
+    /// and gets the rest of the deposit back
+    fn compute_user_twap_allocation(
+        &self,
+        total_user_deposit: &BigUint,
+        settlement: &TwapSettlement<Self::Api>,
+    ) -> (BigUint, BigUint) {
+        if total_user_deposit == &0 {
+            return (BigUint::zero(), BigUint::zero());
+        }
+
+        let accepted_token_balance = self.accepted_token_balance().get();
+        let bought_tokens =
+            &settlement.launched_tokens_sold * total_user_deposit / &accepted_token_balance;
+        let cost = (&settlement.settled_accepted_tokens * total_user_deposit
+            + &accepted_token_balance
+            - 1u32)
+            / &accepted_token_balance;
+        let refund_amount = total_user_deposit - &cost;
+
+        (bought_tokens, refund_amount)
+    }
+
+    /// The cumulative accepted balance at the start of the settlement window,
+    /// kept so the settlement does not depend on the bounded price history
+    #[storage_mapper("twapWindowSnapshot")]
+    fn twap_window_snapshot(&self) -> SingleValueMapper<CumulativeBalanceSnapshot<Self::Api>>;
+
+    #[storage_mapper("twapSettlement")]
+    fn twap_settlement(&self) -> SingleValueMapper<TwapSettlement<Self::Api>>;
+}
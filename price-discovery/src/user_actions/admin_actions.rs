@@ -1,5 +1,10 @@
 use crate::{
-    phase::{Phase, MAX_PHASE_DURATION},
+    board::{ActionId, BoardAction},
+    phase::{Phase, PhaseDefinition, IDLE_PHASE_INDEX, MAX_COOLDOWN_PHASES},
+    roles::Role,
+    sale_mode::SaleMode,
+    soft_close::SoftCloseConfig,
+    timelock::{ChangeId, ParameterChange, PendingChange},
     Timestamp,
 };
 
@@ -8,6 +13,10 @@ multiversx_sc::imports!();
 pub static INVALID_CURRENT_PHASE_ERR_MSG: &[u8] = b"Invalid current phase";
 pub static INVALID_TIMESTAMP_CHANGE_ERR_MSG: &[u8] = b"Invalid timestamp change";
 pub static INVALID_TIMESTAMP_DURATION_ERR_MGS: &[u8] = b"Invalid timestamp";
+pub static INVALID_PHASE_INDEX_ERR_MSG: &[u8] = b"Invalid phase index";
+pub static INVALID_PHASE_PIPELINE_ERR_MSG: &[u8] = b"Invalid phase pipeline";
+pub static START_TIME_IN_PAST_ERR_MSG: &[u8] = b"Start time cannot be in the past";
+pub static TOO_MANY_COOLDOWN_PHASES_ERR_MSG: &[u8] = b"Too many cooldown phases";
 
 #[multiversx_sc::module]
 pub trait AdminActionsModule:
@@ -15,89 +24,275 @@ pub trait AdminActionsModule:
     + crate::common_storage::CommonStorageModule
     + crate::events::EventsModule
     + crate::phase::PhaseModule
+    + crate::dutch_auction::DutchAuctionModule
+    + crate::bonding_curve::BondingCurveModule
+    + crate::limit_orders::LimitOrdersModule
+    + crate::price_history::PriceHistoryModule
+    + crate::twap_settlement::TwapSettlementModule
+    + crate::soft_close::SoftCloseModule
+    + crate::roles::RolesModule
+    + crate::timelock::TimelockModule
+    + crate::board::BoardModule
+    + super::redeem::RedeemModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
-    #[endpoint(setUserDepositWithdrawTime)]
-    fn set_user_deposit_withdraw_time(&self, user_deposit_withdraw_time: Timestamp) {
-        self.require_caller_admin();
-        self.require_valid_timestamp(user_deposit_withdraw_time);
+    /// Moves the whole schedule, as the phase durations are relative to the start time
+    #[endpoint(setStartTime)]
+    fn set_start_time(&self, start_time: Timestamp) {
+        self.require_caller_has_role(Role::TimingManager);
 
-        let current_phase = self.get_current_phase();
-        if current_phase == Phase::UserDepositWithdraw {
-            let current_time = self.user_deposit_withdraw_time().get();
-            require!(
-                current_time <= user_deposit_withdraw_time,
-                "May only extend phase at this point, not reduce"
-            );
-        }
+        self.change_start_time(start_time);
+    }
 
-        self.set_timestamp(
-            user_deposit_withdraw_time,
-            &Phase::UserDepositWithdraw,
-            &self.user_deposit_withdraw_time(),
-        );
+    #[endpoint(setUserDepositWithdrawTime)]
+    fn set_user_deposit_withdraw_time(&self, user_deposit_withdraw_time: Timestamp) {
+        self.require_caller_has_role(Role::TimingManager);
 
-        self.set_user_deposit_withdraw_time_event(user_deposit_withdraw_time);
+        self.change_user_deposit_withdraw_time(user_deposit_withdraw_time);
     }
 
     #[endpoint(setOwnerDepositWithdrawTime)]
     fn set_owner_deposit_withdraw_time(&self, owner_deposit_withdraw_time: Timestamp) {
-        self.require_caller_admin();
-        self.require_valid_timestamp(owner_deposit_withdraw_time);
+        self.require_caller_has_role(Role::TimingManager);
 
-        self.set_timestamp(
-            owner_deposit_withdraw_time,
-            &Phase::OwnerDepositWithdraw,
-            &self.owner_deposit_withdraw_time(),
-        );
-
-        self.set_owner_deposit_withdraw_time_event(owner_deposit_withdraw_time);
+        self.change_owner_deposit_withdraw_time(owner_deposit_withdraw_time);
     }
 
     #[endpoint(setOwnerRedeemTime)]
     fn set_owner_redeem_time(&self, owner_redeem_time: Timestamp) {
+        self.require_caller_has_role(Role::TimingManager);
+
+        self.change_owner_redeem_time(owner_redeem_time);
+    }
+
+    /// Changes the duration of any phase in the pipeline, including the cooldown phases.
+    /// `phase_index` starts from 1, as returned by `getPhasePipeline`
+    #[endpoint(setPhaseDuration)]
+    fn set_phase_duration(&self, phase_index: usize, duration: Timestamp) {
+        self.require_caller_has_role(Role::TimingManager);
+
+        self.change_phase_duration(phase_index, duration);
+    }
+
+    /// Inserts a cooldown phase at `phase_index`, before the phase currently found there.
+    /// Pass the pipeline length + 1 to add it after all the other phases
+    #[endpoint(addCooldownPhase)]
+    fn add_cooldown_phase(&self, phase_index: usize, duration: Timestamp) {
+        self.require_caller_has_role(Role::TimingManager);
+
+        self.change_add_cooldown_phase(phase_index, duration);
+    }
+
+    #[endpoint(removeCooldownPhase)]
+    fn remove_cooldown_phase(&self, phase_index: usize) {
+        self.require_caller_has_role(Role::TimingManager);
+
+        self.change_remove_cooldown_phase(phase_index);
+    }
+
+    /// Pass `0` to settle at the final price instead of the TWAP.
+    /// Only available for the `Standard` sale mode, before the start
+    #[endpoint(setTwapSettlementWindow)]
+    fn set_twap_settlement_window(&self, window: Timestamp) {
         self.require_caller_admin();
-        self.require_valid_long_timestamp(owner_redeem_time);
 
-        self.set_timestamp(
-            owner_redeem_time,
-            &Phase::OwnerRedeem,
-            &self.owner_redeem_time(),
-        );
+        self.change_twap_settlement_window(window);
+    }
 
-        self.set_owner_redeem_time_event(owner_redeem_time);
+    /// A deposit in the last `trigger_window` of the user deposit phase extends it by `extension`,
+    /// up to `max_total_extension`. Pass `0` for all the values to disable it.
+    /// Not available for the `DutchAuction` sale mode, and only before the start
+    #[endpoint(setSoftCloseConfig)]
+    fn set_soft_close_config(
+        &self,
+        trigger_window: Timestamp,
+        extension: Timestamp,
+        max_total_extension: Timestamp,
+    ) {
+        self.require_caller_has_role(Role::TimingManager);
+
+        self.change_soft_close_config(SoftCloseConfig {
+            trigger_window,
+            extension,
+            max_total_extension,
+        });
+    }
+
+    /// Pass `0` to disable closing the user deposit phase early.
+    /// May not be changed after the user deposit phase ended
+    #[endpoint(setTargetRaise)]
+    fn set_target_raise(&self, target_raise: BigUint) {
+        self.require_caller_admin();
+
+        self.change_target_raise(target_raise);
+    }
+
+    /// Ends the user deposit phase at the current time, once the target raise is reached.
+    /// This is the only way of reducing the user deposit phase after it started.
+    /// With a timelock, the phase ends when the change is executed
+    #[endpoint(closeUserPhase)]
+    fn close_user_phase(&self) {
+        self.require_caller_has_role(Role::TimingManager);
+
+        self.change_close_user_phase();
     }
 
     #[endpoint(setMinLaunchedTokens)]
     fn set_min_launched_tokens(&self, min_launched_tokens: BigUint) {
         self.require_caller_admin();
-        require!(min_launched_tokens > 0, "Invalid min launched tokens");
 
-        let phase = self.get_current_phase();
-        self.require_before_redeem(&phase);
-
-        self.min_launched_tokens().set(min_launched_tokens);
+        self.change_min_launched_tokens(min_launched_tokens);
     }
 
-    /// Pass `0` for `limit` if there is no limit
-    #[endpoint(setUserLimit)]
-    fn set_user_limit(&self, user: ManagedAddress, limit: BigUint) {
+    /// Schedule and min launched tokens changes are queued for `delay`, if it is not `0`.
+    /// The delay may only be increased
+    #[endpoint(setTimelockDelay)]
+    fn set_timelock_delay(&self, delay: Timestamp) {
         self.require_caller_admin();
 
-        let user_id = self.user_id_mapper().get_id_non_zero(&user);
-        let user_current_deposit = self.total_deposit_by_user(user_id).get();
-        if user_current_deposit == 0 || limit == 0 {
-            self.set_user_deposit_limit(&user, user_id, &limit);
+        self.change_timelock_delay(delay);
+    }
 
-            return;
+    /// The change is validated again on execution, against the current state
+    #[endpoint(executeChange)]
+    fn execute_change(&self, id: ChangeId) {
+        let pending_change = self.take_pending_change(id);
+        self.require_caller_may_change(&pending_change.change);
+
+        self.apply_pending_change(pending_change);
+    }
+
+    #[endpoint(cancelChange)]
+    fn cancel_change(&self, id: ChangeId) {
+        let pending_change = self.take_pending_change(id);
+        self.require_caller_may_change(&pending_change.change);
+
+        self.emit_cancel_change_event(id);
+    }
+
+    /// Performs the action once enough board members signed it
+    #[endpoint(performAction)]
+    fn perform_action(&self, action_id: ActionId) {
+        let _ = self.require_caller_board_member();
+        self.require_pending_action(action_id);
+        require!(
+            self.action_signers(action_id).len() >= self.quorum().get(),
+            "Quorum not reached"
+        );
+
+        let action = self.action_data().get(action_id);
+        self.action_data().set(action_id, &BoardAction::Nothing);
+        self.action_signers(action_id).clear();
+
+        match action {
+            BoardAction::Nothing => {}
+            BoardAction::SetMinLaunchedTokens(min_launched_tokens) => {
+                self.change_min_launched_tokens(min_launched_tokens)
+            }
+            BoardAction::RefundUsers(users) => self.refund_user_list(&users),
+            BoardAction::SetUserLimit(user, limit) => self.change_user_limit(user, limit),
+            BoardAction::SetStartTime(start_time) => self.change_start_time(start_time),
+            BoardAction::SetUserDepositWithdrawTime(user_deposit_withdraw_time) => {
+                self.change_user_deposit_withdraw_time(user_deposit_withdraw_time)
+            }
+            BoardAction::SetOwnerDepositWithdrawTime(owner_deposit_withdraw_time) => {
+                self.change_owner_deposit_withdraw_time(owner_deposit_withdraw_time)
+            }
+            BoardAction::SetOwnerRedeemTime(owner_redeem_time) => {
+                self.change_owner_redeem_time(owner_redeem_time)
+            }
+            BoardAction::SetPhaseDuration(phase_index, duration) => {
+                self.change_phase_duration(phase_index, duration)
+            }
+            BoardAction::ExecuteChange(id) => {
+                let pending_change = self.take_pending_change(id);
+                self.apply_pending_change(pending_change);
+            }
+            BoardAction::CancelChange(id) => {
+                let _ = self.take_pending_change(id);
+                self.emit_cancel_change_event(id);
+            }
+            BoardAction::AddUsersToWhitelist(entries) => {
+                self.require_before_redeem();
+                for entry in entries.iter() {
+                    self.whitelist_user(entry.address, entry.limit);
+                }
+            }
+            BoardAction::GrantRole(role, address) => self.add_role_holder(role, address),
+            BoardAction::RevokeRole(role, address) => self.remove_role_holder(role, address),
+            BoardAction::Pause => self.set_paused(true),
+            BoardAction::Unpause => self.set_paused(false),
+            BoardAction::SetTargetRaise(target_raise) => self.change_target_raise(target_raise),
+            BoardAction::SetTwapSettlementWindow(window) => {
+                self.change_twap_settlement_window(window)
+            }
+            BoardAction::AddCooldownPhase(phase_index, duration) => {
+                self.change_add_cooldown_phase(phase_index, duration)
+            }
+            BoardAction::RemoveCooldownPhase(phase_index) => {
+                self.change_remove_cooldown_phase(phase_index)
+            }
+            BoardAction::CloseUserPhase => self.change_close_user_phase(),
+            BoardAction::SetSoftCloseConfig(config) => self.change_soft_close_config(config),
+            BoardAction::SetTimelockDelay(delay) => self.change_timelock_delay(delay),
+            BoardAction::ProposeAdmin(new_admin) => self.set_pending_admin(new_admin),
+            BoardAction::ProposeBeneficiary(new_beneficiary) => {
+                self.set_pending_beneficiary(new_beneficiary)
+            }
         }
 
+        self.emit_perform_action_event(action_id);
+    }
+
+    fn apply_pending_change(&self, pending_change: PendingChange<Self::Api>) {
         require!(
-            user_current_deposit <= limit,
-            "May not set user limit below current deposit value"
+            self.get_current_time() >= pending_change.executable_at,
+            "Timelock not expired"
         );
 
-        self.set_user_deposit_limit(&user, user_id, &limit);
+        let id = pending_change.id;
+        match pending_change.change {
+            ParameterChange::PhaseDuration {
+                phase_index,
+                kind,
+                duration,
+            } => {
+                require!(
+                    phase_index > IDLE_PHASE_INDEX
+                        && phase_index <= self.phase_pipeline().len()
+                        && self.phase_pipeline().get(phase_index).kind == kind,
+                    "Phase pipeline changed"
+                );
+
+                self.update_phase_duration(phase_index, duration);
+                self.emit_set_phase_duration_event(phase_index, duration);
+            }
+            ParameterChange::MinLaunchedTokens(min_launched_tokens) => {
+                self.require_before_redeem();
+
+                self.emit_set_min_launched_tokens_event(&min_launched_tokens);
+                self.min_launched_tokens().set(min_launched_tokens);
+            }
+            ParameterChange::StartTime(start_time) => self.update_start_time(start_time),
+            ParameterChange::AddCooldownPhase {
+                phase_index,
+                duration,
+            } => self.insert_cooldown_phase(phase_index, duration),
+            ParameterChange::RemoveCooldownPhase(phase_index) => {
+                self.delete_cooldown_phase(phase_index)
+            }
+            ParameterChange::CloseUserPhase => self.end_user_phase(),
+        }
+
+        self.emit_execute_change_event(id);
+    }
+
+    /// Pass `0` for `limit` if there is no limit
+    #[endpoint(setUserLimit)]
+    fn set_user_limit(&self, user: ManagedAddress, limit: BigUint) {
+        self.require_caller_has_role(Role::WhitelistManager);
+
+        self.change_user_limit(user, limit);
     }
 
     /// `whitelist` arguments are pairs of (address, max_total_deposit). Pass `0` for `max_total_deposit` if there is no limit
@@ -106,39 +301,335 @@ pub trait AdminActionsModule:
         &self,
         whitelist: MultiValueEncoded<MultiValue2<ManagedAddress, BigUint>>,
     ) {
-        self.require_caller_admin();
+        self.require_caller_has_role(Role::WhitelistManager);
 
-        let phase = self.get_current_phase();
-        self.require_before_redeem(&phase);
-
-        let id_mapper = self.user_id_mapper();
-        let whitelist_mapper = self.user_whitelist();
+        self.require_before_redeem();
         for pair in whitelist {
             let (user, limit) = pair.into_tuple();
-            let user_id = id_mapper.insert_new(&user);
-            whitelist_mapper.add(&user_id);
-
-            if limit > 0 {
-                self.user_deposit_limit(user_id).set(limit);
-            }
+            self.whitelist_user(user, limit);
         }
     }
 
     #[endpoint(refundUsers)]
     fn refund_users(&self, users: MultiValueEncoded<ManagedAddress>) {
-        self.require_caller_admin();
+        self.require_caller_has_role(Role::RefundOperator);
 
-        let phase = self.get_current_phase();
-        self.require_before_redeem(&phase);
+        self.refund_user_list(&users.to_vec());
+    }
+
+    fn refund_user_list(&self, users: &ManagedVec<ManagedAddress>) {
+        self.require_before_redeem();
 
         let id_mapper = self.user_id_mapper();
         let whitelist_mapper = self.user_whitelist();
         let owner_address = self.blockchain().get_owner_address();
-        for user in users {
+        for user in users.iter() {
             self.refund_single_user(&owner_address, &user, &id_mapper, &whitelist_mapper);
         }
     }
 
+    fn change_start_time(&self, start_time: Timestamp) {
+        if self.try_queue_change(ParameterChange::StartTime(start_time)) {
+            return;
+        }
+
+        self.update_start_time(start_time);
+    }
+
+    fn update_start_time(&self, start_time: Timestamp) {
+        let phase = self.get_current_phase();
+        require!(phase == Phase::Idle, INVALID_CURRENT_PHASE_ERR_MSG);
+
+        let current_time = self.get_current_time();
+        require!(current_time < start_time, START_TIME_IN_PAST_ERR_MSG);
+
+        self.start_time().set(start_time);
+
+        self.emit_set_start_time_event(start_time);
+    }
+
+    fn change_add_cooldown_phase(&self, phase_index: usize, duration: Timestamp) {
+        self.require_valid_timestamp(duration);
+        if self.try_queue_change(ParameterChange::AddCooldownPhase {
+            phase_index,
+            duration,
+        }) {
+            return;
+        }
+
+        self.insert_cooldown_phase(phase_index, duration);
+    }
+
+    fn insert_cooldown_phase(&self, phase_index: usize, duration: Timestamp) {
+        require!(
+            phase_index > IDLE_PHASE_INDEX && phase_index <= self.phase_pipeline().len() + 1,
+            INVALID_PHASE_INDEX_ERR_MSG
+        );
+        require!(
+            self.get_current_phase_index() < phase_index,
+            INVALID_CURRENT_PHASE_ERR_MSG
+        );
+        require!(
+            self.get_nr_cooldown_phases(&self.get_phase_pipeline()) < MAX_COOLDOWN_PHASES,
+            TOO_MANY_COOLDOWN_PHASES_ERR_MSG
+        );
+
+        let mut pipeline = ManagedVec::new();
+        for (index, definition) in self.phase_pipeline().iter().enumerate() {
+            if index + 1 == phase_index {
+                pipeline.push(PhaseDefinition {
+                    kind: Phase::Cooldown,
+                    duration,
+                });
+            }
+
+            pipeline.push(definition);
+        }
+        if phase_index > self.phase_pipeline().len() {
+            pipeline.push(PhaseDefinition {
+                kind: Phase::Cooldown,
+                duration,
+            });
+        }
+        self.set_phase_pipeline(&pipeline);
+        self.refresh_twap_window_snapshot();
+
+        self.emit_add_cooldown_phase_event(phase_index, duration);
+    }
+
+    fn change_remove_cooldown_phase(&self, phase_index: usize) {
+        if self.try_queue_change(ParameterChange::RemoveCooldownPhase(phase_index)) {
+            return;
+        }
+
+        self.delete_cooldown_phase(phase_index);
+    }
+
+    fn delete_cooldown_phase(&self, phase_index: usize) {
+        require!(
+            phase_index > IDLE_PHASE_INDEX && phase_index <= self.phase_pipeline().len(),
+            INVALID_PHASE_INDEX_ERR_MSG
+        );
+        require!(
+            self.phase_pipeline().get(phase_index).kind == Phase::Cooldown,
+            "May only remove cooldown phases"
+        );
+        require!(
+            self.get_current_phase_index() < phase_index,
+            INVALID_CURRENT_PHASE_ERR_MSG
+        );
+
+        let mut pipeline = self.get_phase_pipeline();
+        pipeline.remove(phase_index - 1);
+        self.set_phase_pipeline(&pipeline);
+        self.refresh_twap_window_snapshot();
+
+        self.emit_remove_cooldown_phase_event(phase_index);
+    }
+
+    fn change_close_user_phase(&self) {
+        if self.try_queue_change(ParameterChange::CloseUserPhase) {
+            return;
+        }
+
+        self.end_user_phase();
+    }
+
+    fn end_user_phase(&self) {
+        let target_raise = self.target_raise().get();
+        require!(target_raise > 0, "No target raise set");
+        require!(
+            self.accepted_token_balance().get() >= target_raise,
+            "Target raise not reached"
+        );
+
+        let phase = self.get_current_phase();
+        require!(
+            phase == Phase::UserDepositWithdraw,
+            INVALID_CURRENT_PHASE_ERR_MSG
+        );
+        require!(
+            !self.is_dutch_auction(),
+            "May not change the auction duration after start"
+        );
+
+        let phase_index = self.get_phase_index(&Phase::UserDepositWithdraw);
+        let current_time = self.get_current_time();
+        let user_deposit_withdraw_time = current_time - self.get_phase_start_by_index(phase_index);
+        self.require_valid_timestamp(user_deposit_withdraw_time);
+
+        // the soft close extension is kept, unless the phase was shorter than the extension
+        let extension_mapper = self.soft_close_total_extension();
+        let extension = core::cmp::min(extension_mapper.get(), user_deposit_withdraw_time - 1);
+        extension_mapper.set(extension);
+
+        let mut pipeline_mapper = self.phase_pipeline();
+        let mut definition = pipeline_mapper.get(phase_index);
+        definition.duration = user_deposit_withdraw_time - extension;
+        pipeline_mapper.set(phase_index, &definition);
+        self.refresh_twap_window_snapshot();
+
+        self.emit_close_user_phase_event(user_deposit_withdraw_time);
+    }
+
+    fn change_user_deposit_withdraw_time(&self, user_deposit_withdraw_time: Timestamp) {
+        let phase_index = self.get_phase_index(&Phase::UserDepositWithdraw);
+        if self.try_queue_phase_duration_change(phase_index, user_deposit_withdraw_time) {
+            return;
+        }
+
+        self.update_phase_duration(phase_index, user_deposit_withdraw_time);
+
+        self.set_user_deposit_withdraw_time_event(user_deposit_withdraw_time);
+    }
+
+    fn change_owner_deposit_withdraw_time(&self, owner_deposit_withdraw_time: Timestamp) {
+        let phase_index = self.get_phase_index(&Phase::OwnerDepositWithdraw);
+        if self.try_queue_phase_duration_change(phase_index, owner_deposit_withdraw_time) {
+            return;
+        }
+
+        self.update_phase_duration(phase_index, owner_deposit_withdraw_time);
+
+        self.set_owner_deposit_withdraw_time_event(owner_deposit_withdraw_time);
+    }
+
+    fn change_owner_redeem_time(&self, owner_redeem_time: Timestamp) {
+        let phase_index = self.get_phase_index(&Phase::OwnerRedeem);
+        if self.try_queue_phase_duration_change(phase_index, owner_redeem_time) {
+            return;
+        }
+
+        self.update_phase_duration(phase_index, owner_redeem_time);
+
+        self.set_owner_redeem_time_event(owner_redeem_time);
+    }
+
+    fn change_phase_duration(&self, phase_index: usize, duration: Timestamp) {
+        require!(
+            phase_index > IDLE_PHASE_INDEX && phase_index <= self.phase_pipeline().len(),
+            INVALID_PHASE_INDEX_ERR_MSG
+        );
+        if self.try_queue_phase_duration_change(phase_index, duration) {
+            return;
+        }
+
+        self.update_phase_duration(phase_index, duration);
+
+        self.emit_set_phase_duration_event(phase_index, duration);
+    }
+
+    fn change_min_launched_tokens(&self, min_launched_tokens: BigUint) {
+        require!(min_launched_tokens > 0, "Invalid min launched tokens");
+
+        self.require_before_redeem();
+        if self.try_queue_change(ParameterChange::MinLaunchedTokens(
+            min_launched_tokens.clone(),
+        )) {
+            return;
+        }
+
+        self.emit_set_min_launched_tokens_event(&min_launched_tokens);
+        self.min_launched_tokens().set(min_launched_tokens);
+    }
+
+    fn change_twap_settlement_window(&self, window: Timestamp) {
+        self.require_valid_twap_settlement_window(window);
+        require!(
+            self.sale_mode().get() == SaleMode::Standard,
+            "TWAP settlement not allowed in this sale mode"
+        );
+
+        let phase = self.get_current_phase();
+        require!(phase == Phase::Idle, INVALID_CURRENT_PHASE_ERR_MSG);
+
+        self.twap_settlement_window().set(window);
+
+        self.emit_set_twap_settlement_window_event(window);
+    }
+
+    fn change_soft_close_config(&self, config: SoftCloseConfig) {
+        require!(
+            !self.is_dutch_auction(),
+            "Soft close not allowed in this sale mode"
+        );
+
+        let phase = self.get_current_phase();
+        require!(phase == Phase::Idle, INVALID_CURRENT_PHASE_ERR_MSG);
+
+        if config.trigger_window == 0 && config.extension == 0 && config.max_total_extension == 0 {
+            self.soft_close_config().clear();
+        } else {
+            self.require_valid_soft_close_config(&config);
+            self.soft_close_config().set(&config);
+        }
+
+        self.emit_set_soft_close_config_event(&config);
+    }
+
+    fn change_target_raise(&self, target_raise: BigUint) {
+        let user_phase_index = self.get_phase_index(&Phase::UserDepositWithdraw);
+        require!(
+            self.get_current_phase_index() <= user_phase_index,
+            INVALID_CURRENT_PHASE_ERR_MSG
+        );
+        if let SaleMode::BondingCurve(curve) = self.sale_mode().get() {
+            if let Some(capacity) = self.get_bonding_curve_capacity(&curve) {
+                require!(
+                    target_raise <= capacity,
+                    "Target raise exceeds the bonding curve capacity"
+                );
+            }
+        }
+
+        self.target_raise().set(&target_raise);
+
+        self.emit_set_target_raise_event(&target_raise);
+    }
+
+    fn change_timelock_delay(&self, delay: Timestamp) {
+        require!(
+            delay <= self.get_max_phase_duration(),
+            "Invalid timelock delay"
+        );
+        require!(
+            delay >= self.timelock_delay().get(),
+            "May only increase the timelock delay"
+        );
+
+        self.timelock_delay().set(delay);
+
+        self.emit_set_timelock_delay_event(delay);
+    }
+
+    fn whitelist_user(&self, user: ManagedAddress, limit: BigUint) {
+        let user_id = self.user_id_mapper().insert_new(&user);
+        self.user_whitelist().add(&user_id);
+        let _ = self.participants().insert(user_id);
+
+        self.emit_whitelist_user_event(&user, &limit);
+        if limit > 0 {
+            self.user_deposit_limit(user_id).set(limit);
+        }
+    }
+
+    fn change_user_limit(&self, user: ManagedAddress, limit: BigUint) {
+        let user_id = self.user_id_mapper().get_id_non_zero(&user);
+        let user_current_deposit = self.total_deposit_by_user(user_id).get();
+        if user_current_deposit == 0 || limit == 0 {
+            self.set_user_deposit_limit(&user, user_id, &limit);
+
+            return;
+        }
+
+        require!(
+            user_current_deposit <= limit,
+            "May not set user limit below current deposit value"
+        );
+
+        self.set_user_deposit_limit(&user, user_id, &limit);
+    }
+
     fn refund_single_user(
         &self,
         owner_address: &ManagedAddress,
@@ -151,18 +642,17 @@ pub trait AdminActionsModule:
         let user_id = id_mapper.get_id_non_zero(user_addr);
         whitelist_mapper.require_whitelisted(&user_id);
         whitelist_mapper.remove(&user_id);
+        let _ = self.participants().swap_remove(&user_id);
 
         let user_deposit = self.total_deposit_by_user(user_id).get();
         self.user_deposit_limit(user_id).clear();
         self.user_withdraw(user_addr, user_id, &user_deposit);
+        if self.is_dutch_auction() {
+            self.remove_auction_bids(user_id);
+        }
 
-        self.emit_refund_user_event(user_addr);
-    }
-
-    fn require_caller_admin(&self) {
-        let caller = self.blockchain().get_caller();
-        let admin = self.admin().get();
-        require!(caller == admin, "Only admin may call this function");
+        let implied_price = self.compute_implied_price(&self.accepted_token_balance().get());
+        self.emit_refund_user_event(user_addr, &user_deposit, &implied_price);
     }
 
     fn set_user_deposit_limit(
@@ -183,30 +673,123 @@ pub trait AdminActionsModule:
         self.set_user_limit_event(user_addr, limit);
     }
 
-    fn set_timestamp(
-        &self,
-        new_timestamp: Timestamp,
-        required_phase_limit: &Phase,
-        mapper: &SingleValueMapper<Timestamp>,
-    ) {
-        let phase_before = self.get_current_phase();
-        require!(
-            &phase_before <= required_phase_limit,
-            INVALID_CURRENT_PHASE_ERR_MSG
-        );
+    /// The permission needed to queue the change is also needed to execute or cancel it
+    fn require_caller_may_change(&self, change: &ParameterChange<Self::Api>) {
+        match change {
+            ParameterChange::PhaseDuration { .. }
+            | ParameterChange::StartTime(_)
+            | ParameterChange::AddCooldownPhase { .. }
+            | ParameterChange::RemoveCooldownPhase(_)
+            | ParameterChange::CloseUserPhase => self.require_caller_has_role(Role::TimingManager),
+            ParameterChange::MinLaunchedTokens(_) => self.require_caller_admin(),
+        }
+    }
 
-        mapper.set(new_timestamp);
+    /// Returns `false` if the change has to be applied right away
+    fn try_queue_phase_duration_change(&self, phase_index: usize, duration: Timestamp) -> bool {
+        let kind = self.phase_pipeline().get(phase_index).kind;
+        self.require_valid_phase_duration(&kind, duration);
 
-        let phase_after = self.get_current_phase();
+        self.try_queue_change(ParameterChange::PhaseDuration {
+            phase_index,
+            kind,
+            duration,
+        })
+    }
+
+    /// The current phase may not change as a result
+    fn update_phase_duration(&self, phase_index: usize, duration: Timestamp) {
+        let pipeline_mapper = self.phase_pipeline();
+        let mut definition = pipeline_mapper.get(phase_index);
+        self.require_valid_phase_duration(&definition.kind, duration);
+
+        let phase_before = self.get_current_phase_index();
+        require!(phase_before <= phase_index, INVALID_CURRENT_PHASE_ERR_MSG);
+
+        if phase_before == phase_index && definition.kind == Phase::UserDepositWithdraw {
+            require!(
+                !self.is_dutch_auction(),
+                "May not change the auction duration after start"
+            );
+            require!(
+                definition.duration <= duration,
+                "May only extend phase at this point, not reduce"
+            );
+        }
+
+        definition.duration = duration;
+        self.phase_pipeline().set(phase_index, &definition);
+
+        let phase_after = self.get_current_phase_index();
         require!(
             phase_before == phase_after,
             INVALID_TIMESTAMP_CHANGE_ERR_MSG
         );
+
+        // the TWAP window moves with the user deposit phase
+        self.refresh_twap_window_snapshot();
+    }
+
+    /// Each of the user deposit, owner deposit and owner redeem phases must appear exactly once,
+    /// with the owner redeem phase after both deposit phases
+    fn require_valid_phase_pipeline(&self, pipeline: &ManagedVec<PhaseDefinition>) {
+        let mut user_deposit_found = false;
+        let mut owner_deposit_found = false;
+        let mut owner_redeem_found = false;
+        for definition in pipeline.iter() {
+            match definition.kind {
+                Phase::UserDepositWithdraw => {
+                    require!(!user_deposit_found, INVALID_PHASE_PIPELINE_ERR_MSG);
+                    user_deposit_found = true;
+                }
+                Phase::OwnerDepositWithdraw => {
+                    require!(!owner_deposit_found, INVALID_PHASE_PIPELINE_ERR_MSG);
+                    owner_deposit_found = true;
+                }
+                Phase::OwnerRedeem => {
+                    require!(
+                        user_deposit_found && owner_deposit_found && !owner_redeem_found,
+                        INVALID_PHASE_PIPELINE_ERR_MSG
+                    );
+                    owner_redeem_found = true;
+                }
+                Phase::Cooldown => {}
+                Phase::Idle | Phase::UserRedeem => sc_panic!(INVALID_PHASE_PIPELINE_ERR_MSG),
+            }
+
+            self.require_valid_phase_duration(&definition.kind, definition.duration);
+        }
+
+        require!(owner_redeem_found, INVALID_PHASE_PIPELINE_ERR_MSG);
+        require!(
+            self.get_nr_cooldown_phases(pipeline) <= MAX_COOLDOWN_PHASES,
+            TOO_MANY_COOLDOWN_PHASES_ERR_MSG
+        );
+    }
+
+    fn get_nr_cooldown_phases(&self, pipeline: &ManagedVec<PhaseDefinition>) -> usize {
+        pipeline
+            .iter()
+            .filter(|definition| definition.kind == Phase::Cooldown)
+            .count()
+    }
+
+    fn require_valid_phase_duration(&self, kind: &Phase, duration: Timestamp) {
+        match kind {
+            Phase::OwnerRedeem => self.require_valid_long_timestamp(duration),
+            Phase::UserDepositWithdraw => {
+                // the soft close extension is added on top of the duration
+                self.require_valid_timestamp(duration);
+                let extension = self.soft_close_total_extension().get();
+                self.require_valid_timestamp(duration + extension);
+            }
+            _ => self.require_valid_timestamp(duration),
+        }
     }
 
     fn require_valid_timestamp(&self, timestamp: Timestamp) {
         require!(
-            timestamp > 0 && timestamp <= MAX_PHASE_DURATION,
+            timestamp > 0 && timestamp <= self.get_max_phase_duration(),
             INVALID_TIMESTAMP_DURATION_ERR_MGS
         );
     }
@@ -214,7 +797,4 @@ pub trait AdminActionsModule:
     fn require_valid_long_timestamp(&self, timestamp: Timestamp) {
         require!(timestamp > 0, INVALID_TIMESTAMP_DURATION_ERR_MGS);
     }
-
-    #[storage_mapper("admin")]
-    fn admin(&self) -> SingleValueMapper<ManagedAddress>;
 }
@@ -9,11 +9,14 @@ pub trait OwnerDepositWithdrawModule:
     crate::common_storage::CommonStorageModule
     + crate::events::EventsModule
     + crate::phase::PhaseModule
+    + crate::bonding_curve::BondingCurveModule
+    + crate::price_history::PriceHistoryModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     #[only_owner]
     #[payable("*")]
     #[endpoint(ownerDeposit)]
+    /// Not blocked while paused, so a pauser may not run out the owner phase
     fn owner_deposit(&self) {
         let min_launched_tokens = self.min_launched_tokens().get();
         require!(min_launched_tokens > 0, "Min launched tokens not set yet");
@@ -28,14 +31,17 @@ pub trait OwnerDepositWithdrawModule:
         let current_total_launched_tokens_amount = self.launched_token_balance().get();
         let new_total = &current_total_launched_tokens_amount + &payment_amount;
         require!(new_total >= min_launched_tokens, INVALID_AMOUNT_ERR_MSG);
+        self.require_enough_launched_tokens_for_curve(&new_total);
 
         self.launched_token_balance().set(new_total);
+        self.add_price_checkpoint();
 
         self.emit_owner_deposit_event(&payment_amount);
     }
 
     #[only_owner]
     #[endpoint(ownerWithdraw)]
+    /// Not blocked while paused, so a pauser may not run out the owner phase
     fn owner_withdraw(&self, withdraw_amount: BigUint) -> EsdtTokenPayment {
         let phase = self.get_current_phase();
         self.require_owner_deposit_withdraw_allowed(&phase);
@@ -47,13 +53,16 @@ pub trait OwnerDepositWithdrawModule:
         );
 
         let min_launched_tokens = self.min_launched_tokens().get();
+        let remaining_launched_tokens = &current_total_launched_tokens - &withdraw_amount;
         require!(
-            &current_total_launched_tokens - &withdraw_amount >= min_launched_tokens,
+            remaining_launched_tokens >= min_launched_tokens,
             INVALID_AMOUNT_ERR_MSG
         );
+        self.require_enough_launched_tokens_for_curve(&remaining_launched_tokens);
 
         self.launched_token_balance()
             .update(|balance| *balance -= &withdraw_amount);
+        self.add_price_checkpoint();
 
         let launched_token_id = self.launched_token_id().get();
         let caller = self.blockchain().get_caller();
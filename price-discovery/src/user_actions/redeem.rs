@@ -1,14 +1,30 @@
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
 
 pub type UserRedeemFlag = bool;
 pub const USER_REDEEMED: UserRedeemFlag = true;
 
+/// The launched tokens bought by the user, and the part of the deposit that is sent back
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct UserRedeemAmounts<M: ManagedTypeApi> {
+    pub bought_tokens: BigUint<M>,
+    pub refund_amount: BigUint<M>,
+}
+
 #[multiversx_sc::module]
 pub trait RedeemModule:
     super::user_deposit_withdraw::UserDepositWithdrawModule
     + crate::common_storage::CommonStorageModule
     + crate::events::EventsModule
     + crate::phase::PhaseModule
+    + crate::dutch_auction::DutchAuctionModule
+    + crate::bonding_curve::BondingCurveModule
+    + crate::limit_orders::LimitOrdersModule
+    + crate::price_history::PriceHistoryModule
+    + crate::twap_settlement::TwapSettlementModule
+    + crate::soft_close::SoftCloseModule
+    + crate::roles::RolesModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     /// After all phases have ended,
@@ -40,6 +56,52 @@ pub trait RedeemModule:
         redeemed_tokens
     }
 
+    /// The accepted tokens of `ownerRedeem` are sent to the beneficiary, once it calls `acceptBeneficiary`.
+    /// A new proposal replaces the previous one
+    #[only_owner]
+    #[endpoint(proposeBeneficiary)]
+    fn propose_beneficiary(&self, new_beneficiary: ManagedAddress) {
+        self.set_pending_beneficiary(new_beneficiary);
+    }
+
+    #[endpoint(acceptBeneficiary)]
+    fn accept_beneficiary(&self) {
+        self.require_owner_didnt_redeem();
+
+        let caller = self.blockchain().get_caller();
+        let pending_beneficiary_mapper = self.pending_beneficiary();
+        require!(
+            !pending_beneficiary_mapper.is_empty() && pending_beneficiary_mapper.get() == caller,
+            "Only the proposed beneficiary may accept"
+        );
+
+        pending_beneficiary_mapper.clear();
+        self.owner_redeem_beneficiary().set(&caller);
+
+        self.emit_accept_beneficiary_event(&caller);
+    }
+
+    /// The owner, if no beneficiary was accepted
+    #[view(getOwnerRedeemBeneficiary)]
+    fn get_owner_redeem_beneficiary(&self) -> ManagedAddress {
+        let beneficiary_mapper = self.owner_redeem_beneficiary();
+        if beneficiary_mapper.is_empty() {
+            self.blockchain().get_owner_address()
+        } else {
+            beneficiary_mapper.get()
+        }
+    }
+
+    #[view(getPendingBeneficiary)]
+    fn get_pending_beneficiary(&self) -> OptionalValue<ManagedAddress> {
+        let pending_beneficiary_mapper = self.pending_beneficiary();
+        if pending_beneficiary_mapper.is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(pending_beneficiary_mapper.get())
+        }
+    }
+
     /// Only to be used in the cases where the owner somehow missed the long owner redeem phase
     #[only_owner]
     #[endpoint(withdrawLaunchpadTokens)]
@@ -55,6 +117,8 @@ pub trait RedeemModule:
             .direct_esdt(&owner, &launched_token_id, 0, &launched_tokens_supply);
 
         self.owner_redeemed().set(USER_REDEEMED);
+
+        self.emit_withdraw_launchpad_tokens_event(&launched_token_id, &launched_tokens_supply);
     }
 
     fn owner_redeem(&self, owner: &ManagedAddress) -> EgldOrEsdtTokenPayment {
@@ -67,12 +131,54 @@ pub trait RedeemModule:
         );
 
         let accepted_token_id = self.accepted_token_id().get();
-        let accepted_token_balance = self.accepted_token_balance().get();
+        let (accepted_token_balance, unsold_launched_tokens) = if self.is_dutch_auction() {
+            let clearing = self.get_or_compute_auction_clearing();
+            let owner_proceeds = self.compute_auction_owner_proceeds(&clearing);
+            (
+                owner_proceeds,
+                &launched_token_supply - &clearing.tokens_sold,
+            )
+        } else if self.is_bonding_curve() {
+            let tokens_sold = self.bonding_curve_tokens_sold().get();
+            (
+                self.accepted_token_balance().get(),
+                &launched_token_supply - &tokens_sold,
+            )
+        } else if self.is_twap_settlement() {
+            let settlement = self.get_or_compute_twap_settlement();
+            (
+                settlement.settled_accepted_tokens,
+                &launched_token_supply - &settlement.launched_tokens_sold,
+            )
+        } else if self.has_limit_orders() {
+            let clearing = self.get_or_compute_limit_order_clearing();
+            let unsold_launched_tokens = if clearing.included_deposits == 0 {
+                launched_token_supply
+            } else {
+                BigUint::zero()
+            };
+            (clearing.included_deposits, unsold_launched_tokens)
+        } else {
+            (self.accepted_token_balance().get(), BigUint::zero())
+        };
+
+        let launched_token_id = self.launched_token_id().get();
+        self.send().direct_non_zero_esdt_payment(
+            owner,
+            &EsdtTokenPayment::new(launched_token_id, 0, unsold_launched_tokens.clone()),
+        );
+        let beneficiary = self.get_owner_redeem_beneficiary();
         self.send()
-            .direct(owner, &accepted_token_id, 0, &accepted_token_balance);
+            .direct(&beneficiary, &accepted_token_id, 0, &accepted_token_balance);
 
         self.owner_redeemed().set(USER_REDEEMED);
 
+        self.emit_finalize_sale_event(
+            &beneficiary,
+            &accepted_token_balance,
+            &unsold_launched_tokens,
+        );
+
         EgldOrEsdtTokenPayment::new(accepted_token_id, 0, accepted_token_balance)
     }
 
@@ -85,34 +191,109 @@ pub trait RedeemModule:
         );
 
         let total_user_deposit = self.total_deposit_by_user(user_id).take();
+        let redeem_amounts = self.compute_user_redeem_amounts(user_id, &total_user_deposit);
 
         let accepted_token_id = self.accepted_token_id().get();
-        let accepted_token_sc_balance = self.blockchain().get_sc_balance(&accepted_token_id, 0);
-        let launched_token_supply = self.launched_token_balance().get();
+        let output_tokens = if redeem_amounts.bought_tokens == 0 {
+            self.send()
+                .direct_non_zero(user, &accepted_token_id, 0, &redeem_amounts.refund_amount);
 
-        // only allow users to withdraw if the launched tokens were deposited AND the owner withdrew his accepted tokens
-        let output_tokens = if launched_token_supply != 0 && accepted_token_sc_balance == 0 {
-            let bought_tokens = self.compute_user_bought_tokens(&total_user_deposit);
-            self.send().direct_non_zero(
-                user,
-                &bought_tokens.token_identifier,
-                0,
-                &bought_tokens.amount,
-            );
-
-            bought_tokens
+            EgldOrEsdtTokenPayment::new(accepted_token_id, 0, redeem_amounts.refund_amount)
         } else {
+            if redeem_amounts.refund_amount > 0 {
+                self.send()
+                    .direct(user, &accepted_token_id, 0, &redeem_amounts.refund_amount);
+                self.emit_redeem_event(&accepted_token_id, &redeem_amounts.refund_amount);
+            }
+
+            let launched_token_id = EgldOrEsdtTokenIdentifier::esdt(self.launched_token_id().get());
             self.send()
-                .direct_non_zero(user, &accepted_token_id, 0, &total_user_deposit);
+                .direct(user, &launched_token_id, 0, &redeem_amounts.bought_tokens);
 
-            EgldOrEsdtTokenPayment::new(accepted_token_id, 0, total_user_deposit)
+            EgldOrEsdtTokenPayment::new(launched_token_id, 0, redeem_amounts.bought_tokens)
         };
 
         user_redeemed_mapper.set(USER_REDEEMED);
+        self.nr_redeemers()
+            .update(|nr_redeemers| *nr_redeemers += 1);
 
         output_tokens
     }
 
+    fn compute_user_redeem_amounts(
+        &self,
+        user_id: AddressId,
+        total_user_deposit: &BigUint,
+    ) -> UserRedeemAmounts<Self::Api> {
+        // the refunds for overpayment or excluded orders stay in the contract after the owner redeems,
+        // so the owner redeemed flag is checked instead of the accepted token balance
+        let launched_token_supply = self.launched_token_balance().get();
+        let owner_redeemed = self.owner_redeemed().get() == USER_REDEEMED;
+
+        // only allow users to withdraw if the launched tokens were deposited AND the owner withdrew his accepted tokens
+        if launched_token_supply == 0 || !owner_redeemed {
+            return UserRedeemAmounts {
+                bought_tokens: BigUint::zero(),
+                refund_amount: total_user_deposit.clone(),
+            };
+        }
+
+        if self.is_dutch_auction() {
+            // the part of the deposit that was not spent at the clearing price is sent back
+            let clearing = self.get_or_compute_auction_clearing();
+            let allocation = self.compute_user_auction_allocation(user_id, &clearing);
+            UserRedeemAmounts {
+                bought_tokens: allocation.bought_tokens,
+                refund_amount: total_user_deposit - &allocation.cost,
+            }
+        } else if self.is_bonding_curve() {
+            UserRedeemAmounts {
+                bought_tokens: self.user_bonding_curve_tokens(user_id).get(),
+                refund_amount: BigUint::zero(),
+            }
+        } else if self.is_twap_settlement() {
+            // the part of the deposit that was not spent at the TWAP is sent back
+            let settlement = self.get_or_compute_twap_settlement();
+            let (bought_tokens, refund_amount) =
+                self.compute_user_twap_allocation(total_user_deposit, &settlement);
+            UserRedeemAmounts {
+                bought_tokens,
+                refund_amount,
+            }
+        } else if !self.limit_order_clearing().is_empty() {
+            self.compute_limit_order_redeem_amounts(user_id, total_user_deposit)
+        } else {
+            UserRedeemAmounts {
+                bought_tokens: self.compute_user_bought_tokens(total_user_deposit).amount,
+                refund_amount: BigUint::zero(),
+            }
+        }
+    }
+
+    /// Users whose orders were excluded get their whole deposit back
+    fn compute_limit_order_redeem_amounts(
+        &self,
+        user_id: AddressId,
+        total_user_deposit: &BigUint,
+    ) -> UserRedeemAmounts<Self::Api> {
+        let clearing = self.limit_order_clearing().get();
+        if self.is_limit_order_excluded(user_id, &clearing) {
+            return UserRedeemAmounts {
+                bought_tokens: BigUint::zero(),
+                refund_amount: total_user_deposit.clone(),
+            };
+        }
+
+        let total_launched_token_supply = self.launched_token_balance().get();
+        let reward_amount =
+            total_launched_token_supply * total_user_deposit / clearing.included_deposits;
+
+        UserRedeemAmounts {
+            bought_tokens: reward_amount,
+            refund_amount: BigUint::zero(),
+        }
+    }
+
     fn compute_user_bought_tokens(&self, redeem_amount: &BigUint) -> EgldOrEsdtTokenPayment {
         let total_deposit_all_users = self.accepted_token_balance().get();
         let launched_token_id = EgldOrEsdtTokenIdentifier::esdt(self.launched_token_id().get());
@@ -122,6 +303,14 @@ pub trait RedeemModule:
         EgldOrEsdtTokenPayment::new(launched_token_id, 0, reward_amount)
     }
 
+    fn set_pending_beneficiary(&self, new_beneficiary: ManagedAddress) {
+        self.require_owner_didnt_redeem();
+
+        self.pending_beneficiary().set(&new_beneficiary);
+
+        self.emit_propose_beneficiary_event(&new_beneficiary);
+    }
+
     fn require_owner_didnt_redeem(&self) {
         require!(
             self.owner_redeemed().get() != USER_REDEEMED,
@@ -132,6 +321,16 @@ pub trait RedeemModule:
     #[storage_mapper("userRedeemed")]
     fn user_redeemed(&self, user_id: AddressId) -> SingleValueMapper<UserRedeemFlag>;
 
+    #[view(getNrRedeemers)]
+    #[storage_mapper("nrRedeemers")]
+    fn nr_redeemers(&self) -> SingleValueMapper<usize>;
+
     #[storage_mapper("ownerRedeemed")]
     fn owner_redeemed(&self) -> SingleValueMapper<UserRedeemFlag>;
+
+    #[storage_mapper("ownerRedeemBeneficiary")]
+    fn owner_redeem_beneficiary(&self) -> SingleValueMapper<ManagedAddress>;
+
+    #[storage_mapper("pendingBeneficiary")]
+    fn pending_beneficiary(&self) -> SingleValueMapper<ManagedAddress>;
 }
@@ -1,41 +1,73 @@
 multiversx_sc::imports!();
 
 pub static INVALID_PAYMENT_ERR_MSG: &[u8] = b"Invalid payment token";
+pub static USER_NOT_WHITELISTED_ERR_MSG: &[u8] = b"User not whitelisted";
+pub static DUTCH_AUCTION_WITHDRAW_ERR_MSG: &[u8] = b"Withdraw not allowed in Dutch auction";
 
 #[multiversx_sc::module]
 pub trait UserDepositWithdrawModule:
     crate::common_storage::CommonStorageModule
     + crate::events::EventsModule
     + crate::phase::PhaseModule
+    + crate::dutch_auction::DutchAuctionModule
+    + crate::bonding_curve::BondingCurveModule
+    + crate::limit_orders::LimitOrdersModule
+    + crate::price_history::PriceHistoryModule
+    + crate::twap_settlement::TwapSettlementModule
+    + crate::soft_close::SoftCloseModule
+    + crate::roles::RolesModule
     + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
 {
     /// Users can deposit accepted_tokens.
     /// Later, they can withdraw launched tokens
+    ///
+    /// `opt_max_price` is the max price the user accepts for their whole deposit,
+    /// and replaces the one given on previous deposits
     #[payable("*")]
     #[endpoint(userDeposit)]
-    fn user_deposit(&self) {
+    fn user_deposit(&self, opt_max_price: OptionalValue<BigUint>) {
+        self.require_not_paused();
+
         let phase = self.get_current_phase();
         self.require_user_deposit_withdraw_allowed(&phase);
 
         let caller = self.blockchain().get_caller();
         let user_id = self.require_user_whitelisted(&caller);
         let (payment_token, payment_amount) = self.call_value().egld_or_single_fungible_esdt();
-        self.add_user_deposit(user_id, &payment_token, &payment_amount);
+        let previous_deposit = self.total_deposit_by_user(user_id).get();
+        self.add_user_deposit(user_id, &payment_token, &payment_amount, &opt_max_price);
+
+        self.remove_limit_order_deposit(user_id, &previous_deposit);
+        self.set_user_max_price(user_id, opt_max_price);
+        self.add_limit_order_deposit(user_id, &(previous_deposit + &payment_amount));
+        if self.is_dutch_auction() {
+            self.add_auction_bid(user_id, &payment_amount);
+        } else if self.is_bonding_curve() {
+            self.buy_bonding_curve_tokens(user_id, &payment_amount);
+        }
+        self.apply_soft_close();
 
-        self.emit_user_deposit_event(&payment_amount);
+        let user_total_deposit = self.total_deposit_by_user(user_id).get();
+        let implied_price = self.compute_implied_price(&self.accepted_token_balance().get());
+        self.emit_user_deposit_event(&payment_amount, &user_total_deposit, &implied_price);
     }
 
     /// Withdraw the initially deposited tokens.
     #[endpoint(userWithdraw)]
     fn user_withdraw_endpoint(&self, withdraw_amount: BigUint) -> EgldOrEsdtTokenPayment {
+        self.require_not_paused();
+
         let phase = self.get_current_phase();
         self.require_user_deposit_withdraw_allowed(&phase);
+        require!(!self.is_dutch_auction(), DUTCH_AUCTION_WITHDRAW_ERR_MSG);
 
         let caller = self.blockchain().get_caller();
         let user_id = self.require_user_whitelisted(&caller);
 
         self.user_withdraw(&caller, user_id, &withdraw_amount);
-        self.emit_user_withdraw_event(&withdraw_amount);
+        let user_total_deposit = self.total_deposit_by_user(user_id).get();
+        let implied_price = self.compute_implied_price(&self.accepted_token_balance().get());
+        self.emit_user_withdraw_event(&withdraw_amount, &user_total_deposit, &implied_price);
 
         let refund_token_id = self.accepted_token_id().get();
         EgldOrEsdtTokenPayment::new(refund_token_id, 0, withdraw_amount)
@@ -74,7 +106,7 @@ pub trait UserDepositWithdrawModule:
         let user_id = self.user_id_mapper().get_id(user);
         require!(
             user_id != NULL_ID && self.user_whitelist().contains(&user_id),
-            "User not whitelisted"
+            USER_NOT_WHITELISTED_ERR_MSG
         );
 
         user_id
@@ -85,44 +117,107 @@ pub trait UserDepositWithdrawModule:
         user_id: AddressId,
         payment_token: &EgldOrEsdtTokenIdentifier,
         payment_amount: &BigUint,
+        opt_max_price: &OptionalValue<BigUint>,
     ) {
         let accepted_token_id = self.accepted_token_id().get();
         require!(payment_token == &accepted_token_id, INVALID_PAYMENT_ERR_MSG);
 
-        self.total_deposit_by_user(user_id).update(|total_deposit| {
-            *total_deposit += payment_amount;
-
-            let min_deposit = self.user_min_deposit().get();
-            require!(*total_deposit >= min_deposit, "Not enough tokens deposited");
-
-            let limit = self.user_deposit_limit(user_id).get();
-            if limit > 0 {
-                require!(*total_deposit <= limit, "Exceeded deposit limit");
-            }
-        });
+        let new_total_deposit = self
+            .check_user_deposit(user_id, payment_amount, opt_max_price)
+            .unwrap_or_else(|err_msg| sc_panic!(err_msg));
+        if &new_total_deposit == payment_amount {
+            self.nr_depositors()
+                .update(|nr_depositors| *nr_depositors += 1);
+        }
+        self.total_deposit_by_user(user_id).set(new_total_deposit);
 
         self.accepted_token_balance()
             .update(|balance| *balance += payment_amount);
+        self.add_price_checkpoint();
+        self.update_twap_window_snapshot();
     }
 
-    fn user_withdraw(&self, caller: &ManagedAddress, user_id: AddressId, amount: &BigUint) {
-        require!(amount > &0, "Invalid withdraw amount");
+    /// Returns the new total deposit of the user.
+    /// Runs all the checks of `userDeposit`, before the deposit is added to the balances
+    fn check_user_deposit(
+        &self,
+        user_id: AddressId,
+        payment_amount: &BigUint,
+        opt_max_price: &OptionalValue<BigUint>,
+    ) -> Result<BigUint, &'static [u8]> {
+        let total_deposit = self.total_deposit_by_user(user_id).get() + payment_amount;
 
-        self.total_deposit_by_user(user_id).update(|total_deposit| {
-            require!(&*total_deposit >= amount, "Error withdrawing");
+        let min_deposit = self.user_min_deposit().get();
+        if total_deposit < min_deposit {
+            return Err(b"Not enough tokens deposited");
+        }
 
-            *total_deposit -= amount;
+        let limit = self.user_deposit_limit(user_id).get();
+        if limit > 0 && total_deposit > limit {
+            return Err(b"Exceeded deposit limit");
+        }
 
-            if *total_deposit == 0 {
-                return;
-            }
+        self.check_user_max_price(opt_max_price)?;
+        if self.is_dutch_auction() {
+            self.check_auction_bid(payment_amount)?;
+        } else if self.is_bonding_curve() {
+            self.check_bonding_curve_deposit(payment_amount)?;
+        }
 
-            let min_deposit = self.user_min_deposit().get();
-            require!(*total_deposit >= min_deposit, "Withdrawing too many tokens");
-        });
+        Ok(total_deposit)
+    }
+
+    /// Returns the remaining deposit of the user
+    fn check_user_withdraw(
+        &self,
+        user_id: AddressId,
+        amount: &BigUint,
+    ) -> Result<BigUint, &'static [u8]> {
+        if amount == &0 {
+            return Err(b"Invalid withdraw amount");
+        }
 
+        let total_deposit = self.total_deposit_by_user(user_id).get();
+        if &total_deposit < amount {
+            return Err(b"Error withdrawing");
+        }
+
+        if self.is_bonding_curve() {
+            self.check_bonding_curve_withdraw(user_id, amount)?;
+        }
+
+        let remaining_deposit = total_deposit - amount;
+        if remaining_deposit == 0 {
+            return Ok(remaining_deposit);
+        }
+
+        let min_deposit = self.user_min_deposit().get();
+        if remaining_deposit < min_deposit {
+            return Err(b"Withdrawing too many tokens");
+        }
+
+        Ok(remaining_deposit)
+    }
+
+    fn user_withdraw(&self, caller: &ManagedAddress, user_id: AddressId, amount: &BigUint) {
+        let remaining_deposit = self
+            .check_user_withdraw(user_id, amount)
+            .unwrap_or_else(|err_msg| sc_panic!(err_msg));
+        if remaining_deposit == 0 {
+            self.nr_depositors()
+                .update(|nr_depositors| *nr_depositors -= 1);
+        }
+        self.total_deposit_by_user(user_id).set(&remaining_deposit);
+
+        if self.is_bonding_curve() {
+            self.sell_bonding_curve_tokens(user_id, amount);
+        }
+
+        self.remove_limit_order_deposit(user_id, amount);
         self.accepted_token_balance()
             .update(|balance| *balance -= amount);
+        self.add_price_checkpoint();
+        self.update_twap_window_snapshot();
 
         let refund_token_id = self.accepted_token_id().get();
         self.send().direct(caller, &refund_token_id, 0, amount);
@@ -143,4 +238,13 @@ pub trait UserDepositWithdrawModule:
 
     #[storage_mapper("totalDepositByUser")]
     fn total_deposit_by_user(&self, user_id: AddressId) -> SingleValueMapper<BigUint>;
+
+    /// All the whitelisted users, in no particular order
+    #[storage_mapper("participants")]
+    fn participants(&self) -> UnorderedSetMapper<AddressId>;
+
+    /// The number of users with a non-zero deposit
+    #[view(getNrDepositors)]
+    #[storage_mapper("nrDepositors")]
+    fn nr_depositors(&self) -> SingleValueMapper<usize>;
 }
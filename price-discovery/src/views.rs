@@ -1,9 +1,103 @@
+use crate::{
+    phase::{Phase, USER_DEPOSIT_WITHDRAW_NOT_ALLOWED_ERR_MSG},
+    roles::CONTRACT_PAUSED_ERR_MSG,
+    sale_mode::SaleMode,
+    user_actions::{
+        redeem::{UserRedeemAmounts, USER_REDEEMED},
+        user_deposit_withdraw::{DUTCH_AUCTION_WITHDRAW_ERR_MSG, USER_NOT_WHITELISTED_ERR_MSG},
+    },
+    Timestamp,
+};
+
 multiversx_sc::imports!();
+multiversx_sc::derive_imports!();
+
+/// The phase ends are absolute timestamps.
+/// `current_price` is `None` while no launched tokens are deposited.
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct ContractState<M: ManagedTypeApi> {
+    pub launched_token_id: TokenIdentifier<M>,
+    pub accepted_token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub price_precision: u64,
+    pub sale_mode: SaleMode<M>,
+    pub twap_settlement_window: Timestamp,
+    pub user_min_deposit: BigUint<M>,
+    pub min_launched_tokens: BigUint<M>,
+    pub target_raise: BigUint<M>,
+    pub launched_token_balance: BigUint<M>,
+    pub accepted_token_balance: BigUint<M>,
+    pub current_price: Option<BigUint<M>>,
+    pub current_phase: Phase,
+    pub start_time: Timestamp,
+    pub user_deposit_withdraw_end: Timestamp,
+    pub owner_deposit_withdraw_end: Timestamp,
+    pub owner_redeem_end: Timestamp,
+    pub min_launched_tokens_reached: bool,
+    pub owner_redeemed: bool,
+    pub owner: ManagedAddress<M>,
+    pub owner_redeem_beneficiary: ManagedAddress<M>,
+    pub admin: ManagedAddress<M>,
+    pub paused: bool,
+}
+
+/// `user_id` is `0` for unknown addresses.
+/// `projected_bought_tokens` is computed at the current price,
+/// while `redeem_amounts` is what a redeem would send right now
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub struct UserInfo<M: ManagedTypeApi> {
+    pub user_id: AddressId,
+    pub whitelisted: bool,
+    pub total_deposit: BigUint<M>,
+    pub deposit_limit: Option<BigUint<M>>,
+    pub redeemed: bool,
+    pub projected_bought_tokens: BigUint<M>,
+    pub redeem_amounts: UserRedeemAmounts<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode)]
+pub struct ActionSimulation<M: ManagedTypeApi> {
+    pub user_total_deposit: BigUint<M>,
+    pub total_deposit: BigUint<M>,
+    pub implied_price: Option<BigUint<M>>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode)]
+pub enum SimulationResult<M: ManagedTypeApi> {
+    Error(ManagedBuffer<M>),
+    Success(ActionSimulation<M>),
+}
 
 #[multiversx_sc::module]
-pub trait ViewsModule: crate::common_storage::CommonStorageModule {
+pub trait ViewsModule:
+    crate::common_storage::CommonStorageModule
+    + crate::events::EventsModule
+    + crate::phase::PhaseModule
+    + crate::dutch_auction::DutchAuctionModule
+    + crate::bonding_curve::BondingCurveModule
+    + crate::limit_orders::LimitOrdersModule
+    + crate::price_history::PriceHistoryModule
+    + crate::twap_settlement::TwapSettlementModule
+    + crate::soft_close::SoftCloseModule
+    + crate::roles::RolesModule
+    + crate::timelock::TimelockModule
+    + crate::board::BoardModule
+    + crate::user_actions::user_deposit_withdraw::UserDepositWithdrawModule
+    + crate::user_actions::redeem::RedeemModule
+    + crate::user_actions::admin_actions::AdminActionsModule
+    + multiversx_sc_modules::default_issue_callbacks::DefaultIssueCallbacksModule
+{
+    /// For bonding curve sales, this is the marginal price of the curve
     #[view(getCurrentPrice)]
     fn get_current_price(&self) -> BigUint {
+        if self.is_bonding_curve() {
+            let accepted_token_balance = self.accepted_token_balance().get();
+            return self.get_bonding_curve_price(&accepted_token_balance);
+        }
+
         let launched_token_balance = self.launched_token_balance().get();
         let accepted_token_balance = self.accepted_token_balance().get();
 
@@ -12,4 +106,221 @@ pub trait ViewsModule: crate::common_storage::CommonStorageModule {
         let price_precision = self.price_precision().get();
         accepted_token_balance * price_precision / launched_token_balance
     }
+
+    #[view(getContractState)]
+    fn get_contract_state(&self) -> ContractState<Self::Api> {
+        let launched_token_balance = self.launched_token_balance().get();
+        let min_launched_tokens = self.min_launched_tokens().get();
+        let current_price = if self.is_bonding_curve() || launched_token_balance > 0 {
+            Some(self.get_current_price())
+        } else {
+            None
+        };
+
+        ContractState {
+            launched_token_id: self.launched_token_id().get(),
+            accepted_token_id: self.accepted_token_id().get(),
+            price_precision: self.price_precision().get(),
+            sale_mode: self.sale_mode().get(),
+            twap_settlement_window: self.twap_settlement_window().get(),
+            user_min_deposit: self.user_min_deposit().get(),
+            min_launched_tokens_reached: launched_token_balance > 0
+                && launched_token_balance >= min_launched_tokens,
+            min_launched_tokens,
+            target_raise: self.target_raise().get(),
+            launched_token_balance,
+            accepted_token_balance: self.accepted_token_balance().get(),
+            current_price,
+            current_phase: self.get_current_phase(),
+            start_time: self.start_time().get(),
+            user_deposit_withdraw_end: self.get_user_deposit_withdraw_end(),
+            owner_deposit_withdraw_end: self.get_owner_deposit_withdraw_end(),
+            owner_redeem_end: self.get_owner_redeem_end(),
+            owner_redeemed: self.owner_redeemed().get() == USER_REDEEMED,
+            owner: self.blockchain().get_owner_address(),
+            owner_redeem_beneficiary: self.get_owner_redeem_beneficiary(),
+            admin: self.admin().get(),
+            paused: self.paused().get(),
+        }
+    }
+
+    #[view(getUserInfo)]
+    fn get_user_info(&self, user: ManagedAddress) -> UserInfo<Self::Api> {
+        let user_id = self.user_id_mapper().get_id(&user);
+        let total_deposit = self.total_deposit_by_user(user_id).get();
+        let deposit_limit = self.user_deposit_limit(user_id).get();
+        let redeemed = self.user_redeemed(user_id).get() == USER_REDEEMED;
+        let redeem_amounts = if redeemed {
+            UserRedeemAmounts {
+                bought_tokens: BigUint::zero(),
+                refund_amount: BigUint::zero(),
+            }
+        } else {
+            self.compute_user_redeem_amounts(user_id, &total_deposit)
+        };
+
+        // after the owner redeemed, the sale is settled
+        let projected_bought_tokens = if self.owner_redeemed().get() == USER_REDEEMED {
+            redeem_amounts.bought_tokens.clone()
+        } else {
+            self.get_projected_bought_tokens(user_id, &total_deposit)
+        };
+
+        UserInfo {
+            user_id,
+            whitelisted: self.user_whitelist().contains(&user_id),
+            projected_bought_tokens,
+            total_deposit,
+            deposit_limit: if deposit_limit > 0 {
+                Some(deposit_limit)
+            } else {
+                None
+            },
+            redeemed,
+            redeem_amounts,
+        }
+    }
+
+    /// The launched tokens the user would buy if the sale ended at the current price.
+    /// Follows the same sale mode dispatch as `compute_user_redeem_amounts`
+    fn get_projected_bought_tokens(&self, user_id: AddressId, total_deposit: &BigUint) -> BigUint {
+        if self.is_bonding_curve() {
+            return self.user_bonding_curve_tokens(user_id).get();
+        }
+
+        let launched_token_balance = self.launched_token_balance().get();
+        let accepted_token_balance = self.accepted_token_balance().get();
+        if launched_token_balance == 0 || accepted_token_balance == 0 || total_deposit == &0 {
+            return BigUint::zero();
+        }
+
+        if self.is_dutch_auction() {
+            let clearing = self.compute_auction_clearing();
+            self.compute_user_auction_allocation(user_id, &clearing)
+                .bought_tokens
+        } else if self.is_twap_settlement() {
+            let settlement = self.compute_twap_settlement();
+            let (bought_tokens, _) = self.compute_user_twap_allocation(total_deposit, &settlement);
+            bought_tokens
+        } else if self.has_limit_orders() {
+            let clearing = self.compute_limit_order_clearing();
+            if self.is_limit_order_excluded(user_id, &clearing) {
+                return BigUint::zero();
+            }
+
+            launched_token_balance * total_deposit / clearing.included_deposits
+        } else {
+            self.compute_user_bought_tokens(total_deposit).amount
+        }
+    }
+
+    /// Returns (address, total_deposit, deposit_limit, redeemed) for each whitelisted user.
+    /// The order changes when users are refunded.
+    #[view(getParticipants)]
+    fn get_participants(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> MultiValueEncoded<MultiValue4<ManagedAddress, BigUint, BigUint, bool>> {
+        let participants_mapper = self.participants();
+        let id_mapper = self.user_id_mapper();
+        let end = core::cmp::min(offset.saturating_add(limit), participants_mapper.len());
+
+        let mut result = MultiValueEncoded::new();
+        for index in offset..end {
+            let user_id = participants_mapper.get_by_index(index + 1);
+            let address = id_mapper.get_address(user_id).unwrap_or_default();
+            let total_deposit = self.total_deposit_by_user(user_id).get();
+            let deposit_limit = self.user_deposit_limit(user_id).get();
+            let redeemed = self.user_redeemed(user_id).get() == USER_REDEEMED;
+            result.push((address, total_deposit, deposit_limit, redeemed).into());
+        }
+
+        result
+    }
+
+    #[view(getNrWhitelistedUsers)]
+    fn get_nr_whitelisted_users(&self) -> usize {
+        self.participants().len()
+    }
+
+    /// Runs the same checks as `userDeposit`, without the payment
+    #[view(simulateDeposit)]
+    fn simulate_deposit(
+        &self,
+        user: ManagedAddress,
+        amount: BigUint,
+        opt_max_price: OptionalValue<BigUint>,
+    ) -> SimulationResult<Self::Api> {
+        let user_id = match self.check_user_action_allowed(&user) {
+            Ok(user_id) => user_id,
+            Err(err_msg) => return SimulationResult::Error(err_msg.into()),
+        };
+
+        match self.check_user_deposit(user_id, &amount, &opt_max_price) {
+            Ok(user_total_deposit) => {
+                let total_deposit = self.accepted_token_balance().get() + amount;
+                self.build_action_simulation(user_total_deposit, total_deposit)
+            }
+            Err(err_msg) => SimulationResult::Error(err_msg.into()),
+        }
+    }
+
+    /// Runs the same checks as `userWithdraw`
+    #[view(simulateWithdraw)]
+    fn simulate_withdraw(
+        &self,
+        user: ManagedAddress,
+        amount: BigUint,
+    ) -> SimulationResult<Self::Api> {
+        if self.is_dutch_auction() {
+            return SimulationResult::Error(DUTCH_AUCTION_WITHDRAW_ERR_MSG.into());
+        }
+
+        let user_id = match self.check_user_action_allowed(&user) {
+            Ok(user_id) => user_id,
+            Err(err_msg) => return SimulationResult::Error(err_msg.into()),
+        };
+
+        match self.check_user_withdraw(user_id, &amount) {
+            Ok(user_total_deposit) => {
+                let total_deposit = self.accepted_token_balance().get() - amount;
+                self.build_action_simulation(user_total_deposit, total_deposit)
+            }
+            Err(err_msg) => SimulationResult::Error(err_msg.into()),
+        }
+    }
+
+    /// Returns the user ID
+    fn check_user_action_allowed(&self, user: &ManagedAddress) -> Result<AddressId, &'static [u8]> {
+        if self.paused().get() {
+            return Err(CONTRACT_PAUSED_ERR_MSG);
+        }
+
+        let phase = self.get_current_phase();
+        if phase != Phase::UserDepositWithdraw {
+            return Err(USER_DEPOSIT_WITHDRAW_NOT_ALLOWED_ERR_MSG);
+        }
+
+        let user_id = self.user_id_mapper().get_id(user);
+        if user_id == NULL_ID || !self.user_whitelist().contains(&user_id) {
+            return Err(USER_NOT_WHITELISTED_ERR_MSG);
+        }
+
+        Ok(user_id)
+    }
+
+    fn build_action_simulation(
+        &self,
+        user_total_deposit: BigUint,
+        total_deposit: BigUint,
+    ) -> SimulationResult<Self::Api> {
+        let implied_price = self.compute_implied_price(&total_deposit);
+
+        SimulationResult::Success(ActionSimulation {
+            user_total_deposit,
+            total_deposit,
+            implied_price,
+        })
+    }
 }
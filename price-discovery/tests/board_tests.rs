@@ -0,0 +1,231 @@
+#![allow(deprecated)]
+
+mod tests_common;
+use multiversx_sc::types::{Address, ManagedVec};
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint, DebugApi};
+use price_discovery::{
+    board::{ActionId, BoardAction, BoardModule, WhitelistEntry},
+    common_storage::CommonStorageModule,
+    events::{ProposeActionEvent, SetBoardEvent},
+    roles::{Role, RolesModule},
+    user_actions::{
+        admin_actions::AdminActionsModule, user_deposit_withdraw::UserDepositWithdrawModule,
+    },
+};
+use tests_common::*;
+
+#[test]
+fn board_action_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let first_member = setup.first_user_address.clone();
+    let second_member = setup.second_user_address.clone();
+    let owner = setup.owner_address.clone();
+
+    setup
+        .call_set_board(3, &[&first_member, &second_member])
+        .assert_user_error("Invalid board quorum");
+    setup
+        .call_set_board(2, &[&first_member, &first_member])
+        .assert_user_error("Duplicate board member");
+    let result = setup.call_set_board(2, &[&first_member, &second_member]);
+    result.assert_ok();
+    let event: SetBoardEvent<DebugApi> = get_event_data(&result, "setBoardEvent", &owner);
+    assert_eq!(event.quorum, 2);
+    assert_eq!(event.members.len(), 2);
+    assert_eq!(
+        event.members.get(0).clone_value(),
+        managed_address!(&first_member)
+    );
+
+    // the admin key has no rights once the board is set
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_min_launched_tokens(managed_biguint!(5_000));
+        })
+        .assert_user_error("Only admin may call this function");
+    setup
+        .call_set_board(1, &[&owner])
+        .assert_user_error("Only admin may call this function");
+
+    setup
+        .b_mock
+        .execute_tx(&owner, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.propose_action(BoardAction::SetMinLaunchedTokens(managed_biguint!(5_000)));
+        })
+        .assert_user_error("Only board members may call this function");
+    let result =
+        setup
+            .b_mock
+            .execute_tx(&first_member, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+                let action_id =
+                    sc.propose_action(BoardAction::SetMinLaunchedTokens(managed_biguint!(5_000)));
+                assert_eq!(action_id, 1);
+            });
+    result.assert_ok();
+    let event: ProposeActionEvent<DebugApi> =
+        get_event_data(&result, "proposeActionEvent", &first_member);
+    assert_eq!(event.action_id, 1);
+    assert!(matches!(
+        event.action,
+        BoardAction::SetMinLaunchedTokens(amount) if amount == managed_biguint!(5_000)
+    ));
+    let action_id: ActionId = get_event_data(&result, "signActionEvent", &first_member);
+    assert_eq!(action_id, 1);
+
+    setup
+        .call_sign_action(&first_member, 1)
+        .assert_user_error("Action already signed");
+    setup
+        .call_perform_action(&first_member, 1)
+        .assert_user_error("Quorum not reached");
+
+    setup.call_sign_action(&second_member, 1).assert_ok();
+    let result = setup.call_perform_action(&second_member, 1);
+    result.assert_ok();
+    let action_id: ActionId = get_event_data(&result, "performActionEvent", &second_member);
+    assert_eq!(action_id, 1);
+    setup
+        .call_perform_action(&first_member, 1)
+        .assert_user_error("Action not found");
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.min_launched_tokens().get(), managed_biguint!(5_000));
+            assert!(!sc.get_action_data(1).is_pending());
+            assert!(sc.get_pending_action_ids().is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn discard_action_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let first_member = setup.first_user_address.clone();
+    let second_member = setup.second_user_address.clone();
+    setup
+        .call_set_board(2, &[&first_member, &second_member])
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&first_member, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            let _ = sc.propose_action(BoardAction::SetUserLimit(
+                managed_address!(&second_member),
+                managed_biguint!(20_000),
+            ));
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_tx(&second_member, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            sc.discard_action(1);
+        })
+        .assert_user_error("Cannot discard action with valid signatures");
+    setup
+        .b_mock
+        .execute_tx(&second_member, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            sc.unsign_action(1);
+        })
+        .assert_user_error("Action not signed");
+    setup
+        .b_mock
+        .execute_tx(&first_member, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            sc.unsign_action(1);
+        })
+        .assert_ok();
+    let result =
+        setup
+            .b_mock
+            .execute_tx(&second_member, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+                sc.discard_action(1);
+            });
+    result.assert_ok();
+    let action_id: ActionId = get_event_data(&result, "discardActionEvent", &second_member);
+    assert_eq!(action_id, 1);
+
+    setup
+        .call_sign_action(&first_member, 1)
+        .assert_user_error("Action not found");
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert!(sc.get_pending_action_ids().is_empty());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn board_admin_actions_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let member = setup.first_user_address.clone();
+    let second_user = setup.second_user_address.clone();
+    let new_user = setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    // the roles granted by the admin key do not survive the handover
+    setup.call_grant_role(Role::Pauser, &member).assert_ok();
+    setup.call_set_board(1, &[&member]).assert_ok();
+    setup
+        .b_mock
+        .execute_tx(&member, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            sc.pause();
+        })
+        .assert_user_error("Permission denied");
+
+    propose_and_perform(&mut setup, &member, 1, || BoardAction::Pause);
+    propose_and_perform(&mut setup, &member, 2, || {
+        BoardAction::GrantRole(Role::Pauser, managed_address!(&second_user))
+    });
+    propose_and_perform(&mut setup, &member, 3, || {
+        let mut entries = ManagedVec::new();
+        entries.push(WhitelistEntry {
+            address: managed_address!(&new_user),
+            limit: managed_biguint!(5_000),
+        });
+        BoardAction::AddUsersToWhitelist(entries)
+    });
+    propose_and_perform(&mut setup, &member, 4, || {
+        BoardAction::SetTargetRaise(managed_biguint!(10_000))
+    });
+    propose_and_perform(&mut setup, &member, 5, || {
+        BoardAction::ProposeAdmin(managed_address!(&second_user))
+    });
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert!(sc.paused().get());
+            assert!(sc.has_role(Role::Pauser, &managed_address!(&second_user)));
+            assert!(!sc.has_role(Role::Pauser, &managed_address!(&member)));
+            assert!(sc.is_user_whitelisted(&managed_address!(&new_user)));
+            assert_eq!(sc.target_raise().get(), managed_biguint!(10_000));
+            assert_eq!(
+                sc.get_pending_admin().into_option(),
+                Some(managed_address!(&second_user))
+            );
+        })
+        .assert_ok();
+}
+
+fn propose_and_perform<PriceDiscObjBuilder, ActionBuilder>(
+    setup: &mut PriceDiscSetup<PriceDiscObjBuilder>,
+    member: &Address,
+    expected_action_id: ActionId,
+    action_builder: ActionBuilder,
+) where
+    PriceDiscObjBuilder: 'static + Copy + Fn() -> price_discovery::ContractObj<DebugApi>,
+    ActionBuilder: Fn() -> BoardAction<DebugApi>,
+{
+    setup
+        .b_mock
+        .execute_tx(member, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            let action_id = sc.propose_action(action_builder());
+            assert_eq!(action_id, expected_action_id);
+        })
+        .assert_ok();
+    setup
+        .call_perform_action(member, expected_action_id)
+        .assert_ok();
+}
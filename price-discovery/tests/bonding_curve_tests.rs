@@ -0,0 +1,339 @@
+#![allow(deprecated)]
+
+mod tests_common;
+use multiversx_sc::types::BigUint;
+use multiversx_sc_scenario::{managed_biguint, rust_biguint, DebugApi};
+use price_discovery::{
+    bonding_curve::{BondingCurveModule, MAX_PERCENTAGE},
+    common_storage::CommonStorageModule,
+    views::ViewsModule,
+};
+use tests_common::*;
+
+const PRICE_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+#[test]
+fn linear_bonding_curve_test() {
+    // price starts at 1 and increases by 1 for every 1_000 tokens sold
+    let mut setup = PriceDiscSetup::new_with_sale_mode(
+        price_discovery::contract_obj,
+        SaleModeArgs::LinearBondingCurve {
+            initial_price: PRICE_PRECISION,
+            slope: PRICE_PRECISION * PRICE_PRECISION / 1_000,
+        },
+    );
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_500)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.user_bonding_curve_tokens(1).get(),
+                managed_biguint!(1_000)
+            );
+            assert_eq!(sc.get_current_price(), BigUint::from(2 * PRICE_PRECISION));
+        })
+        .assert_ok();
+
+    setup
+        .call_user_deposit(&setup.second_user_address.clone(), 2_000)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.user_bonding_curve_tokens(2).get(), managed_biguint!(828));
+            assert_eq!(
+                sc.bonding_curve_tokens_sold().get(),
+                managed_biguint!(1_828)
+            );
+        })
+        .assert_ok();
+
+    // the reserve moves back down the curve, from 3_500 to 2_750
+    setup
+        .call_user_withdraw(&setup.first_user_address.clone(), 750)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.user_bonding_curve_tokens(1).get(), managed_biguint!(721));
+            assert_eq!(
+                sc.bonding_curve_tokens_sold().get(),
+                managed_biguint!(1_549)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+
+    setup
+        .call_owner_deposit(1_000)
+        .assert_user_error("Not enough launched tokens for the bonding curve sales");
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + 1);
+
+    setup.call_owner_redeem().assert_ok();
+
+    // owner gets all the accepted tokens and the unsold launched tokens
+    setup.b_mock.check_esdt_balance(
+        &setup.owner_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(2_750),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.owner_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - 1_549),
+    );
+
+    setup.b_mock.set_block_timestamp(
+        START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME + 1,
+    );
+
+    setup
+        .call_user_redeem(&setup.first_user_address.clone())
+        .assert_ok();
+    setup
+        .call_user_redeem(&setup.second_user_address.clone())
+        .assert_ok();
+
+    setup.b_mock.check_esdt_balance(
+        &setup.first_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(721),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.second_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(828),
+    );
+    setup.b_mock.check_esdt_balance(
+        setup.pd_wrapper.address_ref(),
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+    setup.b_mock.check_esdt_balance(
+        setup.pd_wrapper.address_ref(),
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn bonding_curve_withdraw_invariant_test() {
+    let mut setup = PriceDiscSetup::new_with_sale_mode(
+        price_discovery::contract_obj,
+        SaleModeArgs::LinearBondingCurve {
+            initial_price: PRICE_PRECISION,
+            slope: PRICE_PRECISION * PRICE_PRECISION / 1_000,
+        },
+    );
+    let first_user_address = setup.first_user_address.clone();
+    let second_user_address = setup.second_user_address.clone();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+
+    setup
+        .call_user_deposit(&first_user_address, 1_500)
+        .assert_ok();
+    setup
+        .call_user_deposit(&second_user_address, 2_000)
+        .assert_ok();
+    check_bonding_curve_tokens(&mut setup, 1_000, 828);
+
+    // the first user bought low, so they keep part of their tokens after withdrawing everything
+    setup
+        .call_user_withdraw(&first_user_address, 1_500)
+        .assert_ok();
+    check_bonding_curve_tokens(&mut setup, 408, 828);
+
+    // moving the reserve from 2_000 to 0 gives back 1_236 tokens
+    setup
+        .call_user_withdraw(&second_user_address, 2_000)
+        .assert_user_error("Not enough bonding curve tokens for the withdrawal");
+    setup
+        .call_user_withdraw(&second_user_address, 1_000)
+        .assert_ok();
+    check_bonding_curve_tokens(&mut setup, 408, 324);
+
+    setup
+        .call_user_deposit(&first_user_address, 1_500)
+        .assert_ok();
+    check_bonding_curve_tokens(&mut setup, 1_125, 324);
+}
+
+/// Checks the users' tokens, and that the tokens sold match the curve for the current reserve
+fn check_bonding_curve_tokens<PriceDiscObjBuilder>(
+    setup: &mut PriceDiscSetup<PriceDiscObjBuilder>,
+    first_user_tokens: u64,
+    second_user_tokens: u64,
+) where
+    PriceDiscObjBuilder: 'static + Copy + Fn() -> price_discovery::ContractObj<DebugApi>,
+{
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.user_bonding_curve_tokens(1).get(),
+                managed_biguint!(first_user_tokens)
+            );
+            assert_eq!(
+                sc.user_bonding_curve_tokens(2).get(),
+                managed_biguint!(second_user_tokens)
+            );
+            assert_eq!(
+                sc.bonding_curve_tokens_sold().get(),
+                managed_biguint!(first_user_tokens + second_user_tokens)
+            );
+            assert_eq!(
+                sc.bonding_curve_tokens_sold().get(),
+                sc.get_bonding_curve_tokens(&sc.accepted_token_balance().get())
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn exponential_bonding_curve_test() {
+    // price doubles for every 1_000 accepted tokens deposited
+    let mut setup = PriceDiscSetup::new_with_sale_mode(
+        price_discovery::contract_obj,
+        SaleModeArgs::ExponentialBondingCurve {
+            initial_price: PRICE_PRECISION,
+            growth_step: 1_000,
+            growth_rate: 10_000,
+        },
+    );
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+
+    // 1_000 tokens at price 1, 500 at price 2 and 125 at price 4
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 2_500)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.user_bonding_curve_tokens(1).get(),
+                managed_biguint!(1_625)
+            );
+            assert_eq!(sc.get_current_price(), BigUint::from(4 * PRICE_PRECISION));
+        })
+        .assert_ok();
+
+    // 125 tokens at price 4 and 62 at price 8
+    setup
+        .call_user_deposit(&setup.second_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.user_bonding_curve_tokens(2).get(), managed_biguint!(187));
+            assert_eq!(
+                sc.bonding_curve_tokens_sold().get(),
+                managed_biguint!(1_812)
+            );
+        })
+        .assert_ok();
+
+    // withdrawing moves the price back down the curve
+    setup
+        .call_user_withdraw(&setup.second_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.user_bonding_curve_tokens(2).get(), managed_biguint!(0));
+            assert_eq!(
+                sc.bonding_curve_tokens_sold().get(),
+                managed_biguint!(1_625)
+            );
+            assert_eq!(sc.get_current_price(), BigUint::from(4 * PRICE_PRECISION));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn exponential_bonding_curve_max_steps_test() {
+    // 500 steps of 1_000 tokens, so the curve takes at most 500_000 tokens
+    let mut setup = PriceDiscSetup::new_with_sale_mode(
+        price_discovery::contract_obj,
+        SaleModeArgs::ExponentialBondingCurve {
+            initial_price: PRICE_PRECISION,
+            growth_step: 1_000,
+            growth_rate: 100,
+        },
+    );
+
+    setup
+        .call_set_target_raise(500_001)
+        .assert_user_error("Target raise exceeds the bonding curve capacity");
+    setup.call_set_target_raise(500_000).assert_ok();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 500_000)
+        .assert_ok();
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_user_error("Too many bonding curve steps");
+}
+
+#[test]
+fn invalid_bonding_curve_test() {
+    let mut setup = PriceDiscSetup::new_uninitialized(price_discovery::contract_obj);
+
+    for sale_mode in [
+        SaleModeArgs::LinearBondingCurve {
+            initial_price: 0,
+            slope: PRICE_PRECISION,
+        },
+        SaleModeArgs::ExponentialBondingCurve {
+            initial_price: 0,
+            growth_step: 1_000,
+            growth_rate: 100,
+        },
+    ] {
+        setup
+            .call_init(&InitArgs {
+                sale_mode: Some(sale_mode),
+                ..Default::default()
+            })
+            .assert_user_error("Invalid initial price");
+    }
+
+    for (growth_step, growth_rate) in [(0, 100), (1_000, 0), (1_000, MAX_PERCENTAGE + 1)] {
+        setup
+            .call_init(&InitArgs {
+                sale_mode: Some(SaleModeArgs::ExponentialBondingCurve {
+                    initial_price: PRICE_PRECISION,
+                    growth_step,
+                    growth_rate,
+                }),
+                ..Default::default()
+            })
+            .assert_user_error("Invalid bonding curve growth");
+    }
+
+    setup
+        .call_init(&InitArgs {
+            sale_mode: Some(SaleModeArgs::ExponentialBondingCurve {
+                initial_price: PRICE_PRECISION,
+                growth_step: 1_000,
+                growth_rate: MAX_PERCENTAGE,
+            }),
+            ..Default::default()
+        })
+        .assert_ok();
+}
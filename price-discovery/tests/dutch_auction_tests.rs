@@ -0,0 +1,346 @@
+#![allow(deprecated)]
+
+mod tests_common;
+use multiversx_sc_scenario::{managed_biguint, rust_biguint};
+use price_discovery::dutch_auction::DutchAuctionModule;
+use price_discovery::Timestamp;
+use tests_common::*;
+
+const PRICE_PRECISION: u64 = 1_000_000_000_000_000_000;
+const MAX_PRICE: u64 = 4 * PRICE_PRECISION;
+const MIN_PRICE: u64 = PRICE_PRECISION;
+
+fn dutch_auction(price_step_duration: Timestamp) -> SaleModeArgs {
+    SaleModeArgs::DutchAuction {
+        max_price: MAX_PRICE.into(),
+        min_price: MIN_PRICE.into(),
+        price_step_duration,
+    }
+}
+
+#[test]
+fn auction_price_test() {
+    let mut setup =
+        PriceDiscSetup::new_with_sale_mode(price_discovery::contract_obj, dutch_auction(0));
+
+    setup.b_mock.set_block_timestamp(START_TIME);
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_auction_price(), managed_biguint!(MAX_PRICE));
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 50);
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.get_auction_price(),
+                managed_biguint!(5 * PRICE_PRECISION / 2)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_auction_price(), managed_biguint!(MIN_PRICE));
+        })
+        .assert_ok();
+
+    // stepwise decrease
+    let mut setup =
+        PriceDiscSetup::new_with_sale_mode(price_discovery::contract_obj, dutch_auction(25));
+
+    setup.b_mock.set_block_timestamp(START_TIME + 30);
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.get_auction_price(),
+                managed_biguint!(13 * PRICE_PRECISION / 4)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn auction_withdraw_not_allowed_test() {
+    let mut setup =
+        PriceDiscSetup::new_with_sale_mode(price_discovery::contract_obj, dutch_auction(0));
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .call_user_withdraw(&setup.first_user_address.clone(), 400)
+        .assert_user_error("Withdraw not allowed in Dutch auction");
+}
+
+#[test]
+fn auction_sold_out_clearing_test() {
+    let mut setup =
+        PriceDiscSetup::new_with_sale_mode(price_discovery::contract_obj, dutch_auction(0));
+
+    // 1_000 tokens requested at price 4
+    setup.b_mock.set_block_timestamp(START_TIME);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 4_000)
+        .assert_ok();
+
+    // 2_000 tokens requested at price 2.5
+    setup.b_mock.set_block_timestamp(START_TIME + 50);
+    setup
+        .call_user_deposit(&setup.second_user_address.clone(), 5_000)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + 1);
+
+    setup.call_owner_redeem().assert_ok();
+
+    // supply sells out at price 2.5, with the second level only half filled
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let clearing = sc.auction_clearing().get();
+            assert_eq!(clearing.price, managed_biguint!(5 * PRICE_PRECISION / 2));
+            assert_eq!(clearing.marginal_level, 2);
+            assert_eq!(clearing.marginal_level_fill, managed_biguint!(1_000));
+            assert_eq!(clearing.tokens_sold, managed_biguint!(2_000));
+        })
+        .assert_ok();
+
+    setup.b_mock.check_esdt_balance(
+        &setup.owner_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(5_000),
+    );
+
+    setup.b_mock.set_block_timestamp(
+        START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME + 1,
+    );
+
+    setup
+        .call_user_redeem(&setup.first_user_address.clone())
+        .assert_ok();
+    setup
+        .call_user_redeem(&setup.second_user_address.clone())
+        .assert_ok();
+
+    // both users pay 2.5 per token and get the rest refunded
+    setup.b_mock.check_esdt_balance(
+        &setup.first_user_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - 2_500),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.second_user_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - 2_500),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.first_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(1_000),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.second_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(1_000),
+    );
+    setup.b_mock.check_esdt_balance(
+        setup.pd_wrapper.address_ref(),
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+    setup.b_mock.check_esdt_balance(
+        setup.pd_wrapper.address_ref(),
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn auction_not_sold_out_clearing_test() {
+    let mut setup =
+        PriceDiscSetup::new_with_sale_mode(price_discovery::contract_obj, dutch_auction(0));
+
+    setup.b_mock.set_block_timestamp(START_TIME);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 4_000)
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 50);
+    setup
+        .call_user_deposit(&setup.second_user_address.clone(), 5_000)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+
+    setup.call_owner_deposit(5_000).assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + 1);
+
+    setup.call_owner_redeem().assert_ok();
+
+    // price dropped to the minimum, and the unsold tokens go back to the owner
+    setup.b_mock.check_esdt_balance(
+        &setup.owner_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(3_000),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.owner_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - 3_000),
+    );
+
+    setup.b_mock.set_block_timestamp(
+        START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME + 1,
+    );
+
+    setup
+        .call_user_redeem(&setup.first_user_address.clone())
+        .assert_ok();
+    setup
+        .call_user_redeem(&setup.second_user_address.clone())
+        .assert_ok();
+
+    setup.b_mock.check_esdt_balance(
+        &setup.first_user_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - 1_000),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.second_user_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - 2_000),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.first_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(1_000),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.second_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(2_000),
+    );
+    setup.b_mock.check_esdt_balance(
+        setup.pd_wrapper.address_ref(),
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn auction_refunded_user_bids_removed_test() {
+    let mut setup =
+        PriceDiscSetup::new_with_sale_mode(price_discovery::contract_obj, dutch_auction(0));
+
+    setup.b_mock.set_block_timestamp(START_TIME);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 4_000)
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 50);
+    setup
+        .call_user_deposit(&setup.second_user_address.clone(), 5_000)
+        .assert_ok();
+
+    setup
+        .call_refund_user(&setup.first_user_address.clone())
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + 1);
+
+    setup.call_owner_redeem().assert_ok();
+
+    setup.b_mock.set_block_timestamp(
+        START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME + 1,
+    );
+
+    setup
+        .call_user_redeem(&setup.second_user_address.clone())
+        .assert_ok();
+
+    // the remaining bid takes the whole supply at its own price
+    setup.b_mock.check_esdt_balance(
+        &setup.first_user_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.second_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(2_000),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.owner_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(5_000),
+    );
+    setup.b_mock.check_esdt_balance(
+        setup.pd_wrapper.address_ref(),
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn invalid_auction_config_test() {
+    let mut setup = PriceDiscSetup::new_uninitialized(price_discovery::contract_obj);
+
+    for (max_price, min_price) in [(MAX_PRICE, 0), (MIN_PRICE, MAX_PRICE)] {
+        setup
+            .call_init(&InitArgs {
+                sale_mode: Some(SaleModeArgs::DutchAuction {
+                    max_price: max_price.into(),
+                    min_price: min_price.into(),
+                    price_step_duration: 0,
+                }),
+                ..Default::default()
+            })
+            .assert_user_error("Invalid auction prices");
+    }
+
+    setup
+        .call_init(&InitArgs {
+            sale_mode: Some(dutch_auction(USER_DEPOSIT_TIME + 1)),
+            ..Default::default()
+        })
+        .assert_user_error("Invalid auction price step duration");
+
+    setup
+        .call_init(&InitArgs {
+            sale_mode: Some(dutch_auction(USER_DEPOSIT_TIME)),
+            ..Default::default()
+        })
+        .assert_ok();
+}
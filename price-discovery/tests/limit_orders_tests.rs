@@ -0,0 +1,264 @@
+#![allow(deprecated)]
+
+mod tests_common;
+use multiversx_sc::types::BigUint;
+use multiversx_sc_scenario::{managed_biguint, rust_biguint};
+use price_discovery::limit_orders::{LimitOrdersModule, MAX_LIMIT_ORDER_PRICES};
+use tests_common::*;
+
+const PRICE_PRECISION: u128 = 1_000_000_000_000_000_000;
+
+#[test]
+fn limit_order_excluded_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .call_user_deposit_with_max_price(
+            &setup.second_user_address.clone(),
+            9_000,
+            2 * PRICE_PRECISION,
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    // price would be 5 with all deposits, so the second user's order is excluded
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.get_limit_order_clearing_price(),
+                BigUint::from(PRICE_PRECISION / 2)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + 1);
+
+    setup.call_owner_redeem().assert_ok();
+
+    setup.b_mock.check_esdt_balance(
+        &setup.owner_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(1_000),
+    );
+
+    setup.b_mock.set_block_timestamp(
+        START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME + 1,
+    );
+
+    setup
+        .call_user_redeem(&setup.first_user_address.clone())
+        .assert_ok();
+    setup
+        .call_user_redeem(&setup.second_user_address.clone())
+        .assert_ok();
+
+    setup.b_mock.check_esdt_balance(
+        &setup.first_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(2_000),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.second_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.second_user_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE),
+    );
+    setup.b_mock.check_esdt_balance(
+        setup.pd_wrapper.address_ref(),
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn limit_order_included_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .call_user_deposit_with_max_price(
+            &setup.second_user_address.clone(),
+            9_000,
+            6 * PRICE_PRECISION,
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + 1);
+
+    setup.call_owner_redeem().assert_ok();
+
+    setup.b_mock.set_block_timestamp(
+        START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME + 1,
+    );
+
+    setup
+        .call_user_redeem(&setup.first_user_address.clone())
+        .assert_ok();
+    setup
+        .call_user_redeem(&setup.second_user_address.clone())
+        .assert_ok();
+
+    setup.b_mock.check_esdt_balance(
+        &setup.first_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(200),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.second_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(1_800),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.owner_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(10_000),
+    );
+}
+
+#[test]
+fn limit_order_demand_views_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+
+    setup
+        .call_user_deposit_with_max_price(
+            &setup.first_user_address.clone(),
+            1_000,
+            4 * PRICE_PRECISION,
+        )
+        .assert_ok();
+    setup
+        .call_user_deposit_with_max_price(
+            &setup.second_user_address.clone(),
+            9_000,
+            2 * PRICE_PRECISION,
+        )
+        .assert_ok();
+    setup
+        .call_user_withdraw(&setup.second_user_address.clone(), 4_000)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let demand: Vec<_> = sc
+                .get_demand_by_max_price()
+                .into_iter()
+                .map(|pair| pair.into_tuple())
+                .collect();
+            assert_eq!(
+                demand,
+                vec![
+                    (BigUint::from(2 * PRICE_PRECISION), managed_biguint!(5_000)),
+                    (BigUint::from(4 * PRICE_PRECISION), managed_biguint!(1_000)),
+                ]
+            );
+
+            assert_eq!(
+                sc.get_demand_at_price(BigUint::from(PRICE_PRECISION)),
+                managed_biguint!(6_000)
+            );
+            assert_eq!(
+                sc.get_demand_at_price(BigUint::from(3 * PRICE_PRECISION)),
+                managed_biguint!(1_000)
+            );
+        })
+        .assert_ok();
+
+    // a later deposit without max price removes the limit
+    setup
+        .call_user_deposit(&setup.second_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_demand_by_max_price().len(), 1);
+            assert_eq!(
+                sc.get_demand_at_price(BigUint::from(5 * PRICE_PRECISION)),
+                managed_biguint!(6_000)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn limit_order_not_allowed_in_dutch_auction_test() {
+    let mut setup = PriceDiscSetup::new_with_sale_mode(
+        price_discovery::contract_obj,
+        SaleModeArgs::DutchAuction {
+            max_price: 4 * PRICE_PRECISION,
+            min_price: PRICE_PRECISION,
+            price_step_duration: 0,
+        },
+    );
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+
+    setup
+        .call_user_deposit_with_max_price(
+            &setup.first_user_address.clone(),
+            1_000,
+            2 * PRICE_PRECISION,
+        )
+        .assert_user_error("Max price not allowed in this sale mode");
+}
+
+#[test]
+fn limit_order_max_prices_cap_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+
+    // each user places an order at a different max price
+    for level in 1..=MAX_LIMIT_ORDER_PRICES as u128 {
+        let user = setup.b_mock.create_user_account(&rust_biguint!(0));
+        setup
+            .b_mock
+            .set_esdt_balance(&user, ACCEPTED_TOKEN_ID, &rust_biguint!(1_000));
+        setup.call_add_user_to_whitelist(&user, 0).assert_ok();
+        setup
+            .call_user_deposit_with_max_price(&user, 1_000, level)
+            .assert_ok();
+    }
+
+    setup
+        .call_user_deposit_with_max_price(
+            &setup.first_user_address.clone(),
+            1_000,
+            2 * PRICE_PRECISION,
+        )
+        .assert_user_error("Too many distinct max prices");
+    setup
+        .call_user_deposit_with_max_price(&setup.first_user_address.clone(), 1_000, 50)
+        .assert_ok();
+}
@@ -0,0 +1,386 @@
+#![allow(deprecated)]
+
+mod tests_common;
+use multiversx_sc::types::BigUint;
+use multiversx_sc_scenario::{managed_biguint, rust_biguint, DebugApi};
+use price_discovery::common_storage::CommonStorageModule;
+use price_discovery::events::PhaseDurationEvent;
+use price_discovery::phase::{
+    Phase, PhaseModule, TimeBase, MAX_COOLDOWN_PHASES, MAX_PHASE_DURATION_BLOCKS,
+};
+use price_discovery::Timestamp;
+use tests_common::*;
+
+#[test]
+fn owner_deposit_first_test() {
+    let mut setup = PriceDiscSetup::new_with_phase_pipeline(
+        price_discovery::contract_obj,
+        &[
+            (Phase::OwnerDepositWithdraw, OWNER_DEPOSIT_TIME),
+            (Phase::Cooldown, 50),
+            (Phase::UserDepositWithdraw, USER_DEPOSIT_TIME),
+            (Phase::OwnerRedeem, OWNER_REDEEM_TIME),
+        ],
+    );
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_user_error("User deposit/withdraw not allowed in this phase");
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + OWNER_DEPOSIT_TIME + 1);
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert!(sc.get_current_phase() == Phase::Cooldown);
+        })
+        .assert_ok();
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_user_error("User deposit/withdraw not allowed in this phase");
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + OWNER_DEPOSIT_TIME + 51);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .call_owner_withdraw(500)
+        .assert_user_error("Owner deposit/withdraw not allowed in this phase");
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + OWNER_DEPOSIT_TIME + 51 + USER_DEPOSIT_TIME);
+    setup.call_owner_redeem().assert_ok();
+
+    setup.b_mock.set_block_timestamp(
+        START_TIME + OWNER_DEPOSIT_TIME + 51 + USER_DEPOSIT_TIME + OWNER_REDEEM_TIME,
+    );
+    setup
+        .call_user_redeem(&setup.first_user_address.clone())
+        .assert_ok();
+    setup.b_mock.check_esdt_balance(
+        &setup.first_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(2_000),
+    );
+}
+
+#[test]
+fn modify_phase_pipeline_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+
+    // the current phase may not be changed
+    setup
+        .call_add_cooldown_phase(1, 50)
+        .assert_user_error("Invalid current phase");
+    setup
+        .call_remove_cooldown_phase(2)
+        .assert_user_error("May only remove cooldown phases");
+
+    let result = setup.call_add_cooldown_phase(2, 50);
+    result.assert_ok();
+    let event: PhaseDurationEvent =
+        get_event_data(&result, "addCooldownPhaseEvent", &setup.owner_address);
+    assert_eq!(event.phase_index, 2);
+    assert_eq!(event.duration, 50);
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let schedule: Vec<_> = sc.get_phase_schedule().into_iter().collect();
+            assert_eq!(schedule.len(), 6);
+            assert!(schedule[2].phase == Phase::Cooldown);
+            assert_eq!(schedule[3].start, START_TIME + USER_DEPOSIT_TIME + 50);
+        })
+        .assert_ok();
+
+    let result = setup.call_set_phase_duration(2, 20);
+    result.assert_ok();
+    let event: PhaseDurationEvent =
+        get_event_data(&result, "setPhaseDurationEvent", &setup.owner_address);
+    assert_eq!(event.phase_index, 2);
+    assert_eq!(event.duration, 20);
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+    setup
+        .call_owner_deposit(2_000)
+        .assert_user_error("Owner deposit/withdraw not allowed in this phase");
+    setup
+        .call_remove_cooldown_phase(2)
+        .assert_user_error("Invalid current phase");
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 21);
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    // may not shorten the cooldown so that the current phase changes
+    setup
+        .call_set_phase_duration(3, 1)
+        .assert_user_error("Invalid timestamp change");
+}
+
+#[test]
+fn cooldown_phases_cap_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+
+    for _ in 0..MAX_COOLDOWN_PHASES {
+        setup.call_add_cooldown_phase(2, 10).assert_ok();
+    }
+    setup
+        .call_add_cooldown_phase(2, 10)
+        .assert_user_error("Too many cooldown phases");
+
+    let mut phases = vec![(Phase::UserDepositWithdraw, USER_DEPOSIT_TIME)];
+    phases.extend((0..=MAX_COOLDOWN_PHASES).map(|_| (Phase::Cooldown, 10)));
+    phases.push((Phase::OwnerDepositWithdraw, OWNER_DEPOSIT_TIME));
+    phases.push((Phase::OwnerRedeem, OWNER_REDEEM_TIME));
+    PriceDiscSetup::new_uninitialized(price_discovery::contract_obj)
+        .call_init(&InitArgs {
+            phase_pipeline: Some(phases),
+            ..Default::default()
+        })
+        .assert_user_error("Too many cooldown phases");
+}
+
+#[test]
+fn invalid_phase_pipeline_test() {
+    let mut setup = PriceDiscSetup::new_uninitialized(price_discovery::contract_obj);
+
+    let invalid_pipelines = [
+        vec![
+            (Phase::UserDepositWithdraw, USER_DEPOSIT_TIME),
+            (Phase::OwnerRedeem, OWNER_REDEEM_TIME),
+            (Phase::OwnerDepositWithdraw, OWNER_DEPOSIT_TIME),
+        ],
+        vec![
+            (Phase::UserDepositWithdraw, USER_DEPOSIT_TIME),
+            (Phase::OwnerDepositWithdraw, OWNER_DEPOSIT_TIME),
+            (Phase::UserRedeem, OWNER_REDEEM_TIME),
+        ],
+    ];
+    for phases in invalid_pipelines {
+        setup
+            .call_init(&InitArgs {
+                phase_pipeline: Some(phases),
+                ..Default::default()
+            })
+            .assert_user_error("Invalid phase pipeline");
+    }
+
+    setup
+        .call_init(&InitArgs {
+            phase_pipeline: Some(vec![
+                (Phase::UserDepositWithdraw, USER_DEPOSIT_TIME),
+                (Phase::Cooldown, 0),
+                (Phase::OwnerDepositWithdraw, OWNER_DEPOSIT_TIME),
+                (Phase::OwnerRedeem, OWNER_REDEEM_TIME),
+            ]),
+            ..Default::default()
+        })
+        .assert_user_error("Invalid timestamp");
+}
+
+#[test]
+fn block_nonce_time_base_test() {
+    let mut setup =
+        PriceDiscSetup::new_with_time_base(price_discovery::contract_obj, TimeBase::BlockNonce);
+
+    // the timestamp is ignored
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_user_error("User deposit/withdraw not allowed in this phase");
+
+    setup.b_mock.set_block_nonce(START_TIME + 1);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.get_time_until_next_phase().into_option(),
+                Some(USER_DEPOSIT_TIME - 1)
+            );
+        })
+        .assert_ok();
+
+    // durations are limited to about one month of blocks
+    setup
+        .call_set_phase_duration(1, MAX_PHASE_DURATION_BLOCKS + 1)
+        .assert_user_error("Invalid timestamp");
+    setup
+        .call_set_phase_duration(1, MAX_PHASE_DURATION_BLOCKS)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_nonce(START_TIME + MAX_PHASE_DURATION_BLOCKS + 1);
+    setup.call_owner_deposit(2_000).assert_ok();
+}
+
+#[test]
+fn block_nonce_phase_pipeline_test() {
+    let mut setup = PriceDiscSetup::new_uninitialized(price_discovery::contract_obj);
+    let init_args = |user_deposit_time| InitArgs {
+        phase_pipeline: Some(vec![
+            (Phase::OwnerDepositWithdraw, OWNER_DEPOSIT_TIME),
+            (Phase::Cooldown, 10),
+            (Phase::UserDepositWithdraw, user_deposit_time),
+            (Phase::OwnerRedeem, OWNER_REDEEM_TIME),
+        ]),
+        time_base: Some(TimeBase::BlockNonce),
+        ..Default::default()
+    };
+
+    // the durations are checked against the block nonce limit
+    setup
+        .call_init(&init_args(MAX_PHASE_DURATION_BLOCKS + 1))
+        .assert_user_error("Invalid timestamp");
+    setup
+        .call_init(&init_args(MAX_PHASE_DURATION_BLOCKS))
+        .assert_ok();
+
+    let first_user_address = setup.first_user_address.clone();
+    setup
+        .call_set_min_launched_tokens(MIN_LAUNCHED_TOKENS)
+        .assert_ok();
+    setup
+        .call_add_user_to_whitelist(&first_user_address, 0)
+        .assert_ok();
+
+    setup.b_mock.set_block_nonce(START_TIME + 1);
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    // the timestamp is ignored
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + OWNER_DEPOSIT_TIME + 10);
+    setup
+        .call_user_deposit(&first_user_address, 1_000)
+        .assert_user_error("User deposit/withdraw not allowed in this phase");
+
+    setup
+        .b_mock
+        .set_block_nonce(START_TIME + OWNER_DEPOSIT_TIME + 10);
+    setup
+        .call_user_deposit(&first_user_address, 1_000)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.get_time_until_next_phase().into_option(),
+                Some(MAX_PHASE_DURATION_BLOCKS)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn close_user_phase_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .call_close_user_phase()
+        .assert_user_error("No target raise set");
+
+    let result = setup.call_set_target_raise(5_000);
+    result.assert_ok();
+    let target_raise: BigUint<DebugApi> =
+        get_event_data(&result, "setTargetRaiseEvent", &setup.owner_address);
+    assert_eq!(target_raise, managed_biguint!(5_000));
+    setup
+        .call_close_user_phase()
+        .assert_user_error("Target raise not reached");
+
+    setup.b_mock.set_block_timestamp(START_TIME + 20);
+    setup
+        .call_user_deposit(&setup.second_user_address.clone(), 4_000)
+        .assert_ok();
+    let result = setup.call_close_user_phase();
+    result.assert_ok();
+    let user_deposit_withdraw_time: Timestamp =
+        get_event_data(&result, "closeUserPhaseEvent", &setup.owner_address);
+    assert_eq!(user_deposit_withdraw_time, 20);
+
+    // the owner phase starts right away, and all the other phases move with it
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_user_error("User deposit/withdraw not allowed in this phase");
+    setup.call_owner_deposit(2_000).assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert!(sc.get_current_phase() == Phase::OwnerDepositWithdraw);
+            assert_eq!(sc.get_user_deposit_withdraw_time(), 20);
+        })
+        .assert_ok();
+    setup
+        .call_close_user_phase()
+        .assert_user_error("Invalid current phase");
+    setup
+        .call_set_target_raise(0)
+        .assert_user_error("Invalid current phase");
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + 20 + OWNER_DEPOSIT_TIME);
+    setup.call_owner_redeem().assert_ok();
+}
+
+#[test]
+fn set_start_time_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let new_start_time = START_TIME + 50;
+
+    setup
+        .call_set_start_time(START_TIME - 1)
+        .assert_user_error("Start time cannot be in the past");
+    let result = setup.call_set_start_time(new_start_time);
+    result.assert_ok();
+    let start_time: Timestamp = get_event_data(&result, "setStartTimeEvent", &setup.owner_address);
+    assert_eq!(start_time, new_start_time);
+    setup
+        .call_set_user_deposit_withdraw_timestamp(USER_DEPOSIT_TIME + 10)
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_user_error("User deposit/withdraw not allowed in this phase");
+
+    setup.b_mock.set_block_timestamp(new_start_time);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .call_set_start_time(new_start_time + 100)
+        .assert_user_error("Invalid current phase");
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.start_time().get(), new_start_time);
+            assert_eq!(
+                sc.get_user_deposit_withdraw_end(),
+                new_start_time + USER_DEPOSIT_TIME + 10
+            );
+        })
+        .assert_ok();
+}
@@ -1,7 +1,14 @@
 #![allow(deprecated)]
 
 mod tests_common;
-use multiversx_sc_scenario::{managed_biguint, rust_biguint};
+use multiversx_sc::types::BigUint;
+use multiversx_sc_scenario::{
+    managed_address, managed_biguint, managed_token_id, rust_biguint, DebugApi,
+};
+use price_discovery::events::{
+    FinalizeSaleEvent, RefundUserEvent, UserDepositEvent, UserWithdrawEvent, WhitelistUserEvent,
+    WithdrawLaunchpadTokensEvent,
+};
 use price_discovery::user_actions::user_deposit_withdraw::UserDepositWithdrawModule;
 use tests_common::*;
 
@@ -323,7 +330,19 @@ fn user_redeem_owner_forgot_to_withdraw_test() {
     );
 
     // owner later withdraws his tokens
-    setup.call_owner_withdraw_launchpad_tokens().assert_ok();
+    let result = setup.call_owner_withdraw_launchpad_tokens();
+    result.assert_ok();
+
+    let event: WithdrawLaunchpadTokensEvent<DebugApi> = get_event_data(
+        &result,
+        "withdrawLaunchpadTokensEvent",
+        &setup.owner_address,
+    );
+    assert_eq!(
+        event.launched_token_id,
+        managed_token_id!(LAUNCHED_TOKEN_ID)
+    );
+    assert_eq!(event.launched_token_amount, managed_biguint!(2_000));
 
     setup.b_mock.check_esdt_balance(
         &setup.owner_address,
@@ -360,7 +379,14 @@ fn user_redeem_ok_test() {
         .b_mock
         .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + 1);
 
-    setup.call_owner_redeem().assert_ok();
+    let result = setup.call_owner_redeem();
+    result.assert_ok();
+
+    let event: FinalizeSaleEvent<DebugApi> =
+        get_event_data(&result, "finalizeSaleEvent", &setup.owner_address);
+    assert_eq!(event.beneficiary, managed_address!(&setup.owner_address));
+    assert_eq!(event.accepted_token_amount, managed_biguint!(10_000));
+    assert_eq!(event.unsold_launched_tokens, managed_biguint!(0));
 
     // owner try withdraw twice
     setup
@@ -526,6 +552,81 @@ fn refund_user_test() {
     );
 }
 
+#[test]
+fn whitelist_and_min_launched_tokens_events_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let new_user = setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    let result = setup.call_add_user_to_whitelist(&new_user, 5_000);
+    result.assert_ok();
+
+    let event: WhitelistUserEvent<DebugApi> =
+        get_event_data(&result, "whitelistUserEvent", &setup.owner_address);
+    assert_eq!(event.user, managed_address!(&new_user));
+    assert_eq!(event.deposit_limit, managed_biguint!(5_000));
+
+    let result = setup.call_set_min_launched_tokens(2_000);
+    result.assert_ok();
+
+    let min_launched_tokens: BigUint<DebugApi> =
+        get_event_data(&result, "setMinLaunchedTokensEvent", &setup.owner_address);
+    assert_eq!(min_launched_tokens, managed_biguint!(2_000));
+}
+
+#[test]
+fn user_deposit_withdraw_refund_events_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let first_user = setup.first_user_address.clone();
+    let second_user = setup.second_user_address.clone();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+
+    let result = setup.call_user_deposit(&first_user, 1_000);
+    result.assert_ok();
+
+    let event: UserDepositEvent<DebugApi> =
+        get_event_data(&result, "userDepositEvent", &first_user);
+    assert_eq!(event.token_amount_in, managed_biguint!(1_000));
+    assert_eq!(event.accepted_token_amount, managed_biguint!(1_000));
+    assert_eq!(event.user_total_deposit, managed_biguint!(1_000));
+    assert_eq!(event.launched_token_amount, managed_biguint!(0));
+    assert_eq!(event.implied_price, None);
+
+    setup.call_user_deposit(&second_user, 3_000).assert_ok();
+
+    let result = setup.call_user_withdraw(&first_user, 400);
+    result.assert_ok();
+
+    let event: UserWithdrawEvent<DebugApi> =
+        get_event_data(&result, "userWithdrawEvent", &first_user);
+    assert_eq!(event.token_amount_out, managed_biguint!(400));
+    assert_eq!(event.accepted_token_amount, managed_biguint!(3_600));
+    assert_eq!(event.user_total_deposit, managed_biguint!(600));
+    assert_eq!(event.launched_token_amount, managed_biguint!(0));
+    assert_eq!(event.implied_price, None);
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    let result = setup.call_refund_user(&first_user);
+    result.assert_ok();
+
+    // 3_000 accepted tokens for 2_000 launched tokens, with 18 decimals
+    let event: RefundUserEvent<DebugApi> =
+        get_event_data(&result, "refundUserEvent", &setup.owner_address);
+    assert_eq!(event.user, managed_address!(&first_user));
+    assert_eq!(event.accepted_token_amount, managed_biguint!(3_000));
+    assert_eq!(event.refunded_amount, managed_biguint!(600));
+    assert_eq!(event.user_total_deposit, managed_biguint!(0));
+    assert_eq!(event.launched_token_amount, managed_biguint!(2_000));
+    assert_eq!(
+        event.implied_price,
+        Some(BigUint::from(1_500_000_000_000_000_000u128))
+    );
+}
+
 #[test]
 fn set_user_limit_test() {
     let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
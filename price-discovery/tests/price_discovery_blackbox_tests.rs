@@ -0,0 +1,280 @@
+use std::collections::BTreeSet;
+
+use multiversx_sc_scenario::imports::*;
+use price_discovery::{
+    price_discovery_proxy::{self, Phase, PhaseDefinition, SaleMode, TimeBase},
+    Timestamp,
+};
+
+const CODE_PATH: MxscPath = MxscPath::new("output/price-discovery.mxsc.json");
+const WASM_LIB_PATH: &str = "wasm/src/lib.rs";
+const PROXY_PATH: &str = "src/price_discovery_proxy.rs";
+
+const OWNER_ADDRESS: TestAddress = TestAddress::new("owner");
+const FIRST_USER_ADDRESS: TestAddress = TestAddress::new("first-user");
+const SECOND_USER_ADDRESS: TestAddress = TestAddress::new("second-user");
+const PRICE_DISCOVERY_ADDRESS: TestSCAddress = TestSCAddress::new("price-discovery");
+
+const LAUNCHED_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("SOCOOLWOW-123456");
+const ACCEPTED_TOKEN_ID: TestTokenIdentifier = TestTokenIdentifier::new("USDC-123456");
+const USER_BALANCE: u64 = 1_000_000_000;
+
+const START_TIME: Timestamp = 10;
+const USER_DEPOSIT_TIME: Timestamp = 100;
+const OWNER_DEPOSIT_TIME: Timestamp = 100;
+const OWNER_REDEEM_TIME: Timestamp = 100;
+const MIN_LAUNCHED_TOKENS: u64 = 1_000;
+const SECOND_USER_LIMIT: u64 = 10_000;
+
+fn world() -> ScenarioWorld {
+    let mut blockchain = ScenarioWorld::new();
+    blockchain.register_contract(CODE_PATH, price_discovery::ContractBuilder);
+    blockchain
+}
+
+struct PriceDiscoveryTestState {
+    world: ScenarioWorld,
+}
+
+impl PriceDiscoveryTestState {
+    fn new() -> Self {
+        let mut world = world();
+
+        world
+            .account(OWNER_ADDRESS)
+            .nonce(1)
+            .esdt_balance(LAUNCHED_TOKEN_ID, USER_BALANCE);
+        world
+            .account(FIRST_USER_ADDRESS)
+            .nonce(1)
+            .esdt_balance(ACCEPTED_TOKEN_ID, USER_BALANCE);
+        world
+            .account(SECOND_USER_ADDRESS)
+            .nonce(1)
+            .esdt_balance(ACCEPTED_TOKEN_ID, USER_BALANCE);
+        world.current_block().block_timestamp(START_TIME - 1);
+
+        Self { world }
+    }
+
+    fn deploy(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .init(
+                LAUNCHED_TOKEN_ID,
+                EgldOrEsdtTokenIdentifier::esdt(ACCEPTED_TOKEN_ID),
+                18u32,
+                START_TIME,
+                USER_DEPOSIT_TIME,
+                OWNER_DEPOSIT_TIME,
+                OWNER_REDEEM_TIME,
+                100u64,
+                OWNER_ADDRESS,
+                OptionalValue::<SaleMode<StaticApi>>::None,
+                OptionalValue::<ManagedVec<StaticApi, PhaseDefinition>>::None,
+                OptionalValue::<TimeBase>::None,
+            )
+            .code(CODE_PATH)
+            .new_address(PRICE_DISCOVERY_ADDRESS)
+            .run();
+
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(PRICE_DISCOVERY_ADDRESS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .set_min_launched_tokens(MIN_LAUNCHED_TOKENS)
+            .run();
+
+        let mut whitelist = MultiValueEncoded::new();
+        whitelist.push(MultiValue2::from((
+            FIRST_USER_ADDRESS.to_managed_address(),
+            BigUint::zero(),
+        )));
+        whitelist.push(MultiValue2::from((
+            SECOND_USER_ADDRESS.to_managed_address(),
+            BigUint::from(SECOND_USER_LIMIT),
+        )));
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(PRICE_DISCOVERY_ADDRESS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .add_users_to_whitelist(whitelist)
+            .run();
+    }
+
+    fn user_deposit(&mut self, user: TestAddress, amount: u64) {
+        self.world
+            .tx()
+            .from(user)
+            .to(PRICE_DISCOVERY_ADDRESS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .user_deposit(OptionalValue::<BigUint<StaticApi>>::None)
+            .payment(TestEsdtTransfer(ACCEPTED_TOKEN_ID, 0, amount))
+            .run();
+    }
+
+    fn user_deposit_error(&mut self, user: TestAddress, amount: u64, err_message: &str) {
+        self.world
+            .tx()
+            .from(user)
+            .to(PRICE_DISCOVERY_ADDRESS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .user_deposit(OptionalValue::<BigUint<StaticApi>>::None)
+            .payment(TestEsdtTransfer(ACCEPTED_TOKEN_ID, 0, amount))
+            .returns(ExpectError(4, err_message))
+            .run();
+    }
+
+    fn owner_deposit(&mut self, amount: u64) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(PRICE_DISCOVERY_ADDRESS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .owner_deposit()
+            .payment(TestEsdtTransfer(LAUNCHED_TOKEN_ID, 0, amount))
+            .run();
+    }
+
+    fn owner_redeem(&mut self) {
+        self.world
+            .tx()
+            .from(OWNER_ADDRESS)
+            .to(PRICE_DISCOVERY_ADDRESS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .owner_redeem_endpoint()
+            .run();
+    }
+
+    fn user_redeem(&mut self, user: TestAddress) {
+        self.world
+            .tx()
+            .from(user)
+            .to(PRICE_DISCOVERY_ADDRESS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .user_redeem_endpoint()
+            .run();
+    }
+
+    fn check_current_phase(&mut self, expected_phase: Phase) {
+        self.world
+            .query()
+            .to(PRICE_DISCOVERY_ADDRESS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_current_phase()
+            .returns(ExpectValue(expected_phase))
+            .run();
+    }
+
+    fn check_total_deposit(&mut self, user: TestAddress, expected_deposit: u64) {
+        self.world
+            .query()
+            .to(PRICE_DISCOVERY_ADDRESS)
+            .typed(price_discovery_proxy::PriceDiscoveryProxy)
+            .get_total_deposit_by_user(user)
+            .returns(ExpectValue(expected_deposit))
+            .run();
+    }
+}
+
+/// The endpoint names of the wasm crate, without `init` and `upgrade`
+fn wasm_endpoint_names() -> BTreeSet<String> {
+    let wasm_lib = std::fs::read_to_string(WASM_LIB_PATH).unwrap();
+    let endpoints_start = wasm_lib.find("endpoints!").unwrap();
+    wasm_lib[endpoints_start..]
+        .lines()
+        .filter_map(|line| line.split_once(" => "))
+        .map(|(name, _)| name.trim().to_string())
+        .filter(|name| name != "init" && name != "upgrade")
+        .collect()
+}
+
+fn proxy_endpoint_names() -> BTreeSet<String> {
+    let proxy = std::fs::read_to_string(PROXY_PATH).unwrap();
+    proxy
+        .split(".raw_call(\"")
+        .skip(1)
+        .map(|rest| rest.split('"').next().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn proxy_matches_wasm_endpoints_test() {
+    let wasm_endpoints = wasm_endpoint_names();
+    assert!(!wasm_endpoints.is_empty());
+    assert_eq!(proxy_endpoint_names(), wasm_endpoints);
+}
+
+#[test]
+fn blackbox_deploy_test() {
+    let mut state = PriceDiscoveryTestState::new();
+    state.deploy();
+
+    state.check_current_phase(Phase::Idle);
+    state.user_deposit_error(
+        FIRST_USER_ADDRESS,
+        1_000,
+        "User deposit/withdraw not allowed in this phase",
+    );
+}
+
+#[test]
+fn blackbox_full_sale_test() {
+    let mut state = PriceDiscoveryTestState::new();
+    state.deploy();
+
+    state.world.current_block().block_timestamp(START_TIME);
+    state.check_current_phase(Phase::UserDepositWithdraw);
+
+    state.user_deposit(FIRST_USER_ADDRESS, 1_000);
+    state.user_deposit(SECOND_USER_ADDRESS, 3_000);
+    state.user_deposit_error(
+        SECOND_USER_ADDRESS,
+        SECOND_USER_LIMIT,
+        "Exceeded deposit limit",
+    );
+    state.check_total_deposit(FIRST_USER_ADDRESS, 1_000);
+    state.check_total_deposit(SECOND_USER_ADDRESS, 3_000);
+
+    state
+        .world
+        .current_block()
+        .block_timestamp(START_TIME + USER_DEPOSIT_TIME);
+    state.check_current_phase(Phase::OwnerDepositWithdraw);
+    state.owner_deposit(2_000);
+
+    state
+        .world
+        .current_block()
+        .block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME);
+    state.check_current_phase(Phase::OwnerRedeem);
+    state.owner_redeem();
+
+    state
+        .world
+        .current_block()
+        .block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME);
+    state.check_current_phase(Phase::UserRedeem);
+    state.user_redeem(FIRST_USER_ADDRESS);
+    state.user_redeem(SECOND_USER_ADDRESS);
+
+    state
+        .world
+        .check_account(OWNER_ADDRESS)
+        .esdt_balance(LAUNCHED_TOKEN_ID, USER_BALANCE - 2_000)
+        .esdt_balance(ACCEPTED_TOKEN_ID, 4_000);
+    state
+        .world
+        .check_account(FIRST_USER_ADDRESS)
+        .esdt_balance(LAUNCHED_TOKEN_ID, 500)
+        .esdt_balance(ACCEPTED_TOKEN_ID, USER_BALANCE - 1_000);
+    state
+        .world
+        .check_account(SECOND_USER_ADDRESS)
+        .esdt_balance(LAUNCHED_TOKEN_ID, 1_500)
+        .esdt_balance(ACCEPTED_TOKEN_ID, USER_BALANCE - 3_000);
+}
@@ -0,0 +1,118 @@
+#![allow(deprecated)]
+
+mod tests_common;
+use multiversx_sc_scenario::managed_biguint;
+use price_discovery::price_history::{PriceHistoryModule, MAX_PRICE_CHECKPOINTS};
+use tests_common::*;
+
+#[test]
+fn price_history_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+
+    setup.b_mock.set_block_timestamp(START_TIME + 10);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+
+    // checkpoints in the same block are merged
+    setup.b_mock.set_block_timestamp(START_TIME + 60);
+    setup
+        .call_user_deposit(&setup.second_user_address.clone(), 9_000)
+        .assert_ok();
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 500)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let history: Vec<_> = sc.get_price_history(0, 100).into_iter().collect();
+            assert_eq!(history.len(), 2);
+
+            assert_eq!(history[0].timestamp, START_TIME + 10);
+            assert_eq!(history[0].accepted_token_balance, managed_biguint!(1_000));
+            assert_eq!(history[0].cumulative_accepted_balance, managed_biguint!(0));
+
+            assert_eq!(history[1].timestamp, START_TIME + 60);
+            assert_eq!(history[1].accepted_token_balance, managed_biguint!(10_500));
+            assert_eq!(
+                history[1].cumulative_accepted_balance,
+                managed_biguint!(50_000)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let history: Vec<_> = sc
+                .get_price_history(START_TIME + 61, START_TIME + 200)
+                .into_iter()
+                .collect();
+            assert_eq!(history.len(), 1);
+            assert_eq!(history[0].launched_token_balance, managed_biguint!(2_000));
+
+            // (1_000 * 50 + 10_500 * 40) / 100 = 4_700 accepted tokens on average
+            assert_eq!(
+                sc.get_time_weighted_average_price().into_option(),
+                Some(managed_biguint!(2_350_000_000_000_000_000u64))
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn price_history_bounded_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+
+    setup
+        .call_set_user_deposit_withdraw_timestamp(1_000)
+        .assert_ok();
+
+    let nr_deposits = MAX_PRICE_CHECKPOINTS as u64 + 5;
+    for i in 0..nr_deposits {
+        setup.b_mock.set_block_timestamp(START_TIME + i);
+        setup
+            .call_user_deposit(&setup.first_user_address.clone(), 100)
+            .assert_ok();
+    }
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let history: Vec<_> = sc.get_price_history(0, u64::MAX).into_iter().collect();
+            assert_eq!(history.len(), MAX_PRICE_CHECKPOINTS);
+            assert_eq!(history[0].timestamp, START_TIME + 5);
+            assert_eq!(
+                history[MAX_PRICE_CHECKPOINTS - 1].timestamp,
+                START_TIME + nr_deposits - 1
+            );
+
+            // the recent history is still available
+            assert_eq!(
+                sc.get_time_weighted_accepted_balance(START_TIME + 5, START_TIME + 7),
+                managed_biguint!(650)
+            );
+
+            // the phase average is anchored on the snapshot taken at the phase start
+            assert_eq!(
+                sc.get_user_phase_average_accepted_balance(),
+                managed_biguint!(25_250)
+            );
+            assert!(sc.get_time_weighted_average_price().is_none());
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let _ = sc.get_time_weighted_accepted_balance(START_TIME, START_TIME + 7);
+        })
+        .assert_user_error("Price history not available");
+}
@@ -0,0 +1,295 @@
+#![allow(deprecated)]
+
+mod tests_common;
+use multiversx_sc::types::ManagedAddress;
+use multiversx_sc_scenario::{managed_address, managed_biguint, rust_biguint, DebugApi};
+use price_discovery::{
+    common_storage::CommonStorageModule,
+    events::RoleEvent,
+    phase::PhaseModule,
+    roles::{Role, RolesModule},
+    user_actions::{admin_actions::AdminActionsModule, redeem::RedeemModule},
+};
+use tests_common::*;
+
+#[test]
+fn scoped_roles_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let timing_manager = setup.first_user_address.clone();
+
+    setup
+        .b_mock
+        .execute_tx(
+            &timing_manager,
+            &setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_user_deposit_withdraw_time(USER_DEPOSIT_TIME + 10);
+            },
+        )
+        .assert_user_error("Permission denied");
+
+    let result = setup.call_grant_role(Role::TimingManager, &timing_manager);
+    result.assert_ok();
+    let event: RoleEvent<DebugApi> =
+        get_event_data(&result, "grantRoleEvent", &setup.owner_address);
+    assert!(event.role == Role::TimingManager);
+    assert_eq!(event.address, managed_address!(&timing_manager));
+
+    setup
+        .call_grant_role(Role::TimingManager, &timing_manager)
+        .assert_user_error("Address already has this role");
+
+    setup
+        .b_mock
+        .execute_tx(
+            &timing_manager,
+            &setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_user_deposit_withdraw_time(USER_DEPOSIT_TIME + 10);
+            },
+        )
+        .assert_ok();
+
+    // the role does not give access to the other endpoints
+    setup
+        .b_mock
+        .execute_tx(
+            &timing_manager,
+            &setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_user_limit(managed_address!(&timing_manager), managed_biguint!(5_000));
+            },
+        )
+        .assert_user_error("Permission denied");
+    setup
+        .b_mock
+        .execute_tx(
+            &timing_manager,
+            &setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_min_launched_tokens(managed_biguint!(5_000));
+            },
+        )
+        .assert_user_error("Only admin may call this function");
+    setup
+        .b_mock
+        .execute_tx(
+            &timing_manager,
+            &setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.grant_role(Role::Pauser, managed_address!(&timing_manager));
+            },
+        )
+        .assert_user_error("Only admin may call this function");
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_user_deposit_withdraw_time(), USER_DEPOSIT_TIME + 10);
+
+            let holders: Vec<_> = sc
+                .get_role_holders(Role::TimingManager)
+                .into_iter()
+                .collect();
+            assert_eq!(holders, vec![managed_address!(&timing_manager)]);
+            assert_eq!(sc.get_role_holders(Role::Pauser).len(), 0);
+        })
+        .assert_ok();
+
+    setup
+        .call_revoke_role(Role::TimingManager, &timing_manager)
+        .assert_ok();
+    setup
+        .call_revoke_role(Role::TimingManager, &timing_manager)
+        .assert_user_error("Address does not have this role");
+    setup
+        .b_mock
+        .execute_tx(
+            &timing_manager,
+            &setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_user_deposit_withdraw_time(USER_DEPOSIT_TIME + 20);
+            },
+        )
+        .assert_user_error("Permission denied");
+}
+
+#[test]
+fn pause_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let pauser = setup.second_user_address.clone();
+    setup.call_grant_role(Role::Pauser, &pauser).assert_ok();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+
+    let result = setup
+        .b_mock
+        .execute_tx(&pauser, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            sc.pause();
+        });
+    result.assert_ok();
+    let log = result
+        .result_logs
+        .iter()
+        .find(|log| log.topics[0] == b"pauseEvent")
+        .unwrap();
+    assert_eq!(log.topics[1], pauser.as_bytes());
+
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_user_error("Contract is paused");
+    setup
+        .call_user_withdraw(&setup.first_user_address.clone(), 1_000)
+        .assert_user_error("Contract is paused");
+
+    setup
+        .b_mock
+        .execute_tx(&pauser, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            sc.unpause();
+        })
+        .assert_ok();
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+
+    // the owner phase may not be blocked by pausing
+    setup
+        .b_mock
+        .execute_tx(&pauser, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            sc.pause();
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+    setup.call_owner_deposit(3_000).assert_ok();
+    setup.call_owner_withdraw(1_000).assert_ok();
+}
+
+#[test]
+fn admin_transfer_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let new_admin = setup.first_user_address.clone();
+    let other_user = setup.second_user_address.clone();
+
+    setup
+        .b_mock
+        .execute_tx(&new_admin, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            sc.accept_admin();
+        })
+        .assert_user_error("Only the proposed admin may accept");
+    let result =
+        setup
+            .b_mock
+            .execute_tx(&owner_address, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+                sc.propose_admin(managed_address!(&new_admin));
+            });
+    result.assert_ok();
+    let proposed_admin: ManagedAddress<DebugApi> =
+        get_event_data(&result, "proposeAdminEvent", &owner_address);
+    assert_eq!(proposed_admin, managed_address!(&new_admin));
+
+    // the current admin keeps its rights until the new admin accepts
+    setup
+        .b_mock
+        .execute_tx(&other_user, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            sc.accept_admin();
+        })
+        .assert_user_error("Only the proposed admin may accept");
+    setup
+        .call_set_user_deposit_withdraw_timestamp(USER_DEPOSIT_TIME + 10)
+        .assert_ok();
+
+    let result = setup
+        .b_mock
+        .execute_tx(&new_admin, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            sc.accept_admin();
+        });
+    result.assert_ok();
+    let accepted_admin: ManagedAddress<DebugApi> =
+        get_event_data(&result, "acceptAdminEvent", &new_admin);
+    assert_eq!(accepted_admin, managed_address!(&new_admin));
+    setup
+        .call_set_user_deposit_withdraw_timestamp(USER_DEPOSIT_TIME + 20)
+        .assert_user_error("Permission denied");
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.admin().get(), managed_address!(&new_admin));
+            assert!(sc.get_pending_admin().into_option().is_none());
+        })
+        .assert_ok();
+}
+
+#[test]
+fn beneficiary_transfer_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let owner_address = setup.owner_address.clone();
+    let beneficiary = setup.b_mock.create_user_account(&rust_biguint!(0));
+
+    setup
+        .b_mock
+        .execute_tx(&owner_address, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            sc.propose_beneficiary(managed_address!(&beneficiary));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            // not accepted yet
+            assert_eq!(
+                sc.get_owner_redeem_beneficiary(),
+                managed_address!(&owner_address)
+            );
+        })
+        .assert_ok();
+    let result =
+        setup
+            .b_mock
+            .execute_tx(&beneficiary, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+                sc.accept_beneficiary();
+            });
+    result.assert_ok();
+    let accepted_beneficiary: ManagedAddress<DebugApi> =
+        get_event_data(&result, "acceptBeneficiaryEvent", &beneficiary);
+    assert_eq!(accepted_beneficiary, managed_address!(&beneficiary));
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + 1);
+    setup.call_owner_redeem().assert_ok();
+
+    setup
+        .b_mock
+        .check_esdt_balance(&beneficiary, ACCEPTED_TOKEN_ID, &rust_biguint!(1_000));
+    setup
+        .b_mock
+        .check_esdt_balance(&owner_address, ACCEPTED_TOKEN_ID, &rust_biguint!(0));
+
+    setup
+        .b_mock
+        .execute_tx(&owner_address, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            sc.propose_beneficiary(managed_address!(&owner_address));
+        })
+        .assert_user_error("Owner already redeemed");
+}
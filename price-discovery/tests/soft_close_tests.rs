@@ -0,0 +1,215 @@
+#![allow(deprecated)]
+
+mod tests_common;
+use price_discovery::{
+    phase::{PhaseModule, MAX_PHASE_DURATION},
+    price_history::MAX_PRICE_CHECKPOINTS,
+    soft_close::SoftCloseConfig,
+    twap_settlement::TwapSettlementModule,
+    Timestamp,
+};
+use tests_common::*;
+
+#[test]
+fn soft_close_extension_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let result = setup.call_set_soft_close_config(10, 20, 30);
+    result.assert_ok();
+    let config: SoftCloseConfig =
+        get_event_data(&result, "setSoftCloseConfigEvent", &setup.owner_address);
+    assert_eq!(config.trigger_window, 10);
+    assert_eq!(config.extension, 20);
+    assert_eq!(config.max_total_extension, 30);
+
+    setup.b_mock.set_block_timestamp(START_TIME + 50);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+
+    // deposits in the last 10 seconds extend the phase by 20 seconds
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME - 5);
+    let second_user = setup.second_user_address.clone();
+    let result = setup.call_user_deposit(&second_user, 1_000);
+    result.assert_ok();
+    let user_deposit_withdraw_time: Timestamp =
+        get_event_data(&result, "extendUserPhaseEvent", &second_user);
+    assert_eq!(user_deposit_withdraw_time, USER_DEPOSIT_TIME + 20);
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_user_deposit_withdraw_time(), USER_DEPOSIT_TIME + 20);
+            assert_eq!(sc.soft_close_total_extension().get(), 20);
+        })
+        .assert_ok();
+
+    // withdrawals do not extend the phase
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 15);
+    setup
+        .call_user_withdraw(&setup.second_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .call_user_deposit(&setup.second_user_address.clone(), 1_000)
+        .assert_ok();
+
+    // only 10 seconds are left from the max total extension
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 25);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_user_deposit_withdraw_time(), USER_DEPOSIT_TIME + 30);
+            assert_eq!(sc.soft_close_total_extension().get(), 30);
+        })
+        .assert_ok();
+
+    // the following phases are moved as well
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 29);
+    setup
+        .call_owner_deposit(2_000)
+        .assert_user_error("Owner deposit/withdraw not allowed in this phase");
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 30);
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 30 + OWNER_DEPOSIT_TIME);
+    setup.call_owner_redeem().assert_ok();
+}
+
+#[test]
+fn soft_close_config_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    setup
+        .call_set_soft_close_config(10, 20, 10)
+        .assert_user_error("Invalid soft close config");
+    setup
+        .call_set_soft_close_config(0, 20, 30)
+        .assert_user_error("Invalid soft close config");
+    setup.call_set_soft_close_config(10, 20, 30).assert_ok();
+    setup.call_set_soft_close_config(0, 0, 0).assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME - 5);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .call_set_soft_close_config(10, 20, 30)
+        .assert_user_error("Invalid current phase");
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_user_deposit_withdraw_time(), USER_DEPOSIT_TIME);
+        })
+        .assert_ok();
+
+    let mut setup = PriceDiscSetup::new_with_sale_mode(
+        price_discovery::contract_obj,
+        SaleModeArgs::DutchAuction {
+            max_price: 2_000_000_000_000_000_000,
+            min_price: 1_000_000_000_000_000_000,
+            price_step_duration: 0,
+        },
+    );
+    setup
+        .call_set_soft_close_config(10, 20, 30)
+        .assert_user_error("Soft close not allowed in this sale mode");
+}
+
+#[test]
+fn soft_close_max_phase_duration_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    setup
+        .call_set_phase_duration(1, MAX_PHASE_DURATION - 15)
+        .assert_ok();
+    setup.call_set_soft_close_config(10, 20, 30).assert_ok();
+
+    // the phase is only extended up to the max phase duration
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + MAX_PHASE_DURATION - 20);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_user_deposit_withdraw_time(), MAX_PHASE_DURATION);
+            assert_eq!(sc.soft_close_total_extension().get(), 15);
+        })
+        .assert_ok();
+
+    // the extension is kept apart from the configured duration
+    setup
+        .call_set_phase_duration(1, MAX_PHASE_DURATION - 10)
+        .assert_user_error("Invalid timestamp");
+    setup
+        .call_set_phase_duration(1, MAX_PHASE_DURATION - 15)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_user_deposit_withdraw_time(), MAX_PHASE_DURATION);
+            assert_eq!(sc.soft_close_total_extension().get(), 15);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn soft_close_twap_history_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let first_user_address = setup.first_user_address.clone();
+    setup.call_set_phase_duration(1, 1_000).assert_ok();
+    setup.call_set_twap_settlement_window(900).assert_ok();
+    setup.call_set_soft_close_config(10, 20, 30).assert_ok();
+
+    // the TWAP window snapshot is taken at START_TIME + 100
+    setup.b_mock.set_block_timestamp(START_TIME + 150);
+    setup
+        .call_user_deposit(&first_user_address, 1_000)
+        .assert_ok();
+
+    // the checkpoints before the moved window start are dropped from the history
+    for offset in 0..=MAX_PRICE_CHECKPOINTS as u64 {
+        setup.b_mock.set_block_timestamp(START_TIME + 400 + offset);
+        setup
+            .call_user_deposit(&first_user_address, 100)
+            .assert_ok();
+    }
+
+    // the extension moves the window start to START_TIME + 120, which is no longer covered
+    setup.b_mock.set_block_timestamp(START_TIME + 995);
+    setup
+        .call_user_deposit(&first_user_address, 1_000)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_user_deposit_withdraw_time(), 1_020);
+            assert_eq!(sc.twap_window_snapshot().get().timestamp, START_TIME + 100);
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1_020);
+    setup.call_owner_deposit(2_000).assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let _ = sc.get_twap_settlement_price();
+        })
+        .assert_ok();
+}
@@ -1,12 +1,18 @@
-#![allow(deprecated)]
+#![allow(deprecated, dead_code)]
 
-use multiversx_sc::types::{Address, MultiValueEncoded};
+use multiversx_sc::codec::multi_types::OptionalValue;
+use multiversx_sc::codec::TopDecode;
+use multiversx_sc::types::{Address, BigUint, ManagedVec, MultiValueEncoded};
 use multiversx_sc_scenario::{
     managed_address, managed_biguint, managed_token_id_wrapped, whitebox_legacy::*,
 };
 use multiversx_sc_scenario::{managed_token_id, rust_biguint, DebugApi};
 
+use price_discovery::board::{ActionId, BoardModule};
 use price_discovery::common_storage::CommonStorageModule;
+use price_discovery::phase::{Phase, PhaseDefinition, TimeBase};
+use price_discovery::roles::{Role, RolesModule};
+use price_discovery::sale_mode::{BondingCurve, DutchAuctionConfig, SaleMode};
 use price_discovery::user_actions::admin_actions::AdminActionsModule;
 use price_discovery::*;
 
@@ -27,6 +33,66 @@ pub const OWNER_DEPOSIT_TIME: Timestamp = 100;
 pub const OWNER_REDEEM_TIME: Timestamp = 100;
 pub const MIN_LAUNCHED_TOKENS: u64 = 1_000;
 
+/// The optional `init` arguments, `None` leaves the argument out
+#[derive(Default)]
+pub struct InitArgs {
+    pub sale_mode: Option<SaleModeArgs>,
+    pub phase_pipeline: Option<Vec<(Phase, Timestamp)>>,
+    pub time_base: Option<TimeBase>,
+}
+
+/// Prices use the same unit as the contract, i.e. they include the price precision
+pub enum SaleModeArgs {
+    Standard,
+    DutchAuction {
+        max_price: u128,
+        min_price: u128,
+        price_step_duration: Timestamp,
+    },
+    LinearBondingCurve {
+        initial_price: u128,
+        slope: u128,
+    },
+    ExponentialBondingCurve {
+        initial_price: u128,
+        growth_step: u64,
+        growth_rate: u64,
+    },
+}
+
+impl SaleModeArgs {
+    pub fn to_sale_mode(&self) -> SaleMode<DebugApi> {
+        match self {
+            SaleModeArgs::Standard => SaleMode::Standard,
+            SaleModeArgs::DutchAuction {
+                max_price,
+                min_price,
+                price_step_duration,
+            } => SaleMode::DutchAuction(DutchAuctionConfig {
+                max_price: BigUint::from(*max_price),
+                min_price: BigUint::from(*min_price),
+                price_step_duration: *price_step_duration,
+            }),
+            SaleModeArgs::LinearBondingCurve {
+                initial_price,
+                slope,
+            } => SaleMode::BondingCurve(BondingCurve::Linear {
+                initial_price: BigUint::from(*initial_price),
+                slope: BigUint::from(*slope),
+            }),
+            SaleModeArgs::ExponentialBondingCurve {
+                initial_price,
+                growth_step,
+                growth_rate,
+            } => SaleMode::BondingCurve(BondingCurve::Exponential {
+                initial_price: BigUint::from(*initial_price),
+                growth_step: BigUint::from(*growth_step),
+                growth_rate: *growth_rate,
+            }),
+        }
+    }
+}
+
 pub struct PriceDiscSetup<PriceDiscObjBuilder>
 where
     PriceDiscObjBuilder: 'static + Copy + Fn() -> price_discovery::ContractObj<DebugApi>,
@@ -43,6 +109,77 @@ where
     PriceDiscObjBuilder: 'static + Copy + Fn() -> price_discovery::ContractObj<DebugApi>,
 {
     pub fn new(pd_builder: PriceDiscObjBuilder) -> Self {
+        Self::new_with_init_args(pd_builder, InitArgs::default())
+    }
+
+    pub fn new_with_time_base(pd_builder: PriceDiscObjBuilder, time_base: TimeBase) -> Self {
+        Self::new_with_init_args(
+            pd_builder,
+            InitArgs {
+                time_base: Some(time_base),
+                ..Default::default()
+            },
+        )
+    }
+
+    pub fn new_with_sale_mode(pd_builder: PriceDiscObjBuilder, sale_mode: SaleModeArgs) -> Self {
+        Self::new_with_init_args(
+            pd_builder,
+            InitArgs {
+                sale_mode: Some(sale_mode),
+                ..Default::default()
+            },
+        )
+    }
+
+    pub fn new_with_phase_pipeline(
+        pd_builder: PriceDiscObjBuilder,
+        phase_pipeline: &[(Phase, Timestamp)],
+    ) -> Self {
+        Self::new_with_init_args(
+            pd_builder,
+            InitArgs {
+                phase_pipeline: Some(phase_pipeline.to_vec()),
+                ..Default::default()
+            },
+        )
+    }
+
+    pub fn new_with_init_args(pd_builder: PriceDiscObjBuilder, init_args: InitArgs) -> Self {
+        let mut setup = Self::new_uninitialized(pd_builder);
+        setup.call_init(&init_args).assert_ok();
+
+        let first_user_address = setup.first_user_address.clone();
+        let second_user_address = setup.second_user_address.clone();
+        setup
+            .b_mock
+            .execute_tx(
+                &setup.owner_address,
+                &setup.pd_wrapper,
+                &rust_biguint!(0),
+                |sc| {
+                    sc.min_launched_tokens()
+                        .set(managed_biguint!(MIN_LAUNCHED_TOKENS));
+
+                    let mut pairs = MultiValueEncoded::new();
+                    pairs.push((managed_address!(&first_user_address), managed_biguint!(0)).into());
+                    pairs.push(
+                        (
+                            managed_address!(&second_user_address),
+                            managed_biguint!(10_000),
+                        )
+                            .into(),
+                    );
+                    sc.add_users_to_whitelist(pairs);
+                },
+            )
+            .assert_ok();
+
+        setup
+    }
+
+    /// Creates the accounts and the contract, without calling `init`
+    pub fn new_uninitialized(pd_builder: PriceDiscObjBuilder) -> Self {
         let rust_zero = rust_biguint!(0u64);
         let mut b_mock = BlockchainStateWrapper::new();
         let first_user_address = b_mock.create_user_account(&rust_zero);
@@ -71,10 +208,46 @@ where
         );
 
         b_mock.set_block_timestamp(START_TIME - 1);
+        b_mock.set_block_nonce(START_TIME - 1);
+
+        PriceDiscSetup {
+            b_mock,
+            owner_address,
+            first_user_address,
+            second_user_address,
+            pd_wrapper,
+        }
+    }
+
+    pub fn call_init(&mut self, init_args: &InitArgs) -> TxResult {
+        let owner_address = self.owner_address.clone();
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let opt_sale_mode = match &init_args.sale_mode {
+                    Some(sale_mode) => OptionalValue::Some(sale_mode.to_sale_mode()),
+                    None => OptionalValue::None,
+                };
+                let opt_phase_pipeline = match &init_args.phase_pipeline {
+                    Some(phases) => {
+                        let mut pipeline = ManagedVec::new();
+                        for (kind, duration) in phases {
+                            pipeline.push(PhaseDefinition {
+                                kind: kind.clone(),
+                                duration: *duration,
+                            });
+                        }
+                        OptionalValue::Some(pipeline)
+                    }
+                    None => OptionalValue::None,
+                };
+                let opt_time_base = match &init_args.time_base {
+                    Some(time_base) => OptionalValue::Some(*time_base),
+                    None => OptionalValue::None,
+                };
 
-        // init Price Discovery SC
-        b_mock
-            .execute_tx(&owner_address, &pd_wrapper, &rust_zero, |sc| {
                 sc.init(
                     managed_token_id!(LAUNCHED_TOKEN_ID),
                     managed_token_id_wrapped!(ACCEPTED_TOKEN_ID),
@@ -85,31 +258,12 @@ where
                     OWNER_REDEEM_TIME,
                     managed_biguint!(100),
                     managed_address!(&owner_address),
+                    opt_sale_mode,
+                    opt_phase_pipeline,
+                    opt_time_base,
                 );
-
-                sc.min_launched_tokens()
-                    .set(managed_biguint!(MIN_LAUNCHED_TOKENS));
-
-                let mut pairs = MultiValueEncoded::new();
-                pairs.push((managed_address!(&first_user_address), managed_biguint!(0)).into());
-                pairs.push(
-                    (
-                        managed_address!(&second_user_address),
-                        managed_biguint!(10_000),
-                    )
-                        .into(),
-                );
-                sc.add_users_to_whitelist(pairs);
-            })
-            .assert_ok();
-
-        PriceDiscSetup {
-            b_mock,
-            owner_address,
-            first_user_address,
-            second_user_address,
-            pd_wrapper,
-        }
+            },
+        )
     }
 
     pub fn call_user_deposit(&mut self, user: &Address, amount: u64) -> TxResult {
@@ -120,7 +274,25 @@ where
             0,
             &rust_biguint!(amount),
             |sc| {
-                sc.user_deposit();
+                sc.user_deposit(OptionalValue::None);
+            },
+        )
+    }
+
+    pub fn call_user_deposit_with_max_price(
+        &mut self,
+        user: &Address,
+        amount: u64,
+        max_price: u128,
+    ) -> TxResult {
+        self.b_mock.execute_esdt_transfer(
+            user,
+            &self.pd_wrapper,
+            ACCEPTED_TOKEN_ID,
+            0,
+            &rust_biguint!(amount),
+            |sc| {
+                sc.user_deposit(OptionalValue::Some(BigUint::from(max_price)));
             },
         )
     }
@@ -199,6 +371,17 @@ where
         )
     }
 
+    pub fn call_set_start_time(&mut self, start_time: Timestamp) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_start_time(start_time);
+            },
+        )
+    }
+
     pub fn call_set_user_deposit_withdraw_timestamp(&mut self, timestamp: Timestamp) -> TxResult {
         self.b_mock.execute_tx(
             &self.owner_address,
@@ -210,6 +393,143 @@ where
         )
     }
 
+    pub fn call_set_twap_settlement_window(&mut self, window: Timestamp) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_twap_settlement_window(window);
+            },
+        )
+    }
+
+    pub fn call_set_phase_duration(&mut self, phase_index: usize, duration: Timestamp) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_phase_duration(phase_index, duration);
+            },
+        )
+    }
+
+    pub fn call_add_cooldown_phase(&mut self, phase_index: usize, duration: Timestamp) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.add_cooldown_phase(phase_index, duration);
+            },
+        )
+    }
+
+    pub fn call_remove_cooldown_phase(&mut self, phase_index: usize) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.remove_cooldown_phase(phase_index);
+            },
+        )
+    }
+
+    pub fn call_set_soft_close_config(
+        &mut self,
+        trigger_window: Timestamp,
+        extension: Timestamp,
+        max_total_extension: Timestamp,
+    ) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_soft_close_config(trigger_window, extension, max_total_extension);
+            },
+        )
+    }
+
+    pub fn call_set_target_raise(&mut self, target_raise: u64) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_target_raise(managed_biguint!(target_raise));
+            },
+        )
+    }
+
+    pub fn call_close_user_phase(&mut self) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.close_user_phase();
+            },
+        )
+    }
+
+    pub fn call_set_timelock_delay(&mut self, delay: Timestamp) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_timelock_delay(delay);
+            },
+        )
+    }
+
+    pub fn call_execute_change(&mut self, id: u64) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_change(id);
+            },
+        )
+    }
+
+    pub fn call_cancel_change(&mut self, id: u64) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.cancel_change(id);
+            },
+        )
+    }
+
+    pub fn call_grant_role(&mut self, role: Role, address: &Address) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.grant_role(role, managed_address!(address));
+            },
+        )
+    }
+
+    pub fn call_revoke_role(&mut self, role: Role, address: &Address) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.revoke_role(role, managed_address!(address));
+            },
+        )
+    }
+
     pub fn call_owner_withdraw_launchpad_tokens(&mut self) -> TxResult {
         self.b_mock.execute_tx(
             &self.owner_address,
@@ -220,4 +540,73 @@ where
             },
         )
     }
+
+    pub fn call_set_min_launched_tokens(&mut self, min_launched_tokens: u64) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.set_min_launched_tokens(managed_biguint!(min_launched_tokens));
+            },
+        )
+    }
+
+    pub fn call_add_user_to_whitelist(&mut self, user: &Address, limit: u64) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut pairs = MultiValueEncoded::new();
+                pairs.push((managed_address!(user), managed_biguint!(limit)).into());
+                sc.add_users_to_whitelist(pairs);
+            },
+        )
+    }
+
+    pub fn call_set_board(&mut self, quorum: usize, members: &[&Address]) -> TxResult {
+        self.b_mock.execute_tx(
+            &self.owner_address,
+            &self.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                let mut args = MultiValueEncoded::new();
+                for member in members {
+                    args.push(managed_address!(*member));
+                }
+
+                sc.set_board(quorum, args);
+            },
+        )
+    }
+
+    pub fn call_sign_action(&mut self, member: &Address, action_id: ActionId) -> TxResult {
+        self.b_mock
+            .execute_tx(member, &self.pd_wrapper, &rust_biguint!(0), |sc| {
+                sc.sign_action(action_id);
+            })
+    }
+
+    pub fn call_perform_action(&mut self, member: &Address, action_id: ActionId) -> TxResult {
+        self.b_mock
+            .execute_tx(member, &self.pd_wrapper, &rust_biguint!(0), |sc| {
+                sc.perform_action(action_id);
+            })
+    }
+}
+
+/// Decodes the data of the last `identifier` event logged by the transaction,
+/// after checking that it was emitted for `caller`
+pub fn get_event_data<T: TopDecode>(tx_result: &TxResult, identifier: &str, caller: &Address) -> T {
+    let log = tx_result
+        .result_logs
+        .iter()
+        .rev()
+        .find(|log| log.topics[0] == identifier.as_bytes())
+        .unwrap_or_else(|| panic!("{identifier} not emitted"));
+    assert_eq!(log.topics[1], caller.as_bytes());
+
+    DebugApi::dummy();
+    T::top_decode(log.data[0].as_slice()).unwrap()
 }
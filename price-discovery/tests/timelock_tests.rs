@@ -0,0 +1,199 @@
+#![allow(deprecated)]
+
+mod tests_common;
+use multiversx_sc_scenario::{managed_biguint, rust_biguint, DebugApi};
+use price_discovery::{
+    common_storage::CommonStorageModule,
+    phase::PhaseModule,
+    timelock::{ChangeId, ParameterChange, PendingChange, TimelockModule},
+    user_actions::admin_actions::AdminActionsModule,
+    Timestamp,
+};
+use tests_common::*;
+
+const TIMELOCK_DELAY: Timestamp = 50;
+
+#[test]
+fn timelocked_duration_change_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let result = setup.call_set_timelock_delay(TIMELOCK_DELAY);
+    result.assert_ok();
+    let delay: Timestamp = get_event_data(&result, "setTimelockDelayEvent", &setup.owner_address);
+    assert_eq!(delay, TIMELOCK_DELAY);
+
+    let result = setup.call_set_user_deposit_withdraw_timestamp(USER_DEPOSIT_TIME + 50);
+    result.assert_ok();
+    let pending_change: PendingChange<DebugApi> =
+        get_event_data(&result, "queueChangeEvent", &setup.owner_address);
+    assert_eq!(pending_change.id, 1);
+    assert_eq!(
+        pending_change.executable_at,
+        START_TIME - 1 + TIMELOCK_DELAY
+    );
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_user_deposit_withdraw_time(), USER_DEPOSIT_TIME);
+
+            let pending_changes: Vec<_> = sc.get_pending_changes().into_iter().collect();
+            assert_eq!(pending_changes.len(), 1);
+            assert_eq!(pending_changes[0].id, 1);
+            assert_eq!(
+                pending_changes[0].executable_at,
+                START_TIME - 1 + TIMELOCK_DELAY
+            );
+            match &pending_changes[0].change {
+                ParameterChange::PhaseDuration {
+                    phase_index,
+                    duration,
+                    ..
+                } => {
+                    assert_eq!(*phase_index, 1);
+                    assert_eq!(*duration, USER_DEPOSIT_TIME + 50);
+                }
+                _ => panic!("Invalid change"),
+            }
+        })
+        .assert_ok();
+
+    setup
+        .call_execute_change(1)
+        .assert_user_error("Timelock not expired");
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME - 1 + TIMELOCK_DELAY);
+    setup
+        .b_mock
+        .execute_tx(
+            &setup.first_user_address.clone(),
+            &setup.pd_wrapper,
+            &rust_biguint!(0),
+            |sc| {
+                sc.execute_change(1);
+            },
+        )
+        .assert_user_error("Permission denied");
+    let result = setup.call_execute_change(1);
+    result.assert_ok();
+    let id: ChangeId = get_event_data(&result, "executeChangeEvent", &setup.owner_address);
+    assert_eq!(id, 1);
+    setup
+        .call_execute_change(1)
+        .assert_user_error("Change not found");
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_user_deposit_withdraw_time(), USER_DEPOSIT_TIME + 50);
+            assert_eq!(sc.get_pending_changes().len(), 0);
+        })
+        .assert_ok();
+
+    // a change that became invalid in the meantime may not be executed
+    setup
+        .call_set_user_deposit_withdraw_timestamp(USER_DEPOSIT_TIME)
+        .assert_ok();
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME - 1 + 2 * TIMELOCK_DELAY);
+    setup
+        .call_execute_change(2)
+        .assert_user_error("May only extend phase at this point, not reduce");
+}
+
+#[test]
+fn timelocked_min_launched_tokens_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    setup.call_set_timelock_delay(TIMELOCK_DELAY).assert_ok();
+    setup
+        .call_set_timelock_delay(TIMELOCK_DELAY - 1)
+        .assert_user_error("May only increase the timelock delay");
+
+    let owner_address = setup.owner_address.clone();
+    setup
+        .b_mock
+        .execute_tx(&owner_address, &setup.pd_wrapper, &rust_biguint!(0), |sc| {
+            sc.set_min_launched_tokens(managed_biguint!(5_000));
+        })
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.min_launched_tokens().get(),
+                managed_biguint!(MIN_LAUNCHED_TOKENS)
+            );
+            assert_eq!(sc.get_pending_changes().len(), 1);
+        })
+        .assert_ok();
+
+    let result = setup.call_cancel_change(1);
+    result.assert_ok();
+    let id: ChangeId = get_event_data(&result, "cancelChangeEvent", &setup.owner_address);
+    assert_eq!(id, 1);
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME - 1 + TIMELOCK_DELAY);
+    setup
+        .call_execute_change(1)
+        .assert_user_error("Change not found");
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.min_launched_tokens().get(),
+                managed_biguint!(MIN_LAUNCHED_TOKENS)
+            );
+            assert_eq!(sc.get_pending_changes().len(), 0);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn timelocked_schedule_changes_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    setup.call_set_timelock_delay(TIMELOCK_DELAY).assert_ok();
+    setup.call_set_target_raise(1_000).assert_ok();
+
+    setup.call_add_cooldown_phase(3, 20).assert_ok();
+    setup.call_set_start_time(START_TIME + 5).assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_phase_pipeline().len(), 3);
+            assert_eq!(sc.start_time().get(), START_TIME);
+            assert_eq!(sc.get_pending_changes().len(), 2);
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup.call_close_user_phase().assert_ok();
+
+    // the queued changes are checked again on execution
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + 1 + TIMELOCK_DELAY);
+    setup.call_execute_change(1).assert_ok();
+    setup
+        .call_execute_change(2)
+        .assert_user_error("Invalid current phase");
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_phase_pipeline().len(), 4);
+            assert_eq!(sc.get_user_deposit_withdraw_time(), USER_DEPOSIT_TIME);
+        })
+        .assert_ok();
+
+    setup.call_execute_change(3).assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_user_deposit_withdraw_time(), 1 + TIMELOCK_DELAY);
+        })
+        .assert_ok();
+}
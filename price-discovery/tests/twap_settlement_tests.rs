@@ -0,0 +1,213 @@
+#![allow(deprecated)]
+
+mod tests_common;
+use multiversx_sc_scenario::{managed_biguint, rust_biguint};
+use price_discovery::{
+    price_history::MAX_PRICE_CHECKPOINTS, twap_settlement::TwapSettlementModule, Timestamp,
+};
+use tests_common::*;
+
+const TWAP_WINDOW: Timestamp = 50;
+
+#[test]
+fn twap_settlement_late_deposit_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let result = setup.call_set_twap_settlement_window(TWAP_WINDOW);
+    result.assert_ok();
+    let window: Timestamp = get_event_data(
+        &result,
+        "setTwapSettlementWindowEvent",
+        &setup.owner_address,
+    );
+    assert_eq!(window, TWAP_WINDOW);
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+
+    // a large deposit just before the end barely moves the settlement price
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME - 1);
+    setup
+        .call_user_deposit(&setup.second_user_address.clone(), 9_000)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    // (1_000 * 49 + 10_000 * 1) / 50 = 1_180 accepted tokens on average
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(
+                sc.get_twap_settlement_price(),
+                managed_biguint!(590_000_000_000_000_000)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + 1);
+    setup.call_owner_redeem().assert_ok();
+
+    setup.b_mock.check_esdt_balance(
+        &setup.owner_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(1_180),
+    );
+
+    setup.b_mock.set_block_timestamp(
+        START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME + 1,
+    );
+    setup
+        .call_user_redeem(&setup.first_user_address.clone())
+        .assert_ok();
+    setup
+        .call_user_redeem(&setup.second_user_address.clone())
+        .assert_ok();
+
+    // all launched tokens are sold, and the excess deposits are refunded
+    setup.b_mock.check_esdt_balance(
+        &setup.first_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(200),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.first_user_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - 118),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.second_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(1_800),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.second_user_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - 1_062),
+    );
+    setup.b_mock.check_esdt_balance(
+        setup.pd_wrapper.address_ref(),
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn twap_settlement_late_withdraw_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    setup
+        .call_set_twap_settlement_window(TWAP_WINDOW)
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .call_user_deposit(&setup.second_user_address.clone(), 9_000)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME - 1);
+    setup
+        .call_user_withdraw(&setup.second_user_address.clone(), 9_000)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + 1);
+    setup.call_owner_redeem().assert_ok();
+
+    // (10_000 * 49 + 1_000 * 1) / 50 = 9_820 accepted tokens on average,
+    // so only 2_000 * 1_000 / 9_820 = 203 launched tokens are sold
+    setup.b_mock.check_esdt_balance(
+        &setup.owner_address,
+        ACCEPTED_TOKEN_ID,
+        &rust_biguint!(1_000),
+    );
+    setup.b_mock.check_esdt_balance(
+        &setup.owner_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(USER_BALANCE - 203),
+    );
+
+    setup.b_mock.set_block_timestamp(
+        START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME + 1,
+    );
+    setup
+        .call_user_redeem(&setup.first_user_address.clone())
+        .assert_ok();
+
+    setup.b_mock.check_esdt_balance(
+        &setup.first_user_address,
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(203),
+    );
+    setup.b_mock.check_esdt_balance(
+        setup.pd_wrapper.address_ref(),
+        LAUNCHED_TOKEN_ID,
+        &rust_biguint!(0),
+    );
+}
+
+#[test]
+fn twap_settlement_config_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    setup
+        .call_set_twap_settlement_window(TWAP_WINDOW)
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_set_twap_settlement_window(0)
+        .assert_user_error("Invalid current phase");
+    setup
+        .call_user_deposit_with_max_price(
+            &setup.first_user_address.clone(),
+            1_000,
+            1_000_000_000_000_000_000,
+        )
+        .assert_user_error("Max price not allowed with TWAP settlement");
+}
+
+#[test]
+fn twap_settlement_phase_change_keeps_snapshot_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    setup
+        .call_set_user_deposit_withdraw_timestamp(1_000)
+        .assert_ok();
+    setup.call_set_twap_settlement_window(600).assert_ok();
+
+    // more checkpoints than the price history keeps, all inside the window
+    let window_start = START_TIME + 400;
+    let nr_deposits = MAX_PRICE_CHECKPOINTS as u64 + 100;
+    for i in 0..nr_deposits {
+        setup.b_mock.set_block_timestamp(window_start + i);
+        setup
+            .call_user_deposit(&setup.first_user_address.clone(), 100)
+            .assert_ok();
+    }
+
+    // the user deposit phase end does not move, so the snapshot is kept
+    setup.b_mock.set_block_timestamp(START_TIME + 1_000 + 1);
+    setup.call_owner_deposit(2_000).assert_ok();
+    setup.call_set_phase_duration(3, 200).assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + 1_000 + 200 + 1);
+    setup.call_owner_redeem().assert_ok();
+}
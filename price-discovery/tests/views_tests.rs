@@ -0,0 +1,471 @@
+#![allow(deprecated)]
+
+mod tests_common;
+use multiversx_sc::{api::ManagedTypeApi, codec::multi_types::OptionalValue, types::ManagedBuffer};
+use multiversx_sc_scenario::{managed_address, managed_biguint};
+use price_discovery::{
+    phase::{Phase, PhaseModule},
+    user_actions::{redeem::RedeemModule, user_deposit_withdraw::UserDepositWithdrawModule},
+    views::{SimulationResult, ViewsModule},
+};
+use tests_common::*;
+
+#[test]
+fn contract_state_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let state = sc.get_contract_state();
+            assert!(state.current_phase == Phase::Idle);
+            assert_eq!(state.start_time, START_TIME);
+            assert_eq!(
+                state.user_deposit_withdraw_end,
+                START_TIME + USER_DEPOSIT_TIME
+            );
+            assert_eq!(
+                state.owner_redeem_end,
+                START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME
+            );
+            assert_eq!(state.current_price, None);
+            assert!(!state.min_launched_tokens_reached);
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let state = sc.get_contract_state();
+            assert!(state.current_phase == Phase::OwnerDepositWithdraw);
+            assert_eq!(state.accepted_token_balance, managed_biguint!(1_000));
+            assert_eq!(state.launched_token_balance, managed_biguint!(2_000));
+            assert_eq!(
+                state.current_price,
+                Some(managed_biguint!(500_000_000_000_000_000))
+            );
+            assert!(state.min_launched_tokens_reached);
+            assert!(!state.owner_redeemed);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn user_info_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .call_user_deposit(&setup.second_user_address.clone(), 3_000)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    let first_user_address = setup.first_user_address.clone();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let user_info = sc.get_user_info(managed_address!(&first_user_address));
+            assert_eq!(user_info.user_id, 1);
+            assert!(user_info.whitelisted);
+            assert_eq!(user_info.total_deposit, managed_biguint!(1_000));
+            assert_eq!(user_info.deposit_limit, None);
+            assert!(!user_info.redeemed);
+            assert_eq!(user_info.projected_bought_tokens, managed_biguint!(500));
+
+            // the owner did not redeem yet, so a redeem would refund the deposit
+            assert_eq!(user_info.redeem_amounts.bought_tokens, managed_biguint!(0));
+            assert_eq!(
+                user_info.redeem_amounts.refund_amount,
+                managed_biguint!(1_000)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + 1);
+    setup.call_owner_redeem().assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let user_info = sc.get_user_info(managed_address!(&first_user_address));
+            assert_eq!(
+                user_info.redeem_amounts.bought_tokens,
+                managed_biguint!(500)
+            );
+            assert_eq!(user_info.redeem_amounts.refund_amount, managed_biguint!(0));
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(
+        START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME + 1,
+    );
+    setup.call_user_redeem(&first_user_address).assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let user_info = sc.get_user_info(managed_address!(&first_user_address));
+            assert!(user_info.redeemed);
+            assert_eq!(user_info.redeem_amounts.bought_tokens, managed_biguint!(0));
+        })
+        .assert_ok();
+}
+
+#[test]
+fn simulate_deposit_withdraw_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let first_user_address = setup.first_user_address.clone();
+    let second_user_address = setup.second_user_address.clone();
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let result = sc.simulate_deposit(
+                managed_address!(&first_user_address),
+                managed_biguint!(1_000),
+                OptionalValue::None,
+            );
+            assert_simulation_error(result, "User deposit/withdraw not allowed in this phase");
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_user_deposit(&first_user_address, 1_000)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let result = sc.simulate_deposit(
+                managed_address!(&second_user_address),
+                managed_biguint!(50),
+                OptionalValue::None,
+            );
+            assert_simulation_error(result, "Not enough tokens deposited");
+
+            let result = sc.simulate_deposit(
+                managed_address!(&second_user_address),
+                managed_biguint!(20_000),
+                OptionalValue::None,
+            );
+            assert_simulation_error(result, "Exceeded deposit limit");
+
+            let result = sc.simulate_deposit(
+                managed_address!(&second_user_address),
+                managed_biguint!(3_000),
+                OptionalValue::Some(managed_biguint!(0)),
+            );
+            assert_simulation_error(result, "Invalid max price");
+
+            match sc.simulate_deposit(
+                managed_address!(&second_user_address),
+                managed_biguint!(3_000),
+                OptionalValue::None,
+            ) {
+                SimulationResult::Success(simulation) => {
+                    assert_eq!(simulation.user_total_deposit, managed_biguint!(3_000));
+                    assert_eq!(simulation.total_deposit, managed_biguint!(4_000));
+                    assert_eq!(simulation.implied_price, None);
+                }
+                SimulationResult::Error(_) => panic!("Simulation should succeed"),
+            }
+
+            let result =
+                sc.simulate_withdraw(managed_address!(&first_user_address), managed_biguint!(950));
+            assert_simulation_error(result, "Withdrawing too many tokens");
+
+            match sc.simulate_withdraw(
+                managed_address!(&first_user_address),
+                managed_biguint!(1_000),
+            ) {
+                SimulationResult::Success(simulation) => {
+                    assert_eq!(simulation.user_total_deposit, managed_biguint!(0));
+                    assert_eq!(simulation.total_deposit, managed_biguint!(0));
+                }
+                SimulationResult::Error(_) => panic!("Simulation should succeed"),
+            }
+        })
+        .assert_ok();
+
+    // the simulations do not change the state
+    setup
+        .call_user_withdraw(&first_user_address, 950)
+        .assert_user_error("Withdrawing too many tokens");
+}
+
+#[test]
+fn simulate_deposit_sale_mode_checks_test() {
+    // each token costs more than the min deposit
+    let mut setup = PriceDiscSetup::new_with_sale_mode(
+        price_discovery::contract_obj,
+        SaleModeArgs::DutchAuction {
+            max_price: 1_000_000_000_000_000_000_000,
+            min_price: 1_000_000_000_000_000_000_000,
+            price_step_duration: 0,
+        },
+    );
+    let first_user_address = setup.first_user_address.clone();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let result = sc.simulate_deposit(
+                managed_address!(&first_user_address),
+                managed_biguint!(1_000),
+                OptionalValue::Some(managed_biguint!(1_000)),
+            );
+            assert_simulation_error(result, "Max price not allowed in this sale mode");
+
+            let result = sc.simulate_deposit(
+                managed_address!(&first_user_address),
+                managed_biguint!(100),
+                OptionalValue::None,
+            );
+            assert_simulation_error(result, "Deposit too small for the current auction price");
+        })
+        .assert_ok();
+
+    let mut setup = PriceDiscSetup::new_with_sale_mode(
+        price_discovery::contract_obj,
+        SaleModeArgs::ExponentialBondingCurve {
+            initial_price: 1_000_000_000_000_000_000,
+            growth_step: 1_000,
+            growth_rate: 100,
+        },
+    );
+    let first_user_address = setup.first_user_address.clone();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let result = sc.simulate_deposit(
+                managed_address!(&first_user_address),
+                managed_biguint!(600_000),
+                OptionalValue::None,
+            );
+            assert_simulation_error(result, "Too many bonding curve steps");
+        })
+        .assert_ok();
+}
+
+fn assert_simulation_error<M: ManagedTypeApi>(result: SimulationResult<M>, expected_err: &str) {
+    match result {
+        SimulationResult::Error(err_msg) => {
+            assert_eq!(err_msg, ManagedBuffer::from(expected_err));
+        }
+        SimulationResult::Success(_) => panic!("Simulation should fail"),
+    }
+}
+
+#[test]
+fn participants_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    let first_user_address = setup.first_user_address.clone();
+    let second_user_address = setup.second_user_address.clone();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_user_deposit(&first_user_address, 1_000)
+        .assert_ok();
+    setup
+        .call_user_deposit(&second_user_address, 3_000)
+        .assert_ok();
+    setup
+        .call_user_withdraw(&second_user_address, 3_000)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_nr_whitelisted_users(), 2);
+            assert_eq!(sc.nr_depositors().get(), 1);
+
+            let participants: Vec<_> = sc
+                .get_participants(0, 10)
+                .into_iter()
+                .map(|participant| participant.into_tuple())
+                .collect();
+            assert_eq!(
+                participants,
+                vec![
+                    (
+                        managed_address!(&first_user_address),
+                        managed_biguint!(1_000),
+                        managed_biguint!(0),
+                        false
+                    ),
+                    (
+                        managed_address!(&second_user_address),
+                        managed_biguint!(0),
+                        managed_biguint!(10_000),
+                        false
+                    ),
+                ]
+            );
+
+            assert_eq!(sc.get_participants(1, 10).len(), 1);
+            assert_eq!(sc.get_participants(2, 10).len(), 0);
+        })
+        .assert_ok();
+
+    setup.call_refund_user(&first_user_address).assert_ok();
+
+    setup.b_mock.set_block_timestamp(
+        START_TIME + USER_DEPOSIT_TIME + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME + 1,
+    );
+    setup.call_user_redeem(&second_user_address).assert_ok();
+
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_nr_whitelisted_users(), 1);
+            assert_eq!(sc.nr_depositors().get(), 0);
+            assert_eq!(sc.nr_redeemers().get(), 1);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn phase_schedule_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let schedule: Vec<_> = sc.get_phase_schedule().into_iter().collect();
+            assert_eq!(schedule.len(), 5);
+            assert!(schedule[1].phase == Phase::UserDepositWithdraw);
+            assert_eq!(schedule[1].start, START_TIME);
+            assert_eq!(schedule[1].end, Some(START_TIME + USER_DEPOSIT_TIME));
+            assert!(schedule[4].phase == Phase::UserRedeem);
+            assert_eq!(schedule[4].end, None);
+
+            assert_eq!(
+                sc.get_time_until_next_phase().into_option(),
+                Some(USER_DEPOSIT_TIME - 1)
+            );
+        })
+        .assert_ok();
+
+    setup
+        .call_set_user_deposit_withdraw_timestamp(USER_DEPOSIT_TIME + 50)
+        .assert_ok();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let schedule: Vec<_> = sc.get_phase_schedule().into_iter().collect();
+            assert_eq!(schedule[2].start, START_TIME + USER_DEPOSIT_TIME + 50);
+            assert_eq!(
+                sc.get_time_until_next_phase().into_option(),
+                Some(USER_DEPOSIT_TIME + 49)
+            );
+        })
+        .assert_ok();
+
+    setup.b_mock.set_block_timestamp(
+        START_TIME + USER_DEPOSIT_TIME + 50 + OWNER_DEPOSIT_TIME + OWNER_REDEEM_TIME,
+    );
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            assert_eq!(sc.get_time_until_next_phase().into_option(), None);
+        })
+        .assert_ok();
+}
+
+#[test]
+fn user_info_projection_by_sale_mode_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .call_user_deposit_with_max_price(
+            &setup.second_user_address.clone(),
+            9_000,
+            2_000_000_000_000_000_000,
+        )
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    // the second user's order would be excluded at a price of 5,
+    // so the first user gets all the launched tokens
+    let first_user_address = setup.first_user_address.clone();
+    let second_user_address = setup.second_user_address.clone();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let first_user_info = sc.get_user_info(managed_address!(&first_user_address));
+            assert_eq!(
+                first_user_info.projected_bought_tokens,
+                managed_biguint!(2_000)
+            );
+            let second_user_info = sc.get_user_info(managed_address!(&second_user_address));
+            assert_eq!(
+                second_user_info.projected_bought_tokens,
+                managed_biguint!(0)
+            );
+        })
+        .assert_ok();
+}
+
+#[test]
+fn user_info_twap_projection_test() {
+    let mut setup = PriceDiscSetup::new(price_discovery::contract_obj);
+    setup.call_set_twap_settlement_window(50).assert_ok();
+
+    setup.b_mock.set_block_timestamp(START_TIME + 1);
+    setup
+        .call_user_deposit(&setup.first_user_address.clone(), 1_000)
+        .assert_ok();
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME - 1);
+    setup
+        .call_user_deposit(&setup.second_user_address.clone(), 9_000)
+        .assert_ok();
+
+    setup
+        .b_mock
+        .set_block_timestamp(START_TIME + USER_DEPOSIT_TIME + 1);
+    setup.call_owner_deposit(2_000).assert_ok();
+
+    // same allocation as the TWAP settlement, not the spot price
+    let first_user_address = setup.first_user_address.clone();
+    setup
+        .b_mock
+        .execute_query(&setup.pd_wrapper, |sc| {
+            let user_info = sc.get_user_info(managed_address!(&first_user_address));
+            assert_eq!(user_info.projected_bought_tokens, managed_biguint!(200));
+        })
+        .assert_ok();
+}
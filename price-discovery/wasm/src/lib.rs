@@ -6,9 +6,9 @@
 
 // Init:                                 1
 // Upgrade:                              1
-// Endpoints:                           24
+// Endpoints:                           91
 // Async Callback:                       1
-// Total number of exported functions:  27
+// Total number of exported functions:  94
 
 #![no_std]
 
@@ -20,30 +20,97 @@ multiversx_sc_wasm_adapter::endpoints! {
     (
         init => init
         upgrade => upgrade
+        getMinLaunchedTokens => min_launched_tokens
         getStartTime => start_time
+        getTimeBase => time_base
+        getSaleMode => sale_mode
+        getTargetRaise => target_raise
+        getTwapSettlementWindow => twap_settlement_window
+        getAdmin => admin
         getCurrentPhase => get_current_phase
-        getUserDepositWithdrawTime => user_deposit_withdraw_time
-        getOwnerDepositWithdrawTime => owner_deposit_withdraw_time
-        getOwnerRedeemTime => owner_redeem_time
+        getPhaseSchedule => get_phase_schedule
+        getTimeUntilNextPhase => get_time_until_next_phase
+        getUserDepositWithdrawTime => get_user_deposit_withdraw_time
+        getOwnerDepositWithdrawTime => get_owner_deposit_withdraw_time
+        getOwnerRedeemTime => get_owner_redeem_time
+        getPhasePipeline => phase_pipeline
+        getSoftCloseTotalExtension => soft_close_total_extension
+        getAuctionPrice => get_auction_price
+        getBondingCurveTokensSold => bonding_curve_tokens_sold
+        getDemandByMaxPrice => get_demand_by_max_price
+        getDemandAtPrice => get_demand_at_price
+        getLimitOrderClearingPrice => get_limit_order_clearing_price
+        getPriceHistory => get_price_history
+        getTimeWeightedAveragePrice => get_time_weighted_average_price
+        getUserPhaseAverageAcceptedBalance => get_user_phase_average_accepted_balance
+        getTimeWeightedAcceptedBalance => get_time_weighted_accepted_balance
+        getTwapSettlementPrice => get_twap_settlement_price
+        getSoftCloseConfig => soft_close_config
+        grantRole => grant_role
+        revokeRole => revoke_role
+        proposeAdmin => propose_admin
+        acceptAdmin => accept_admin
+        pause => pause
+        unpause => unpause
+        getRoleHolders => get_role_holders
+        getPendingAdmin => get_pending_admin
+        hasRole => has_role
+        isPaused => paused
+        getPendingChanges => get_pending_changes
+        getTimelockDelay => timelock_delay
+        setBoard => set_board
+        proposeAction => propose_action
+        signAction => sign_action
+        unsignAction => unsign_action
+        discardAction => discard_action
+        getBoardMembers => get_board_members
+        getActionData => get_action_data
+        getActionSigners => get_action_signers
+        getPendingActionIds => get_pending_action_ids
+        getQuorum => quorum
         userDeposit => user_deposit
         userWithdraw => user_withdraw_endpoint
         isUserWhitelisted => is_user_whitelisted
         getUserDepositLimit => get_user_deposit_limit
         getTotalDepositByUser => get_total_deposit_by_user
         getUserMinDeposit => user_min_deposit
+        getNrDepositors => nr_depositors
         ownerDeposit => owner_deposit
         ownerWithdraw => owner_withdraw
         userRedeem => user_redeem_endpoint
         ownerRedeem => owner_redeem_endpoint
+        proposeBeneficiary => propose_beneficiary
+        acceptBeneficiary => accept_beneficiary
+        getOwnerRedeemBeneficiary => get_owner_redeem_beneficiary
+        getPendingBeneficiary => get_pending_beneficiary
         withdrawLaunchpadTokens => withdraw_launchpad_tokens
+        getNrRedeemers => nr_redeemers
+        setStartTime => set_start_time
         setUserDepositWithdrawTime => set_user_deposit_withdraw_time
         setOwnerDepositWithdrawTime => set_owner_deposit_withdraw_time
         setOwnerRedeemTime => set_owner_redeem_time
+        setPhaseDuration => set_phase_duration
+        addCooldownPhase => add_cooldown_phase
+        removeCooldownPhase => remove_cooldown_phase
+        setTwapSettlementWindow => set_twap_settlement_window
+        setSoftCloseConfig => set_soft_close_config
+        setTargetRaise => set_target_raise
+        closeUserPhase => close_user_phase
         setMinLaunchedTokens => set_min_launched_tokens
+        setTimelockDelay => set_timelock_delay
+        executeChange => execute_change
+        cancelChange => cancel_change
+        performAction => perform_action
         setUserLimit => set_user_limit
         addUsersToWhitelist => add_users_to_whitelist
         refundUsers => refund_users
         getCurrentPrice => get_current_price
+        getContractState => get_contract_state
+        getUserInfo => get_user_info
+        getParticipants => get_participants
+        getNrWhitelistedUsers => get_nr_whitelisted_users
+        simulateDeposit => simulate_deposit
+        simulateWithdraw => simulate_withdraw
     )
 }
 